@@ -68,6 +68,12 @@ impl<F: PrimeField, MM: MarlinMode> Circuit<F, MM> {
         crate::fft::EvaluationDomain::<F>::new(self.index_info.num_constraints).unwrap().size()
     }
 
+    /// Returns the SHA-256 digest of the index information and the `A`, `B`, and `C` matrices,
+    /// which identifies the constraint system this circuit was indexed from.
+    pub fn digest(&self) -> [u8; 32] {
+        super::matrix_digest(&self.index_info, &self.a, &self.b, &self.c)
+    }
+
     /// Iterate over the indexed polynomials.
     pub fn iter(&self) -> impl Iterator<Item = &LabeledPolynomial<F>> {
         // Alphabetical order
@@ -19,7 +19,7 @@ use crate::{
     polycommit::sonic_pc::{PolynomialInfo, PolynomialLabel},
     snark::marlin::{
         ahp::{
-            indexer::{Circuit, CircuitInfo, ConstraintSystem as IndexerConstraintSystem},
+            indexer::{matrix_digest, Circuit, CircuitInfo, ConstraintSystem as IndexerConstraintSystem},
             matrices::arithmetize_matrix,
             AHPError,
             AHPForR1CS,
@@ -116,9 +116,79 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         })
     }
 
+    /// Returns the digest of the index information and matrices for this constraint system.
+    /// This matches `Circuit::digest` on the index, without computing the index polynomials.
+    pub fn index_digest<C: ConstraintSynthesizer<F>>(c: &C) -> Result<[u8; 32], AHPError> {
+        let (index_info, a, b, c) = Self::index_matrices(c)?;
+        Ok(matrix_digest(&index_info, &a, &b, &c))
+    }
+
     fn index_helper<C: ConstraintSynthesizer<F>>(c: &C) -> Result<IndexerState<F>, AHPError> {
         let index_time = start_timer!(|| "AHP::Index");
 
+        let (index_info, a, b, c) = Self::index_matrices(c)?;
+        let num_constraints = index_info.num_constraints;
+        let num_padded_public_variables = index_info.num_public_inputs;
+        let num_non_zero_a = index_info.num_non_zero_a;
+        let num_non_zero_b = index_info.num_non_zero_b;
+        let num_non_zero_c = index_info.num_non_zero_c;
+
+        let constraint_domain =
+            EvaluationDomain::new(num_constraints).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let input_domain =
+            EvaluationDomain::new(num_padded_public_variables).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+
+        let non_zero_a_domain =
+            EvaluationDomain::new(num_non_zero_a).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let non_zero_b_domain =
+            EvaluationDomain::new(num_non_zero_b).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let non_zero_c_domain =
+            EvaluationDomain::new(num_non_zero_c).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+
+        let (constraint_domain_elements, constraint_domain_eq_poly_vals) =
+            precomputation_for_matrix_evals(&constraint_domain);
+
+        let [a_evals, b_evals, c_evals]: [_; 3] =
+            cfg_into_iter!([(&a, &non_zero_a_domain), (&b, &non_zero_b_domain), (&c, &non_zero_c_domain),])
+                .map(|(matrix, non_zero_domain)| {
+                    matrix_evals(
+                        matrix,
+                        non_zero_domain,
+                        &constraint_domain,
+                        &input_domain,
+                        &constraint_domain_elements,
+                        &constraint_domain_eq_poly_vals,
+                    )
+                })
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap();
+
+        let result = Ok(IndexerState {
+            constraint_domain,
+
+            a,
+            non_zero_a_domain,
+            a_evals,
+
+            b,
+            non_zero_b_domain,
+            b_evals,
+
+            c,
+            non_zero_c_domain,
+            c_evals,
+
+            index_info,
+        });
+        end_timer!(index_time);
+        result
+    }
+
+    /// Synthesizes the constraint system, and returns the index information and the padded, square matrices.
+    fn index_matrices<C: ConstraintSynthesizer<F>>(
+        c: &C,
+    ) -> Result<(CircuitInfo<F>, Matrix<F>, Matrix<F>, Matrix<F>), AHPError> {
         let constraint_time = start_timer!(|| "Generating constraints");
         let mut ics = IndexerConstraintSystem::new();
         c.generate_constraints(&mut ics)?;
@@ -174,56 +244,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             f: PhantomData,
         };
 
-        let constraint_domain =
-            EvaluationDomain::new(num_constraints).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-        let input_domain =
-            EvaluationDomain::new(num_padded_public_variables).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-
-        let non_zero_a_domain =
-            EvaluationDomain::new(num_non_zero_a).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-        let non_zero_b_domain =
-            EvaluationDomain::new(num_non_zero_b).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-        let non_zero_c_domain =
-            EvaluationDomain::new(num_non_zero_c).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-
-        let (constraint_domain_elements, constraint_domain_eq_poly_vals) =
-            precomputation_for_matrix_evals(&constraint_domain);
-
-        let [a_evals, b_evals, c_evals]: [_; 3] =
-            cfg_into_iter!([(&a, &non_zero_a_domain), (&b, &non_zero_b_domain), (&c, &non_zero_c_domain),])
-                .map(|(matrix, non_zero_domain)| {
-                    matrix_evals(
-                        matrix,
-                        non_zero_domain,
-                        &constraint_domain,
-                        &input_domain,
-                        &constraint_domain_elements,
-                        &constraint_domain_eq_poly_vals,
-                    )
-                })
-                .collect::<Vec<_>>()
-                .try_into()
-                .unwrap();
-
-        let result = Ok(IndexerState {
-            constraint_domain,
-
-            a,
-            non_zero_a_domain,
-            a_evals,
-
-            b,
-            non_zero_b_domain,
-            b_evals,
-
-            c,
-            non_zero_c_domain,
-            c_evals,
-
-            index_info,
-        });
-        end_timer!(index_time);
-        result
+        Ok((index_info, a, b, c))
     }
 
     pub fn evaluate_index_polynomials<C: ConstraintSynthesizer<F>>(
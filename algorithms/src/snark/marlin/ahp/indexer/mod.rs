@@ -33,3 +33,20 @@ pub(crate) type Matrix<F> = Vec<Vec<(F, usize)>>;
 pub(crate) fn num_non_zero<F>(joint_matrix: &Matrix<F>) -> usize {
     joint_matrix.iter().map(|row| row.len()).sum()
 }
+
+/// Returns the SHA-256 digest of the canonical encoding of the given index information and matrices.
+pub(crate) fn matrix_digest<F: snarkvm_fields::PrimeField>(
+    index_info: &CircuitInfo<F>,
+    a: &Matrix<F>,
+    b: &Matrix<F>,
+    c: &Matrix<F>,
+) -> [u8; 32] {
+    use snarkvm_utilities::serialize::CanonicalSerialize;
+
+    let mut bytes = Vec::new();
+    index_info.serialize_uncompressed(&mut bytes).unwrap();
+    for matrix in [a, b, c] {
+        matrix.serialize_uncompressed(&mut bytes).unwrap();
+    }
+    crate::crypto_hash::sha256(&bytes)
+}
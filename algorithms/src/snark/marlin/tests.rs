@@ -478,6 +478,23 @@ mod marlin_hiding {
         assert!(MarlinInst::verify(&fs_parameters, &new_vk, [c, d], &proof).unwrap());
     }
 
+    #[test]
+    fn test_index_digest() {
+        let (circuit, _, _) = setup_test(100, 25);
+
+        // Ensure the digest matches the digest of the full index.
+        let digest = AHPForR1CS::<Fr, MarlinHidingMode>::index_digest(&circuit).unwrap();
+        assert_eq!(digest, AHPForR1CS::<Fr, MarlinHidingMode>::index(&circuit).unwrap().digest());
+
+        // Ensure the digest does not depend on the witness.
+        let unassigned = Circuit::<Fr> { a: None, b: None, num_constraints: 100, num_variables: 25 };
+        assert_eq!(digest, AHPForR1CS::<Fr, MarlinHidingMode>::index_digest(&unassigned).unwrap());
+
+        // Ensure the digest differs for a different constraint system.
+        let (other, _, _) = setup_test(101, 25);
+        assert_ne!(digest, AHPForR1CS::<Fr, MarlinHidingMode>::index_digest(&other).unwrap());
+    }
+
     #[test]
     fn test_srs_downloads() {
         let rng = &mut TestRng::default();
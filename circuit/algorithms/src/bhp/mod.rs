@@ -21,6 +21,9 @@ mod commit;
 mod commit_uncompressed;
 mod hash;
 mod hash_uncompressed;
+mod sponge;
+
+pub use sponge::BHPSponge;
 
 #[cfg(all(test, console))]
 use snarkvm_circuit_types::environment::assert_scope;
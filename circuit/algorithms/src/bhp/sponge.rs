@@ -0,0 +1,166 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A streaming interface to the BHP hash function, for inputs whose length is not known up front.
+///
+/// ## Design
+/// As the input length is unknown when the first block is processed, the length is moved to a final block:
+/// ```text
+/// DIGEST_0 = BHP([ 0...0 || DOMAIN || 1...1 || INPUT[0..BLOCK_SIZE] ]);
+/// DIGEST_N+1 = BHP([ DIGEST_N[0..DATA_BITS] || INPUT[(N+1)*BLOCK_SIZE..(N+2)*BLOCK_SIZE] ]);
+/// OUTPUT = BHP([ DIGEST_N[0..DATA_BITS] || LENGTH(INPUT) ]);
+/// ```
+pub struct BHPSponge<'a, E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> {
+    /// The BHP hash function.
+    bhp: &'a BHP<E, NUM_WINDOWS, WINDOW_SIZE>,
+    /// The digest of the processed blocks, if any.
+    digest: Option<Group<E>>,
+    /// The absorbed bits that have not been processed yet.
+    buffer: Vec<Boolean<E>>,
+    /// The total number of absorbed bits.
+    num_absorbed: u64,
+}
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns a new streaming instance of the BHP hash function.
+    pub fn sponge(&self) -> BHPSponge<'_, E, NUM_WINDOWS, WINDOW_SIZE> {
+        BHPSponge { bhp: self, digest: None, buffer: Vec::new(), num_absorbed: 0 }
+    }
+}
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPSponge<'_, E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the number of input bits processed per block.
+    fn block_size() -> usize {
+        NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE - E::BaseField::size_in_data_bits()
+    }
+
+    /// Absorbs the given input bits, processing every full block.
+    pub fn absorb(&mut self, input: &[Boolean<E>]) {
+        // Update the number of absorbed bits.
+        self.num_absorbed = match self.num_absorbed.checked_add(input.len() as u64) {
+            Some(num_absorbed) => num_absorbed,
+            None => E::halt(format!("The BHP sponge cannot absorb more than {} bits", u64::MAX)),
+        };
+        // Buffer the input, and process each full block.
+        self.buffer.extend_from_slice(input);
+        while self.buffer.len() >= Self::block_size() {
+            let block = self.buffer.drain(..Self::block_size()).collect::<Vec<_>>();
+            self.digest = Some(self.process_block(&block));
+        }
+    }
+
+    /// Returns the BHP hash of the absorbed input as an affine group element.
+    pub fn squeeze_uncompressed(&self) -> Group<E> {
+        // Process the remaining bits, ensuring at least one block is processed.
+        let digest = match (&self.digest, self.buffer.is_empty()) {
+            (Some(digest), true) => digest.clone(),
+            _ => self.process_block(&self.buffer),
+        };
+        // Construct the final block as: [ DIGEST[0..DATA_BITS] || LENGTH(INPUT) ].
+        let mut preimage = Vec::with_capacity(E::BaseField::size_in_data_bits() + 64);
+        preimage.extend(digest.to_x_coordinate().to_bits_le().into_iter().take(E::BaseField::size_in_data_bits()));
+        preimage.extend(U64::constant(console::U64::new(self.num_absorbed)).to_bits_le());
+        self.bhp.hasher.hash_uncompressed(&preimage)
+    }
+
+    /// Returns the BHP hash of the absorbed input as a field element.
+    pub fn squeeze(&self) -> Field<E> {
+        self.squeeze_uncompressed().to_x_coordinate()
+    }
+
+    /// Returns the digest after processing the given block.
+    fn process_block(&self, block: &[Boolean<E>]) -> Group<E> {
+        let mut preimage = Vec::with_capacity(E::BaseField::size_in_data_bits() + block.len());
+        match &self.digest {
+            // Construct the first block as: [ 0...0 || DOMAIN || 1...1 || INPUT[0..BLOCK_SIZE] ].
+            None => {
+                preimage.extend(self.bhp.domain.clone());
+                preimage.extend(U64::constant(console::U64::new(u64::MAX)).to_bits_le());
+            }
+            // Construct the subsequent blocks as: [ PREVIOUS_HASH[0..DATA_BITS] || INPUT[I * BLOCK_SIZE..(I + 1) * BLOCK_SIZE] ].
+            Some(digest) => preimage
+                .extend(digest.to_x_coordinate().to_bits_le().into_iter().take(E::BaseField::size_in_data_bits())),
+        }
+        preimage.extend_from_slice(block);
+        self.bhp.hasher.hash_uncompressed(&preimage)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 3;
+    const DOMAIN: &str = "BHPSpongeCircuit";
+
+    fn check_sponge<const NUM_WINDOWS: u8, const WINDOW_SIZE: u8>(mode: Mode, rng: &mut TestRng) -> Result<()> {
+        // Initialize the BHP hash.
+        let native = console::BHP::<<Circuit as Environment>::Network, NUM_WINDOWS, WINDOW_SIZE>::setup(DOMAIN)?;
+        let circuit = BHP::<Circuit, NUM_WINDOWS, WINDOW_SIZE>::constant(native.clone());
+        let block_size = BHPSponge::<Circuit, NUM_WINDOWS, WINDOW_SIZE>::block_size();
+
+        for num_bits in [0, 1, block_size, 2 * block_size + 7] {
+            for i in 0..ITERATIONS {
+                // Sample a random input.
+                let input = (0..num_bits).map(|_| bool::rand(rng)).collect::<Vec<bool>>();
+                // Compute the expected hash.
+                let mut native_sponge = native.sponge();
+                native_sponge.absorb(&input)?;
+                let expected = native_sponge.squeeze()?;
+                // Prepare the circuit input.
+                let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+                Circuit::scope(format!("BHP Sponge {mode} {i}"), || {
+                    // Absorb the input in random-sized pieces.
+                    let mut sponge = circuit.sponge();
+                    let mut remaining = &circuit_input[..];
+                    while !remaining.is_empty() {
+                        let (piece, rest) = remaining.split_at(1 + u16::rand(rng) as usize % remaining.len());
+                        sponge.absorb(piece);
+                        remaining = rest;
+                    }
+                    // Ensure the streaming hash matches.
+                    assert_eq!(expected, sponge.squeeze().eject_value());
+                });
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bhp256_sponge() -> Result<()> {
+        let mut rng = TestRng::default();
+        check_sponge::<3, 57>(Mode::Constant, &mut rng)?;
+        check_sponge::<3, 57>(Mode::Public, &mut rng)?;
+        check_sponge::<3, 57>(Mode::Private, &mut rng)
+    }
+
+    #[test]
+    fn test_bhp1024_sponge() -> Result<()> {
+        let mut rng = TestRng::default();
+        check_sponge::<8, 54>(Mode::Constant, &mut rng)?;
+        check_sponge::<8, 54>(Mode::Public, &mut rng)?;
+        check_sponge::<8, 54>(Mode::Private, &mut rng)
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Hash for Blake3<E> {
+    type Input = Boolean<E>;
+    type Output = Vec<Boolean<E>>;
+
+    /// Returns the BLAKE3 hash of the given input as a list of bits.
+    /// A byte string is hashed by passing each byte as **little-endian** bits, in order,
+    /// and the digest is returned in the same form. The input length must be a multiple of 8.
+    fn hash(&self, input: &[Self::Input]) -> Self::Output {
+        // Ensure the input is a byte string.
+        if input.len() % 8 != 0 {
+            E::halt(format!("BLAKE3 expects a multiple of 8 input bits, found {}", input.len()))
+        }
+
+        // Split the input into chunks, where the empty input is a single empty chunk.
+        let chunks = match input.is_empty() {
+            true => vec![input],
+            false => input.chunks(CHUNK_SIZE_IN_BITS).collect(),
+        };
+
+        // Output the root chaining value as little-endian bytes, of little-endian bits.
+        Self::subtree(&chunks, 0, ROOT).into_iter().flatten().collect()
+    }
+}
+
+impl<E: Environment> Blake3<E> {
+    /// Returns the chaining value of the subtree over the given chunks, where `counter` is the index of the first chunk.
+    /// The given `flags` are set on the final compression of the subtree.
+    fn subtree(chunks: &[&[Boolean<E>]], counter: u64, flags: u32) -> Vec<Vec<Boolean<E>>> {
+        if chunks.len() == 1 {
+            return Self::chunk(chunks[0], counter, flags);
+        }
+        // The left subtree holds the largest power-of-two number of chunks, while leaving at least one chunk on the right.
+        let num_left = 1usize << (usize::BITS - 1 - (chunks.len() - 1).leading_zeros());
+        let left = Self::subtree(&chunks[..num_left], counter, 0);
+        let right = Self::subtree(&chunks[num_left..], counter + num_left as u64, 0);
+
+        // Compress the chaining values of the children into the parent.
+        let block = [left, right].concat();
+        let chaining_value = IV.map(Self::constant);
+        Self::compress(&chaining_value, &block, 0, BLOCK_SIZE_IN_BITS / 8, PARENT | flags)
+    }
+
+    /// Returns the chaining value of the given chunk, where `counter` is the index of the chunk.
+    /// The given `flags` are set on the last block of the chunk.
+    fn chunk(chunk: &[Boolean<E>], counter: u64, flags: u32) -> Vec<Vec<Boolean<E>>> {
+        // Split the chunk into blocks, where the empty chunk is a single empty block.
+        let blocks = match chunk.is_empty() {
+            true => vec![chunk],
+            false => chunk.chunks(BLOCK_SIZE_IN_BITS).collect(),
+        };
+
+        let mut chaining_value = IV.map(Self::constant).to_vec();
+        for (i, block) in blocks.iter().enumerate() {
+            // Pad the block with zeros, and split it into words of 4 little-endian bytes.
+            let mut padded = block.to_vec();
+            padded.resize(BLOCK_SIZE_IN_BITS, Boolean::constant(false));
+            let words = padded.chunks(32).map(|bits| bits.to_vec()).collect::<Vec<_>>();
+            // Set the domain flags for the block.
+            let mut block_flags = 0;
+            if i == 0 {
+                block_flags |= CHUNK_START;
+            }
+            if i == blocks.len() - 1 {
+                block_flags |= CHUNK_END | flags;
+            }
+            chaining_value = Self::compress(&chaining_value, &words, counter, block.len() / 8, block_flags);
+        }
+        chaining_value
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 3;
+
+    fn check_hash(mode: Mode, num_input_bytes: usize) -> Result<()> {
+        use console::Hash as H;
+
+        let native = console::Blake3::new();
+        let circuit = Blake3::<Circuit>::new();
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let input = (0..num_input_bytes * 8).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = native.hash(&input)?;
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("Blake3 {mode} {i}"), || {
+                // Perform the hash operation.
+                let candidate = circuit.hash(&circuit_input);
+                assert_eq!(expected, candidate.eject_value());
+                if mode.is_constant() {
+                    assert!(candidate.iter().all(|bit| bit.is_constant()));
+                }
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_constant() -> Result<()> {
+        for num_input_bytes in [0, 1, 63, 64, 65, 1025] {
+            check_hash(Mode::Constant, num_input_bytes)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_public() -> Result<()> {
+        for num_input_bytes in [1, 63, 64, 65] {
+            check_hash(Mode::Public, num_input_bytes)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_private() -> Result<()> {
+        for num_input_bytes in [1, 63, 64, 65] {
+            check_hash(Mode::Private, num_input_bytes)?;
+        }
+        Ok(())
+    }
+}
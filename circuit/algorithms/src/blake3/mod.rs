@@ -0,0 +1,139 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod hash;
+
+use crate::Hash;
+use snarkvm_circuit_types::prelude::*;
+
+use core::marker::PhantomData;
+
+/// The initial chaining value of BLAKE3, which is the initial hash value of SHA-256.
+const IV: [u32; 8] = [0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19];
+
+/// The permutation applied to the message words between rounds.
+const MESSAGE_PERMUTATION: [usize; 16] = [2, 6, 3, 10, 7, 0, 4, 13, 1, 11, 12, 5, 9, 14, 15, 8];
+
+/// The domain flag for the first block of a chunk.
+const CHUNK_START: u32 = 1 << 0;
+/// The domain flag for the last block of a chunk.
+const CHUNK_END: u32 = 1 << 1;
+/// The domain flag for a parent node.
+const PARENT: u32 = 1 << 2;
+/// The domain flag for the root node.
+const ROOT: u32 = 1 << 3;
+
+/// The number of bits in a BLAKE3 block.
+const BLOCK_SIZE_IN_BITS: usize = 512;
+/// The number of bits in a BLAKE3 chunk.
+const CHUNK_SIZE_IN_BITS: usize = 8192;
+
+/// BLAKE3 is a tree hash built on a reduced-round BLAKE2s compression function,
+/// as specified in the BLAKE3 paper. This implements the default (unkeyed) 256-bit hash.
+/// Each 32-bit word is represented as 32 **little-endian** bits.
+pub struct Blake3<E: Environment>(PhantomData<E>);
+
+impl<E: Environment> Blake3<E> {
+    /// Initializes a new instance of BLAKE3.
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+
+    /// Applies the BLAKE3 compression function, and returns the new chaining value.
+    /// The counter, block length, and flags are determined by the input length, and are therefore constant.
+    fn compress(
+        chaining_value: &[Vec<Boolean<E>>],
+        block: &[Vec<Boolean<E>>],
+        counter: u64,
+        block_len: usize,
+        flags: u32,
+    ) -> Vec<Vec<Boolean<E>>> {
+        // Initialize the state from the chaining value, the IV, the counter, the block length, and the flags.
+        let mut state = chaining_value.to_vec();
+        state.extend(IV[..4].iter().map(|word| Self::constant(*word)));
+        state.push(Self::constant((counter & 0xffff_ffff) as u32));
+        state.push(Self::constant((counter >> 32) as u32));
+        state.push(Self::constant(block_len as u32));
+        state.push(Self::constant(flags));
+
+        // Perform the rounds, permuting the message words between rounds.
+        let mut m = block.to_vec();
+        for round in 0..7 {
+            // Mix the columns.
+            Self::g(&mut state, [0, 4, 8, 12], &m[0], &m[1]);
+            Self::g(&mut state, [1, 5, 9, 13], &m[2], &m[3]);
+            Self::g(&mut state, [2, 6, 10, 14], &m[4], &m[5]);
+            Self::g(&mut state, [3, 7, 11, 15], &m[6], &m[7]);
+            // Mix the diagonals.
+            Self::g(&mut state, [0, 5, 10, 15], &m[8], &m[9]);
+            Self::g(&mut state, [1, 6, 11, 12], &m[10], &m[11]);
+            Self::g(&mut state, [2, 7, 8, 13], &m[12], &m[13]);
+            Self::g(&mut state, [3, 4, 9, 14], &m[14], &m[15]);
+            if round < 6 {
+                m = MESSAGE_PERMUTATION.iter().map(|i| m[*i].clone()).collect();
+            }
+        }
+
+        // Output the first half of the state, masked by the second half.
+        (0..8).map(|i| Self::xor(&state[i], &state[i + 8])).collect()
+    }
+
+    /// The BLAKE3 quarter-round function, which mixes the message words `x` and `y` into the given state words.
+    fn g(state: &mut [Vec<Boolean<E>>], [a, b, c, d]: [usize; 4], x: &[Boolean<E>], y: &[Boolean<E>]) {
+        state[a] = Self::add_many(&[&state[a], &state[b], x]);
+        state[d] = Self::rotr(&Self::xor(&state[d], &state[a]), 16);
+        state[c] = Self::add_many(&[&state[c], &state[d]]);
+        state[b] = Self::rotr(&Self::xor(&state[b], &state[c]), 12);
+        state[a] = Self::add_many(&[&state[a], &state[b], y]);
+        state[d] = Self::rotr(&Self::xor(&state[d], &state[a]), 8);
+        state[c] = Self::add_many(&[&state[c], &state[d]]);
+        state[b] = Self::rotr(&Self::xor(&state[b], &state[c]), 7);
+    }
+
+    /// Returns the given 32-bit constant as a word.
+    fn constant(value: u32) -> Vec<Boolean<E>> {
+        (0..32).map(|i| Boolean::constant((value >> i) & 1 == 1)).collect()
+    }
+
+    /// Returns the given word rotated right by `n` bits.
+    fn rotr(word: &[Boolean<E>], n: usize) -> Vec<Boolean<E>> {
+        (0..32).map(|i| word[(i + n) % 32].clone()).collect()
+    }
+
+    /// Returns the bitwise XOR of the two given words.
+    fn xor(a: &[Boolean<E>], b: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        a.iter().zip_eq(b).map(|(a, b)| a ^ b).collect()
+    }
+
+    /// Returns the sum of the given words, modulo `2^32`.
+    /// Note: The words are summed as field elements, and only the lower 32 bits of the sum are kept.
+    fn add_many(words: &[&[Boolean<E>]]) -> Vec<Boolean<E>> {
+        // Compute the sum of the words as a field element.
+        let sum = words.iter().fold(Field::zero(), |sum, word| sum + Field::from_bits_le(word));
+        // Determine the number of carry bits in the sum.
+        let num_carry_bits = (usize::BITS - words.len().leading_zeros()) as usize;
+        // Extract the lower bits of the sum, and drop the carry bits.
+        let mut bits_le = sum.to_lower_bits_le(32 + num_carry_bits);
+        bits_le.truncate(32);
+        bits_le
+    }
+}
+
+impl<E: Environment> Default for Blake3<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod point;
+mod verify;
+
+use crate::non_native::{from_limbs, NonNativeField, NonNativeParameters, Secp256k1BaseField};
+use point::Point;
+
+use snarkvm_circuit_types::prelude::*;
+
+use core::marker::PhantomData;
+use num_bigint::BigUint;
+
+/// The coefficient `B` of secp256k1, in `y^2 = x^3 + B`.
+const SECP256K1_B: u64 = 7;
+/// The affine generator of secp256k1, as little-endian 64-bit limbs.
+const SECP256K1_GENERATOR: ([u64; 4], [u64; 4]) =
+    ([0x59F2_815B_16F8_1798, 0x029B_FCDB_2DCE_28D9, 0x55A0_6295_CE87_0B07, 0x79BE_667E_F9DC_BBAC], [
+        0x9C47_D08F_FB10_D4B8,
+        0xFD17_B448_A685_5419,
+        0x5DA4_FBFC_0E11_08A8,
+        0x483A_DA77_26A3_C465,
+    ]);
+/// The initial point of the scalar multiplication accumulator, as little-endian 64-bit limbs.
+/// This point is found by hashing `snarkVM ECDSA secp256k1 offset` with SHA-256 to an x-coordinate,
+/// and incrementing the x-coordinate until it is on the curve, so its discrete logarithm is unknown.
+const ACCUMULATOR_OFFSET: ([u64; 4], [u64; 4]) =
+    ([0xCB64_8BC5_E7A5_416E, 0x6EBA_06A4_D34B_3325, 0x4476_7E58_ABD2_90D3, 0x8F98_E785_D394_9662], [
+        0x1DB9_0A46_D07C_34BE,
+        0x7C7B_5923_A4C0_1A4D,
+        0xD1B9_6322_29AD_D804,
+        0x4A3F_005A_6009_B5CA,
+    ]);
+/// The negation of `2^256` times the accumulator offset, as little-endian 64-bit limbs.
+const ACCUMULATOR_OFFSET_CORRECTION: ([u64; 4], [u64; 4]) =
+    ([0x841B_646D_C6A3_781B, 0x3955_3DBD_3151_2342, 0x0DC8_7CCF_F5D8_F066, 0x6DB4_BF52_809A_E127], [
+        0xCB66_B8FF_BB1C_A188,
+        0x1773_A256_2DE1_296F,
+        0x20A8_7953_5963_7526,
+        0xFB52_4B3C_CAB1_D41A,
+    ]);
+
+/// The scalar field modulus (the group order) of secp256k1.
+struct ScalarField;
+
+impl NonNativeParameters for ScalarField {
+    const MODULUS: [u64; 4] =
+        [0xBFD2_5E8C_D036_4141, 0xBAAE_DCE6_AF48_A03B, 0xFFFF_FFFF_FFFF_FFFE, 0xFFFF_FFFF_FFFF_FFFF];
+}
+
+/// ECDSA over secp256k1, as used by Bitcoin and Ethereum.
+///
+/// The secp256k1 field elements are emulated with 64-bit limbs over the base field of the circuit.
+/// Public keys are encoded as the 64-byte concatenation of the big-endian affine coordinates `x || y`,
+/// and signatures as the 64-byte concatenation of the big-endian scalars `r || s`.
+/// As with the other gadgets, each byte is given as **little-endian** bits.
+pub struct ECDSA<E: Environment>(PhantomData<E>);
+
+impl<E: Environment> ECDSA<E> {
+    /// Initializes a new instance of ECDSA over secp256k1.
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<E: Environment> Default for ECDSA<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the integer encoded by the given big-endian bytes, of little-endian bits, as little-endian bits.
+fn bytes_to_bits_le<E: Environment>(bits: &[Boolean<E>]) -> Vec<Boolean<E>> {
+    bits.chunks(8).rev().flatten().cloned().collect()
+}
@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// An affine point on secp256k1, with reduced coordinates.
+/// Note: The point at infinity is not representable, so the group law is incomplete.
+#[derive(Clone)]
+pub(super) struct Point<E: Environment> {
+    /// The x-coordinate.
+    x: NonNativeField<E, Secp256k1BaseField>,
+    /// The y-coordinate.
+    y: NonNativeField<E, Secp256k1BaseField>,
+}
+
+impl<E: Environment> Point<E> {
+    /// Initializes a constant point from the given little-endian 64-bit limbs of its coordinates.
+    pub(super) fn constant(x: &[u64; 4], y: &[u64; 4]) -> Self {
+        Self { x: NonNativeField::constant(&from_limbs(x)), y: NonNativeField::constant(&from_limbs(y)) }
+    }
+
+    /// Initializes a point from the given coordinates, enforcing that it is on the curve.
+    /// Note: As secp256k1 has a cofactor of 1, every point on the curve is in the prime-order group.
+    pub(super) fn from_xy_coordinates(
+        x: NonNativeField<E, Secp256k1BaseField>,
+        y: NonNativeField<E, Secp256k1BaseField>,
+    ) -> Self {
+        let one = NonNativeField::one();
+        let b = NonNativeField::constant(&BigUint::from(SECP256K1_B));
+        // Ensure `y^2 == x^3 + B`.
+        let x2 = NonNativeField::sum_of_products(&[(&x, &x)], &[]);
+        NonNativeField::assert_sum_of_products_is_zero(&[(&y, &y)], &[(&x2, &x), (&b, &one)]);
+        Self { x, y }
+    }
+
+    /// Returns the x-coordinate of the point.
+    pub(super) fn x(&self) -> &NonNativeField<E, Secp256k1BaseField> {
+        &self.x
+    }
+
+    /// Returns `self + other`, enforcing that the points have distinct x-coordinates.
+    pub(super) fn add(&self, other: &Self) -> Self {
+        let mode = Self::witness_mode(&[self, other]);
+        let one = NonNativeField::one();
+        let modulus = NonNativeField::<E, Secp256k1BaseField>::modulus();
+
+        // Compute `lambda := (y2 - y1) / (x2 - x1)`, or zero if the x-coordinates are equal.
+        let (x1, y1, x2, y2) =
+            (self.x.eject_value(), self.y.eject_value(), other.x.eject_value(), other.y.eject_value());
+        let dx = (&x2 + &modulus - &x1) % &modulus;
+        let dx_inverse = dx.modpow(&(&modulus - BigUint::from(2u64)), &modulus);
+        let lambda = (&y2 + &modulus - &y1) * &dx_inverse % &modulus;
+
+        // Ensure `x2 - x1` is nonzero, by witnessing its inverse.
+        let dx_inverse = NonNativeField::new_witness(mode, &dx_inverse);
+        NonNativeField::assert_sum_of_products_is_zero(&[(&dx_inverse, &other.x)], &[
+            (&dx_inverse, &self.x),
+            (&one, &one),
+        ]);
+
+        // Ensure `lambda * (x2 - x1) == y2 - y1`.
+        let lambda = NonNativeField::new_witness(mode, &lambda);
+        NonNativeField::assert_sum_of_products_is_zero(&[(&lambda, &other.x), (&self.y, &one)], &[
+            (&lambda, &self.x),
+            (&other.y, &one),
+        ]);
+
+        // Compute `x3 := lambda^2 - x1 - x2` and `y3 := lambda * (x1 - x3) - y1`.
+        let x = NonNativeField::sum_of_products(&[(&lambda, &lambda)], &[(&self.x, &one), (&other.x, &one)]);
+        let y = NonNativeField::sum_of_products(&[(&lambda, &self.x)], &[(&lambda, &x), (&self.y, &one)]);
+        Self { x, y }
+    }
+
+    /// Returns `2 * self`.
+    /// Note: As secp256k1 has no points of order 2, the y-coordinate is always nonzero.
+    pub(super) fn double(&self) -> Self {
+        let mode = Self::witness_mode(&[self]);
+        let one = NonNativeField::one();
+        let modulus = NonNativeField::<E, Secp256k1BaseField>::modulus();
+
+        // Compute `lambda := 3 * x^2 / (2 * y)`.
+        let (x, y) = (self.x.eject_value(), self.y.eject_value());
+        let denominator = (&y << 1u32) % &modulus;
+        let lambda = &x * &x * 3u64 * denominator.modpow(&(&modulus - BigUint::from(2u64)), &modulus) % &modulus;
+
+        // Ensure `lambda * 2 * y == 3 * x^2`.
+        let lambda = NonNativeField::new_witness(mode, &lambda);
+        NonNativeField::assert_sum_of_products_is_zero(&[(&lambda, &self.y), (&lambda, &self.y)], &[
+            (&self.x, &self.x),
+            (&self.x, &self.x),
+            (&self.x, &self.x),
+        ]);
+
+        // Compute `x3 := lambda^2 - 2 * x` and `y3 := lambda * (x - x3) - y`.
+        let x = NonNativeField::sum_of_products(&[(&lambda, &lambda)], &[(&self.x, &one), (&self.x, &one)]);
+        let y = NonNativeField::sum_of_products(&[(&lambda, &self.x)], &[(&lambda, &x), (&self.y, &one)]);
+        Self { x, y }
+    }
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    pub(super) fn ternary(condition: &Boolean<E>, first: &Self, second: &Self) -> Self {
+        Self {
+            x: NonNativeField::ternary(condition, &first.x, &second.x),
+            y: NonNativeField::ternary(condition, &first.y, &second.y),
+        }
+    }
+
+    /// Returns `Mode::Constant` if all of the given points are constant, and `Mode::Private` otherwise.
+    fn witness_mode(points: &[&Self]) -> Mode {
+        match points.iter().all(|point| point.x.is_constant() && point.y.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
@@ -0,0 +1,173 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> ECDSA<E> {
+    /// Returns `true` if the signature is valid for the given public key and message digest.
+    /// The digest is the 32-byte hash of the message, such as the output of `Keccak256` or `Sha256`.
+    ///
+    /// The circuit is unsatisfiable if the public key is not a point on the curve, if the signature
+    /// scalars are not less than the group order, or if `u1 * G + u2 * Q` is the point at infinity.
+    pub fn verify(&self, public_key: &[Boolean<E>], digest: &[Boolean<E>], signature: &[Boolean<E>]) -> Boolean<E> {
+        // Ensure the inputs are of the expected length.
+        if public_key.len() != 512 || digest.len() != 256 || signature.len() != 512 {
+            E::halt("ECDSA expects a 64-byte public key, a 32-byte digest, and a 64-byte signature")
+        }
+
+        // Recover the public key, and ensure it is on the curve.
+        let x = NonNativeField::from_bits_le(&bytes_to_bits_le(&public_key[..256]));
+        let y = NonNativeField::from_bits_le(&bytes_to_bits_le(&public_key[256..]));
+        x.enforce_reduced();
+        y.enforce_reduced();
+        let public_key = Point::from_xy_coordinates(x, y);
+
+        // Recover the signature scalars, and ensure they are less than the group order.
+        let r = NonNativeField::<E, ScalarField>::from_bits_le(&bytes_to_bits_le(&signature[..256]));
+        let s = NonNativeField::<E, ScalarField>::from_bits_le(&bytes_to_bits_le(&signature[256..]));
+        r.enforce_reduced();
+        s.enforce_reduced();
+
+        // Ensure `r` and `s` are nonzero. To keep the circuit satisfiable, `s` is replaced by `1` when zero.
+        let zero = NonNativeField::zero();
+        let is_r_zero = r.is_equal(&zero);
+        let is_s_zero = s.is_equal(&zero);
+        let s = NonNativeField::ternary(&is_s_zero, &NonNativeField::one(), &s);
+
+        // Compute `u1 := z / s` and `u2 := r / s`, where `z` is the digest reduced modulo `n`.
+        let z = NonNativeField::<E, ScalarField>::from_bits_le(&bytes_to_bits_le(digest)).reduce();
+        let s_inverse = s.inverse();
+        let u1 = NonNativeField::sum_of_products(&[(&z, &s_inverse)], &[]);
+        let u2 = NonNativeField::sum_of_products(&[(&r, &s_inverse)], &[]);
+
+        // Compute `R := u1 * G + u2 * Q`.
+        let point = Self::double_scalar_mul(&u1, &u2, &public_key);
+
+        // Ensure `R.x mod n == r`.
+        let rx = NonNativeField::<E, ScalarField>::from_limbs_of(point.x()).reduce();
+        !is_r_zero & !is_s_zero & rx.is_equal(&r)
+    }
+
+    /// Returns `u1 * G + u2 * Q`, using a joint double-and-add over the bits of `u1` and `u2`.
+    ///
+    /// The accumulator starts at a fixed point of unknown discrete logarithm, which is removed at the end,
+    /// so that the incomplete addition law only fails with negligible probability for honest inputs.
+    fn double_scalar_mul(
+        u1: &NonNativeField<E, ScalarField>,
+        u2: &NonNativeField<E, ScalarField>,
+        public_key: &Point<E>,
+    ) -> Point<E> {
+        let generator = Point::constant(&SECP256K1_GENERATOR.0, &SECP256K1_GENERATOR.1);
+        let generator_plus_public_key = generator.add(public_key);
+
+        let mut accumulator = Point::constant(&ACCUMULATOR_OFFSET.0, &ACCUMULATOR_OFFSET.1);
+        for (bit_1, bit_2) in u1.to_bits_le().iter().rev().zip_eq(u2.to_bits_le().iter().rev()) {
+            accumulator = accumulator.double();
+            // Select the point to add, from `G`, `Q`, and `G + Q`.
+            let addend = Point::ternary(bit_1, &generator, public_key);
+            let addend = Point::ternary(&(bit_1 & bit_2), &generator_plus_public_key, &addend);
+            let sum = accumulator.add(&addend);
+            accumulator = Point::ternary(&(bit_1 | bit_2), &sum, &accumulator);
+        }
+
+        // Remove the accumulator offset, which is now `2^256` times the initial point.
+        accumulator.add(&Point::constant(&ACCUMULATOR_OFFSET_CORRECTION.0, &ACCUMULATOR_OFFSET_CORRECTION.1))
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    /// Returns the test vectors as `(public_key, digest, signature)`.
+    fn test_vectors() -> Vec<(Vec<u8>, Vec<u8>, Vec<u8>)> {
+        vec![
+            (
+                "2c8c31fc9f990c6b55e3865a184a4ce50e09481f2eaeb3e60ec1cea13a6ae64564b95e4fdb6948c0386e189b006a29f686769b011704275e4459822dc3328085",
+                "af2bdbe1aa9b6ec1e2ade1d694f41fc71a831d0268e9891562113d8a62add1bf",
+                "432310e32cb80eb6503a26ce83cc165c783b870845fb8aad6d970889fcd7a6c8530128b6b81c548874a6305d93ed071ca6e05074d85863d4056ce89b02bfab69",
+            ),
+            (
+                "f973a0b87062c389d125d8199e803b832b6ac6bf7867a4f6cd87506060fc4c584b4a0a3f26c988c54c236b224c48bb605b265949e65c098ecd87a581ca10e25d",
+                "0827be68960cbca55a732db7d63111c5970d47077867f17ef14852457d8552ef",
+                "8bf5447ae65c5ebbeb7e474cf4e8a5c255dbfd33763d93535bfb4d970de72fc467670d3fe9a1056ca29fae905dc16eb46a7d83f09a7d65b7f2a822b788342389",
+            ),
+        ]
+        .into_iter()
+        .map(|(public_key, digest, signature)| {
+            (hex_to_bytes(public_key), hex_to_bytes(digest), hex_to_bytes(signature))
+        })
+        .collect()
+    }
+
+    /// Returns the bytes of the given hex string.
+    fn hex_to_bytes(hex: &str) -> Vec<u8> {
+        (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect()
+    }
+
+    /// Returns the little-endian bits of each of the given bytes.
+    fn to_bits(bytes: &[u8]) -> Vec<bool> {
+        bytes.iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)).collect()
+    }
+
+    fn check_verify(mode: Mode, public_key: &[u8], digest: &[u8], signature: &[u8]) {
+        // Compute the expected result.
+        let expected = console::ECDSA::new().verify(
+            public_key.try_into().unwrap(),
+            digest.try_into().unwrap(),
+            signature.try_into().unwrap(),
+        );
+
+        // Inject the inputs.
+        let public_key: Vec<Boolean<_>> = Inject::new(mode, to_bits(public_key));
+        let digest: Vec<Boolean<_>> = Inject::new(mode, to_bits(digest));
+        let signature: Vec<Boolean<_>> = Inject::new(mode, to_bits(signature));
+
+        Circuit::scope(format!("ECDSA {mode}"), || {
+            let candidate = ECDSA::<Circuit>::new().verify(&public_key, &digest, &signature);
+            assert_eq!(expected, candidate.eject_value());
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_verify_constant() {
+        for (public_key, digest, signature) in test_vectors() {
+            check_verify(Mode::Constant, &public_key, &digest, &signature);
+        }
+    }
+
+    #[test]
+    fn test_verify_private() {
+        // Note: A single vector is checked, as the emulated arithmetic synthesizes millions of constraints.
+        let (public_key, digest, signature) = &test_vectors()[0];
+        check_verify(Mode::Private, public_key, digest, signature);
+    }
+
+    #[test]
+    fn test_verify_fails() {
+        for (public_key, mut digest, mut signature) in test_vectors() {
+            // Ensure a different digest fails.
+            digest[0] ^= 1;
+            check_verify(Mode::Constant, &public_key, &digest, &signature);
+            // Ensure a zero `s` fails.
+            signature[32..].iter_mut().for_each(|byte| *byte = 0);
+            check_verify(Mode::Constant, &public_key, &digest, &signature);
+        }
+    }
+}
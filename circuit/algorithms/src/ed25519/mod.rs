@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod point;
+mod verify;
+
+use crate::{
+    non_native::{from_limbs, Ed25519BaseField, NonNativeField, NonNativeParameters},
+    Hash,
+    Sha512,
+};
+use point::Point;
+
+use snarkvm_circuit_types::prelude::*;
+
+use core::marker::PhantomData;
+use num_bigint::BigUint;
+
+/// The coefficient `D` of edwards25519, in `-x^2 + y^2 = 1 + D * x^2 * y^2`, as little-endian 64-bit limbs.
+const ED25519_D: [u64; 4] =
+    [0x75EB_4DCA_1359_78A3, 0x0070_0A4D_4141_D8AB, 0x8CC7_4079_7779_E898, 0x5203_6CEE_2B6F_FE73];
+/// The affine base point of edwards25519, as little-endian 64-bit limbs.
+const ED25519_GENERATOR: ([u64; 4], [u64; 4]) =
+    ([0xC956_2D60_8F25_D51A, 0x692C_C760_9525_A7B2, 0xC0A4_E231_FDD6_DC5C, 0x2169_36D3_CD6E_53FE], [
+        0x6666_6666_6666_6658,
+        0x6666_6666_6666_6666,
+        0x6666_6666_6666_6666,
+        0x6666_6666_6666_6666,
+    ]);
+/// A square root of `-1` in the base field, as little-endian 64-bit limbs.
+const SQRT_MINUS_ONE: [u64; 4] =
+    [0xC4EE_1B27_4A0E_A0B0, 0x2F43_1806_AD2F_E478, 0x2B4D_0099_3DFB_D7A7, 0x2B83_2480_4FC1_DF0B];
+/// The value `2^256` modulo the subgroup order, as little-endian 64-bit limbs.
+const TWO_POW_256_MOD_ORDER: [u64; 4] =
+    [0xD6EC_3174_8D98_951D, 0xC6EF_5BF4_737D_CF70, 0xFFFF_FFFF_FFFF_FFFE, 0x0FFF_FFFF_FFFF_FFFF];
+/// The number of bits in the subgroup order.
+const ORDER_BITS: usize = 253;
+
+/// The order of the prime-order subgroup of edwards25519.
+struct ScalarField;
+
+impl NonNativeParameters for ScalarField {
+    const MODULUS: [u64; 4] =
+        [0x5812_631A_5CF5_D3ED, 0x14DE_F9DE_A2F7_9CD6, 0x0000_0000_0000_0000, 0x1000_0000_0000_0000];
+}
+
+/// Ed25519, the EdDSA signature scheme over edwards25519 with SHA-512, as specified in RFC 8032.
+///
+/// The edwards25519 field elements are emulated with 64-bit limbs over the base field of the circuit.
+/// Public keys and signatures follow the RFC 8032 encodings, and messages are byte strings,
+/// where each byte is given as **little-endian** bits.
+pub struct Ed25519<E: Environment>(PhantomData<E>);
+
+impl<E: Environment> Ed25519<E> {
+    /// Initializes a new instance of Ed25519.
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<E: Environment> Default for Ed25519<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,171 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// An affine point on edwards25519, with reduced coordinates.
+/// Note: The twisted Edwards addition law is complete, so there are no exceptional cases.
+#[derive(Clone)]
+pub(super) struct Point<E: Environment> {
+    /// The x-coordinate.
+    x: NonNativeField<E, Ed25519BaseField>,
+    /// The y-coordinate.
+    y: NonNativeField<E, Ed25519BaseField>,
+}
+
+impl<E: Environment> Point<E> {
+    /// Initializes a constant point from the given little-endian 64-bit limbs of its coordinates.
+    pub(super) fn constant(x: &[u64; 4], y: &[u64; 4]) -> Self {
+        Self { x: NonNativeField::constant(&from_limbs(x)), y: NonNativeField::constant(&from_limbs(y)) }
+    }
+
+    /// Returns the identity point `(0, 1)`.
+    pub(super) fn zero() -> Self {
+        Self { x: NonNativeField::zero(), y: NonNativeField::one() }
+    }
+
+    /// Decodes the point from its 256-bit compressed encoding, as in RFC 8032.
+    /// The circuit is unsatisfiable if the encoding is not a valid point.
+    pub(super) fn decompress(bits_le: &[Boolean<E>]) -> Self {
+        // Ensure the number of bits is correct.
+        if bits_le.len() != 256 {
+            E::halt(format!("Expected 256 bits for a compressed point, found {}", bits_le.len()))
+        }
+
+        // Recover the y-coordinate, and ensure it is reduced.
+        let y_bits = bits_le[..255].iter().cloned().chain([Boolean::constant(false)]).collect::<Vec<_>>();
+        let y = NonNativeField::from_bits_le(&y_bits);
+        y.enforce_reduced();
+        let is_odd = &bits_le[255];
+
+        // Witness the x-coordinate, or zero if there is no such point.
+        let mode = match y.is_constant() && is_odd.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        };
+        let x = recover_x(&y.eject_value(), is_odd.eject_value()).unwrap_or_default();
+        let x = NonNativeField::new_witness(mode, &x);
+        x.enforce_reduced();
+
+        // Ensure the sign of the x-coordinate matches.
+        E::assert_eq(&x.to_bits_le()[0], is_odd);
+
+        // Ensure `-x^2 + y^2 == 1 + D * x^2 * y^2`.
+        let one = NonNativeField::one();
+        let d = NonNativeField::constant(&from_limbs(&ED25519_D));
+        let x2 = NonNativeField::sum_of_products(&[(&x, &x)], &[]);
+        let y2 = NonNativeField::sum_of_products(&[(&y, &y)], &[]);
+        let dx2 = NonNativeField::sum_of_products(&[(&d, &x2)], &[]);
+        NonNativeField::assert_sum_of_products_is_zero(&[(&y2, &one)], &[(&x2, &one), (&one, &one), (&dx2, &y2)]);
+
+        Self { x, y }
+    }
+
+    /// Returns `-self`.
+    pub(super) fn neg(&self) -> Self {
+        Self { x: NonNativeField::sum_of_products(&[], &[(&self.x, &NonNativeField::one())]), y: self.y.clone() }
+    }
+
+    /// Returns `self + other`.
+    pub(super) fn add(&self, other: &Self) -> Self {
+        let mode = match [&self.x, &self.y, &other.x, &other.y].iter().all(|element| element.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        };
+        let one = NonNativeField::one();
+        let d = NonNativeField::constant(&from_limbs(&ED25519_D));
+
+        // Compute `x1 * x2`, `y1 * y2`, and `t := D * x1 * x2 * y1 * y2`.
+        let x1x2 = NonNativeField::sum_of_products(&[(&self.x, &other.x)], &[]);
+        let y1y2 = NonNativeField::sum_of_products(&[(&self.y, &other.y)], &[]);
+        let dx1x2 = NonNativeField::sum_of_products(&[(&d, &x1x2)], &[]);
+        let t = NonNativeField::sum_of_products(&[(&dx1x2, &y1y2)], &[]);
+
+        // Compute `x3 := (x1 * y2 + y1 * x2) / (1 + t)` and `y3 := (y1 * y2 + x1 * x2) / (1 - t)`.
+        let (x1, y1, x2, y2) =
+            (self.x.eject_value(), self.y.eject_value(), other.x.eject_value(), other.y.eject_value());
+        let modulus = NonNativeField::<E, Ed25519BaseField>::modulus();
+        let inverse = |value: BigUint| value.modpow(&(&modulus - BigUint::from(2u64)), &modulus);
+        let t_value = t.eject_value();
+        let x3 = (&x1 * &y2 + &y1 * &x2) * inverse((&t_value + 1u64) % &modulus) % &modulus;
+        let y3 = (&y1 * &y2 + &x1 * &x2) * inverse((&modulus + 1u64 - &t_value) % &modulus) % &modulus;
+
+        // Ensure `x3 + x3 * t == x1 * y2 + y1 * x2`.
+        let x3 = NonNativeField::new_witness(mode, &x3);
+        x3.enforce_reduced();
+        NonNativeField::assert_sum_of_products_is_zero(&[(&x3, &one), (&x3, &t)], &[
+            (&self.x, &other.y),
+            (&self.y, &other.x),
+        ]);
+
+        // Ensure `y3 - y3 * t == y1 * y2 + x1 * x2`.
+        let y3 = NonNativeField::new_witness(mode, &y3);
+        y3.enforce_reduced();
+        NonNativeField::assert_sum_of_products_is_zero(&[(&y3, &one)], &[(&y3, &t), (&y1y2, &one), (&x1x2, &one)]);
+
+        Self { x: x3, y: y3 }
+    }
+
+    /// Returns `2 * self`.
+    pub(super) fn double(&self) -> Self {
+        self.add(self)
+    }
+
+    /// Returns `true` if `self` and `other` are equal.
+    pub(super) fn is_equal(&self, other: &Self) -> Boolean<E> {
+        self.x.is_equal(&other.x) & self.y.is_equal(&other.y)
+    }
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    pub(super) fn ternary(condition: &Boolean<E>, first: &Self, second: &Self) -> Self {
+        Self {
+            x: NonNativeField::ternary(condition, &first.x, &second.x),
+            y: NonNativeField::ternary(condition, &first.y, &second.y),
+        }
+    }
+}
+
+/// Returns the x-coordinate of the point with the given y-coordinate and sign of x, if it exists.
+fn recover_x(y: &BigUint, is_odd: bool) -> Option<BigUint> {
+    let p = from_limbs(&Ed25519BaseField::MODULUS);
+    let d = from_limbs(&ED25519_D);
+    let zero = BigUint::default();
+    let inverse = |value: &BigUint| value.modpow(&(&p - BigUint::from(2u64)), &p);
+
+    // Compute `x^2 := (y^2 - 1) / (D * y^2 + 1)`.
+    let y2 = y * y % &p;
+    let x2 = (&y2 + &p - 1u64) * inverse(&((&d * &y2 + 1u64) % &p)) % &p;
+    if x2 == zero {
+        return match is_odd {
+            true => None,
+            false => Some(zero),
+        };
+    }
+
+    // Compute the square root of `x^2`, as `p = 5 mod 8`.
+    let mut x = x2.modpow(&((&p + 3u64) >> 3), &p);
+    if (&x * &x) % &p != x2 {
+        x = x * from_limbs(&SQRT_MINUS_ONE) % &p;
+    }
+    if (&x * &x) % &p != x2 {
+        return None;
+    }
+    // Select the root with the given sign.
+    match (&x % 2u64 == BigUint::from(1u64)) == is_odd {
+        true => Some(x),
+        false => Some(&p - x),
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Ed25519<E> {
+    /// Returns `true` if the signature is valid for the given public key and message.
+    /// Note: This checks the cofactorless verification equation `[S]B == R + [k]A`, as the console does.
+    ///
+    /// The circuit is unsatisfiable if the public key or `R` are not valid point encodings,
+    /// or if `S` is not less than the subgroup order.
+    pub fn verify(&self, public_key: &[Boolean<E>], message: &[Boolean<E>], signature: &[Boolean<E>]) -> Boolean<E> {
+        // Ensure the inputs are of the expected length.
+        if public_key.len() != 256 || message.len() % 8 != 0 || signature.len() != 512 {
+            E::halt("Ed25519 expects a 32-byte public key, a byte string message, and a 64-byte signature")
+        }
+
+        // Decode the public key and the `R` component of the signature.
+        let public_key_point = Point::decompress(public_key);
+        let r = Point::decompress(&signature[..256]);
+
+        // Recover the `S` component of the signature, and ensure it is less than the subgroup order.
+        let s = NonNativeField::<E, ScalarField>::from_bits_le(&signature[256..]);
+        s.enforce_reduced();
+
+        // Compute `k := SHA-512(R || A || M)`, as a little-endian integer modulo the subgroup order.
+        let preimage = signature[..256].iter().chain(public_key).chain(message).cloned().collect::<Vec<_>>();
+        let hash = Sha512::new().hash(&preimage);
+        let (lower, upper) = (NonNativeField::from_bits_le(&hash[..256]), NonNativeField::from_bits_le(&hash[256..]));
+        let two_pow_256 = NonNativeField::constant(&from_limbs(&TWO_POW_256_MOD_ORDER));
+        let k = NonNativeField::<E, ScalarField>::sum_of_products(
+            &[(&lower, &NonNativeField::one()), (&upper, &two_pow_256)],
+            &[],
+        );
+
+        // Ensure `[S]B + [k](-A) == R`.
+        let candidate = Self::double_scalar_mul(&s, &k, &public_key_point.neg());
+        candidate.is_equal(&r)
+    }
+
+    /// Returns `s * B + k * point`, using a joint double-and-add over the bits of `s` and `k`.
+    /// Note: Both scalars must be reduced, so that only the lower bits of the subgroup order are used.
+    fn double_scalar_mul(
+        s: &NonNativeField<E, ScalarField>,
+        k: &NonNativeField<E, ScalarField>,
+        point: &Point<E>,
+    ) -> Point<E> {
+        let generator = Point::constant(&ED25519_GENERATOR.0, &ED25519_GENERATOR.1);
+        let generator_plus_point = generator.add(point);
+        let zero = Point::zero();
+
+        let s_bits = s.to_bits_le();
+        let k_bits = k.to_bits_le();
+        let mut accumulator = Point::zero();
+        for (bit_s, bit_k) in s_bits[..ORDER_BITS].iter().rev().zip_eq(k_bits[..ORDER_BITS].iter().rev()) {
+            // Select the point to add, from the identity, `B`, `point`, and `B + point`.
+            let addend_if_s = Point::ternary(bit_k, &generator_plus_point, &generator);
+            let addend_if_not_s = Point::ternary(bit_k, point, &zero);
+            let addend = Point::ternary(bit_s, &addend_if_s, &addend_if_not_s);
+            accumulator = accumulator.double().add(&addend);
+        }
+        accumulator
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    /// Returns the test vectors from RFC 8032, as `(public_key, message, signature)`.
+    fn test_vectors() -> Vec<(Vec<u8>, Vec<u8>, Vec<u8>)> {
+        vec![
+            (
+                "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+                "",
+                "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+            ),
+            (
+                "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+                "72",
+                "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+            ),
+        ]
+        .into_iter()
+        .map(|(public_key, message, signature)| {
+            (hex_to_bytes(public_key), hex_to_bytes(message), hex_to_bytes(signature))
+        })
+        .collect()
+    }
+
+    /// Returns the bytes of the given hex string.
+    fn hex_to_bytes(hex: &str) -> Vec<u8> {
+        (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect()
+    }
+
+    /// Returns the little-endian bits of each of the given bytes.
+    fn to_bits(bytes: &[u8]) -> Vec<bool> {
+        bytes.iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)).collect()
+    }
+
+    fn check_verify(mode: Mode, public_key: &[u8], message: &[u8], signature: &[u8]) {
+        // Compute the expected result.
+        let expected =
+            console::Ed25519::new().verify(public_key.try_into().unwrap(), message, signature.try_into().unwrap());
+
+        // Inject the inputs.
+        let public_key: Vec<Boolean<_>> = Inject::new(mode, to_bits(public_key));
+        let message: Vec<Boolean<_>> = Inject::new(mode, to_bits(message));
+        let signature: Vec<Boolean<_>> = Inject::new(mode, to_bits(signature));
+
+        Circuit::scope(format!("Ed25519 {mode}"), || {
+            let candidate = Ed25519::<Circuit>::new().verify(&public_key, &message, &signature);
+            assert_eq!(expected, candidate.eject_value());
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_verify_constant() {
+        for (public_key, message, signature) in test_vectors() {
+            check_verify(Mode::Constant, &public_key, &message, &signature);
+        }
+    }
+
+    #[test]
+    fn test_verify_private() {
+        // Note: A single vector is checked, as the emulated arithmetic synthesizes millions of constraints.
+        let (public_key, message, signature) = &test_vectors()[0];
+        check_verify(Mode::Private, public_key, message, signature);
+    }
+
+    #[test]
+    fn test_verify_fails() {
+        for (public_key, mut message, signature) in test_vectors() {
+            // Ensure a different message fails.
+            message.push(0);
+            check_verify(Mode::Constant, &public_key, &message, &signature);
+        }
+    }
+}
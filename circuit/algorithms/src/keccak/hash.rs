@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Hash for Keccak256<E> {
+    type Input = Boolean<E>;
+    type Output = Vec<Boolean<E>>;
+
+    /// Returns the Keccak-256 hash of the given input as a list of **little-endian** bits.
+    /// A byte string is hashed by passing each byte as little-endian bits, in order.
+    fn hash(&self, input: &[Self::Input]) -> Self::Output {
+        // Pad the input with `10*1`, to a multiple of the rate.
+        let num_padded_bits = (input.len() + 1 + KECCAK_256_RATE) / KECCAK_256_RATE * KECCAK_256_RATE;
+        let mut padded = Vec::with_capacity(num_padded_bits);
+        padded.extend_from_slice(input);
+        padded.push(Boolean::constant(true));
+        padded.resize(num_padded_bits - 1, Boolean::constant(false));
+        padded.push(Boolean::constant(true));
+
+        // Absorb each block into the state.
+        let mut state: [Vec<Boolean<E>>; 25] = Default::default();
+        state.iter_mut().for_each(|lane| *lane = vec![Boolean::constant(false); 64]);
+        for block in padded.chunks(KECCAK_256_RATE) {
+            for (lane, bits) in state.iter_mut().zip(block.chunks(64)) {
+                lane.iter_mut().zip_eq(bits).for_each(|(lane_bit, bit)| *lane_bit ^= bit);
+            }
+            Self::keccak_f(&mut state);
+        }
+
+        // Squeeze the output from the state.
+        state.into_iter().flatten().take(KECCAK_256_OUTPUT).collect()
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 3;
+
+    fn check_hash(mode: Mode, num_input_bits: usize) -> Result<()> {
+        use console::Hash as H;
+
+        let native = console::Keccak256::new();
+        let circuit = Keccak256::<Circuit>::new();
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let input = (0..num_input_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = native.hash(&input).expect("Failed to hash native input");
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("Keccak256 {mode} {i}"), || {
+                // Perform the hash operation.
+                let candidate = circuit.hash(&circuit_input);
+                assert_eq!(expected, candidate.eject_value());
+                if mode.is_constant() {
+                    assert!(candidate.iter().all(|bit| bit.is_constant()));
+                }
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_constant() -> Result<()> {
+        for num_input_bits in [0, 8, 1087, 1088, 2048] {
+            check_hash(Mode::Constant, num_input_bits)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_public() -> Result<()> {
+        for num_input_bits in [8, 1087, 1088, 2048] {
+            check_hash(Mode::Public, num_input_bits)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_private() -> Result<()> {
+        for num_input_bits in [8, 1087, 1088, 2048] {
+            check_hash(Mode::Private, num_input_bits)?;
+        }
+        Ok(())
+    }
+}
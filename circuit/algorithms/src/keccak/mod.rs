@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod hash;
+
+use crate::Hash;
+use snarkvm_circuit_types::prelude::*;
+
+use core::marker::PhantomData;
+
+/// The round constants for the iota step, indexed by round.
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000_0000_0000_0001,
+    0x0000_0000_0000_8082,
+    0x8000_0000_0000_808A,
+    0x8000_0000_8000_8000,
+    0x0000_0000_0000_808B,
+    0x0000_0000_8000_0001,
+    0x8000_0000_8000_8081,
+    0x8000_0000_0000_8009,
+    0x0000_0000_0000_008A,
+    0x0000_0000_0000_0088,
+    0x0000_0000_8000_8009,
+    0x0000_0000_8000_000A,
+    0x0000_0000_8000_808B,
+    0x8000_0000_0000_008B,
+    0x8000_0000_0000_8089,
+    0x8000_0000_0000_8003,
+    0x8000_0000_0000_8002,
+    0x8000_0000_0000_0080,
+    0x0000_0000_0000_800A,
+    0x8000_0000_8000_000A,
+    0x8000_0000_8000_8081,
+    0x8000_0000_0000_8080,
+    0x0000_0000_8000_0001,
+    0x8000_0000_8000_8008,
+];
+
+/// The rotation offsets for the rho step, indexed by `x + 5 * y`.
+const ROTATION_OFFSETS: [usize; 25] =
+    [0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14];
+
+/// The rate of Keccak-256 in bits.
+const KECCAK_256_RATE: usize = 1088;
+/// The number of output bits of Keccak-256.
+const KECCAK_256_OUTPUT: usize = 256;
+
+/// Keccak-256 is the Keccak sponge with a rate of 1088 bits and a 256-bit output,
+/// as used in Ethereum. It uses the original Keccak padding, and not the SHA-3 padding.
+pub struct Keccak256<E: Environment>(PhantomData<E>);
+
+impl<E: Environment> Keccak256<E> {
+    /// Initializes a new instance of Keccak-256.
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+
+    /// Applies the Keccak-f\[1600\] permutation to the given state, indexed by `x + 5 * y`.
+    /// Each lane is represented as 64 **little-endian** bits.
+    fn keccak_f(state: &mut [Vec<Boolean<E>>; 25]) {
+        for round_constant in ROUND_CONSTANTS {
+            // Theta step.
+            let c = (0..5)
+                .map(|x| {
+                    (0..64)
+                        .map(|i| {
+                            &(&(&(&state[x][i] ^ &state[x + 5][i]) ^ &state[x + 10][i]) ^ &state[x + 15][i])
+                                ^ &state[x + 20][i]
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+            for x in 0..5 {
+                let d = (0..64).map(|i| &c[(x + 4) % 5][i] ^ &c[(x + 1) % 5][(i + 63) % 64]).collect::<Vec<_>>();
+                for y in 0..5 {
+                    state[x + 5 * y].iter_mut().zip_eq(&d).for_each(|(bit, d)| *bit ^= d);
+                }
+            }
+
+            // Rho and pi steps.
+            let mut b: [Vec<Boolean<E>>; 25] = Default::default();
+            for x in 0..5 {
+                for y in 0..5 {
+                    let offset = ROTATION_OFFSETS[x + 5 * y];
+                    b[y + 5 * ((2 * x + 3 * y) % 5)] =
+                        (0..64).map(|i| state[x + 5 * y][(i + 64 - offset) % 64].clone()).collect();
+                }
+            }
+
+            // Chi step.
+            for x in 0..5 {
+                for y in 0..5 {
+                    state[x + 5 * y] = (0..64)
+                        .map(|i| &b[x + 5 * y][i] ^ &(&!&b[(x + 1) % 5 + 5 * y][i] & &b[(x + 2) % 5 + 5 * y][i]))
+                        .collect();
+                }
+            }
+
+            // Iota step.
+            for (i, bit) in state[0].iter_mut().enumerate() {
+                if (round_constant >> i) & 1 == 1 {
+                    *bit = !&*bit;
+                }
+            }
+        }
+    }
+}
+
+impl<E: Environment> Default for Keccak256<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
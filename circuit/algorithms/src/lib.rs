@@ -20,14 +20,38 @@
 pub mod bhp;
 pub use bhp::*;
 
+pub mod blake3;
+pub use blake3::Blake3;
+
+pub mod ecdsa;
+pub use ecdsa::ECDSA;
+
+pub mod ed25519;
+pub use ed25519::Ed25519;
+
 pub mod elligator2;
 pub use elligator2::Elligator2;
 
+pub mod keccak;
+pub use keccak::Keccak256;
+
+pub mod non_native;
+pub use non_native::{Bn254BaseField, Ed25519BaseField, NonNativeField, NonNativeParameters, Secp256k1BaseField};
+
 pub mod pedersen;
 pub use pedersen::*;
 
 pub mod poseidon;
 pub use poseidon::*;
 
+pub mod poseidon_v2;
+pub use poseidon_v2::*;
+
+pub mod sha256;
+pub use sha256::Sha256;
+
+pub mod sha512;
+pub use sha512::Sha512;
+
 pub mod traits;
 pub use traits::*;
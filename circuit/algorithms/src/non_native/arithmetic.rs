@@ -0,0 +1,172 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, P: NonNativeParameters> NonNativeField<E, P> {
+    /// Returns the reduced element `self + other`.
+    pub fn add(&self, other: &Self) -> Self {
+        let one = Self::one();
+        Self::sum_of_products(&[(self, &one), (other, &one)], &[])
+    }
+
+    /// Returns the reduced element `self - other`.
+    pub fn sub(&self, other: &Self) -> Self {
+        let one = Self::one();
+        Self::sum_of_products(&[(self, &one)], &[(other, &one)])
+    }
+
+    /// Returns the reduced element `-self`.
+    pub fn neg(&self) -> Self {
+        Self::sum_of_products(&[], &[(self, &Self::one())])
+    }
+
+    /// Returns the reduced element `self * other`.
+    pub fn mul(&self, other: &Self) -> Self {
+        Self::sum_of_products(&[(self, other)], &[])
+    }
+
+    /// Returns the reduced element `self * self`.
+    pub fn square(&self) -> Self {
+        Self::sum_of_products(&[(self, self)], &[])
+    }
+
+    /// Returns the reduced inverse of `self`, enforcing that `self` is nonzero modulo the modulus.
+    pub fn inverse(&self) -> Self {
+        // Compute the inverse of `self`, or zero if `self` is zero.
+        let modulus = Self::modulus();
+        let inverse = self.eject_value().modpow(&(&modulus - BigUint::from(2u64)), &modulus);
+        let inverse = Self::new_witness(Self::witness_mode(&[self]), &inverse);
+        // Ensure `self * inverse == 1`.
+        Self::assert_sum_of_products_is_zero(&[(self, &inverse)], &[(&Self::one(), &Self::one())]);
+        // Ensure the inverse is reduced.
+        inverse.enforce_reduced();
+        inverse
+    }
+
+    /// Returns the reduced element `self` modulo the modulus.
+    pub fn reduce(&self) -> Self {
+        Self::sum_of_products(&[(self, &Self::one())], &[])
+    }
+
+    /// Returns the reduced element `Σ a_i * b_i - Σ c_j * d_j` modulo the modulus,
+    /// given the `positive` products `(a_i, b_i)` and the `negative` products `(c_j, d_j)`.
+    ///
+    /// This costs a single reduction, so gadgets should prefer it over chaining `add` and `mul`.
+    pub fn sum_of_products(positive: &[(&Self, &Self)], negative: &[(&Self, &Self)]) -> Self {
+        let remainder = Self::enforce_sum_of_products(positive, negative, true);
+        // Ensure the remainder is reduced.
+        remainder.enforce_reduced();
+        remainder
+    }
+
+    /// Enforces that `Σ a_i * b_i - Σ c_j * d_j` is zero modulo the modulus,
+    /// given the `positive` products `(a_i, b_i)` and the `negative` products `(c_j, d_j)`.
+    pub fn assert_sum_of_products_is_zero(positive: &[(&Self, &Self)], negative: &[(&Self, &Self)]) {
+        Self::enforce_sum_of_products(positive, negative, false);
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    /// Returns the test values for the given modulus.
+    fn test_values(modulus: &BigUint) -> Vec<BigUint> {
+        vec![
+            BigUint::default(),
+            BigUint::from(1u64),
+            BigUint::from(u64::MAX),
+            modulus - BigUint::from(1u64),
+            modulus - BigUint::from(2u64),
+            modulus >> 1,
+            BigUint::from(0x0123_4567_89ab_cdefu64) << 130,
+        ]
+    }
+
+    fn check_arithmetic<P: NonNativeParameters>(mode: Mode) {
+        let modulus = NonNativeField::<Circuit, P>::modulus();
+        for a in test_values(&modulus) {
+            for b in test_values(&modulus) {
+                let first = NonNativeField::<Circuit, P>::new(mode, &a);
+                let second = NonNativeField::<Circuit, P>::new(mode, &b);
+
+                Circuit::scope(format!("NonNativeField {mode}"), || {
+                    assert_eq!((&a + &b) % &modulus, first.add(&second).eject_value());
+                    assert_eq!((&a + &modulus - &b) % &modulus, first.sub(&second).eject_value());
+                    assert_eq!((&modulus - &a) % &modulus, first.neg().eject_value());
+                    assert_eq!((&a * &b) % &modulus, first.mul(&second).eject_value());
+                    assert_eq!((&a * &a) % &modulus, first.square().eject_value());
+                    assert_eq!(a == b, first.is_equal(&second).eject_value());
+                });
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+    }
+
+    fn check_inverse<P: NonNativeParameters>(mode: Mode) {
+        let modulus = NonNativeField::<Circuit, P>::modulus();
+        for a in test_values(&modulus) {
+            let element = NonNativeField::<Circuit, P>::new(mode, &a);
+
+            Circuit::scope(format!("NonNativeField::inverse {mode}"), || {
+                let inverse = element.inverse();
+                if a != BigUint::default() {
+                    assert_eq!(BigUint::from(1u64), (&a * inverse.eject_value()) % &modulus);
+                }
+            });
+            // Ensure the circuit is unsatisfiable if and only if the element is zero.
+            assert_eq!(a != BigUint::default(), Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_arithmetic::<Secp256k1BaseField>(mode);
+            check_arithmetic::<Bn254BaseField>(mode);
+            check_arithmetic::<Ed25519BaseField>(mode);
+        }
+    }
+
+    #[test]
+    fn test_inverse() {
+        for mode in [Mode::Public, Mode::Private] {
+            check_inverse::<Secp256k1BaseField>(mode);
+            check_inverse::<Bn254BaseField>(mode);
+            check_inverse::<Ed25519BaseField>(mode);
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_is_not_reduced() {
+        // Ensure an unreduced element is reduced by `reduce`.
+        let modulus = NonNativeField::<Circuit, Secp256k1BaseField>::modulus();
+        let value = &modulus + BigUint::from(5u64);
+        let bits = (0..256).map(|i| Boolean::new(Mode::Private, (&value >> i) % 2u64 == BigUint::from(1u64)));
+        let element = NonNativeField::<Circuit, Secp256k1BaseField>::from_bits_le(&bits.collect::<Vec<_>>());
+        assert_eq!(BigUint::from(5u64), element.reduce().eject_value());
+        assert!(Circuit::is_satisfied());
+
+        // Ensure an unreduced element fails `enforce_reduced`.
+        element.enforce_reduced();
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
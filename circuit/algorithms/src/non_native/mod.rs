@@ -0,0 +1,195 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod arithmetic;
+mod parameters;
+mod reduce;
+
+pub use parameters::*;
+
+use snarkvm_circuit_types::prelude::*;
+
+use core::marker::PhantomData;
+use num_bigint::{BigInt, BigUint};
+
+/// The number of bits in each limb of a non-native field element.
+const LIMB_BITS: usize = 64;
+/// The number of limbs in a non-native field element.
+const NUM_LIMBS: usize = 4;
+/// The offset added to each carry of the limb-wise reduction, so that it is nonnegative.
+/// Note: The carries are bounded by `2^72` in magnitude, for the supported number of terms.
+const CARRY_OFFSET_BITS: usize = 80;
+/// The maximum number of products in a single reduction.
+const MAX_TERMS: usize = 16;
+
+/// An element of the prime field defined by `P`, emulated as four little-endian 64-bit limbs
+/// over the native field of the environment.
+///
+/// Each limb is range-checked to 64 bits. An element is *reduced* if it is less than the modulus.
+/// The results of the arithmetic operations are reduced, while elements from `from_bits_le`
+/// may not be, and must be reduced with `reduce` or `enforce_reduced` before they are compared.
+pub struct NonNativeField<E: Environment, P: NonNativeParameters> {
+    /// The little-endian 64-bit limbs of the element.
+    limbs: Vec<Field<E>>,
+    /// PhantomData.
+    _parameters: PhantomData<P>,
+}
+
+impl<E: Environment, P: NonNativeParameters> NonNativeField<E, P> {
+    /// Returns the modulus as an integer.
+    pub fn modulus() -> BigUint {
+        from_limbs(&P::MODULUS)
+    }
+
+    /// Initializes a new element of the given mode from the given integer, reduced modulo the modulus.
+    /// The limbs of the element are range-checked, and the element is enforced to be reduced.
+    pub fn new(mode: Mode, value: &BigUint) -> Self {
+        let element = Self::new_witness(mode, value);
+        if !mode.is_constant() {
+            element.enforce_reduced();
+        }
+        element
+    }
+
+    /// Initializes a constant element from the given integer, reduced modulo the modulus.
+    pub fn constant(value: &BigUint) -> Self {
+        let limbs = to_limbs(&(value % Self::modulus()), NUM_LIMBS);
+        Self::from_field_limbs(limbs.into_iter().map(|limb| Field::constant(console::Field::from_u64(limb))).collect())
+    }
+
+    /// Returns the constant `0`.
+    pub fn zero() -> Self {
+        Self::constant(&BigUint::default())
+    }
+
+    /// Returns the constant `1`.
+    pub fn one() -> Self {
+        Self::constant(&BigUint::from(1u64))
+    }
+
+    /// Initializes an element from its 256 **little-endian** bits.
+    /// Note: The element is not ensured to be reduced.
+    pub fn from_bits_le(bits_le: &[Boolean<E>]) -> Self {
+        // Ensure the number of bits is correct.
+        if bits_le.len() != NUM_LIMBS * LIMB_BITS {
+            E::halt(format!(
+                "Expected {} bits for a non-native field element, found {}",
+                NUM_LIMBS * LIMB_BITS,
+                bits_le.len()
+            ))
+        }
+        Self::from_field_limbs(bits_le.chunks(LIMB_BITS).map(Field::from_bits_le).collect())
+    }
+
+    /// Initializes an element with the limbs of the given element, from another non-native field.
+    /// Note: The element is not ensured to be reduced.
+    pub fn from_limbs_of<Q: NonNativeParameters>(other: &NonNativeField<E, Q>) -> Self {
+        Self::from_field_limbs(other.limbs.clone())
+    }
+
+    /// Returns the 256 **little-endian** bits of the element.
+    pub fn to_bits_le(&self) -> Vec<Boolean<E>> {
+        self.limbs.iter().flat_map(|limb| limb.to_lower_bits_le(LIMB_BITS)).collect()
+    }
+
+    /// Returns `true` if the element is a constant.
+    pub fn is_constant(&self) -> bool {
+        self.limbs.iter().all(|limb| limb.is_constant())
+    }
+
+    /// Returns the integer value of the element.
+    pub fn eject_value(&self) -> BigUint {
+        from_limbs(&self.limbs.iter().map(|limb| to_u128::<E>(&limb.eject_value()) as u64).collect::<Vec<_>>())
+    }
+
+    /// Returns `true` if `self` and `other` are equal.
+    /// Note: Both elements must be reduced.
+    pub fn is_equal(&self, other: &Self) -> Boolean<E> {
+        self.limbs
+            .iter()
+            .zip_eq(&other.limbs)
+            .fold(Boolean::constant(true), |is_equal, (a, b)| is_equal & a.is_equal(b))
+    }
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    pub fn ternary(condition: &Boolean<E>, first: &Self, second: &Self) -> Self {
+        Self::from_field_limbs(
+            first.limbs.iter().zip_eq(&second.limbs).map(|(a, b)| Field::ternary(condition, a, b)).collect(),
+        )
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> NonNativeField<E, P> {
+    /// Initializes a new witness element from the given integer, reduced modulo the modulus.
+    /// Note: The limbs of the witness are range-checked, but the witness is not ensured to be reduced.
+    pub(crate) fn new_witness(mode: Mode, value: &BigUint) -> Self {
+        let limbs = to_limbs(&(value % Self::modulus()), NUM_LIMBS);
+        Self::from_field_limbs(limbs.into_iter().map(|limb| new_range_checked(mode, limb.into(), LIMB_BITS)).collect())
+    }
+
+    /// Initializes an element from the given limbs.
+    fn from_field_limbs(limbs: Vec<Field<E>>) -> Self {
+        Self { limbs, _parameters: PhantomData }
+    }
+
+    /// Returns `Mode::Constant` if all of the given elements are constant, and `Mode::Private` otherwise.
+    fn witness_mode(elements: &[&Self]) -> Mode {
+        match elements.iter().all(|element| element.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> Clone for NonNativeField<E, P> {
+    fn clone(&self) -> Self {
+        Self::from_field_limbs(self.limbs.clone())
+    }
+}
+
+/// Returns the integer represented by the given little-endian 64-bit limbs.
+pub(crate) fn from_limbs(limbs: &[u64]) -> BigUint {
+    limbs.iter().rev().fold(BigUint::default(), |value, limb| (value << LIMB_BITS) + *limb)
+}
+
+/// Returns the given number of little-endian 64-bit limbs of the given integer.
+fn to_limbs(value: &BigUint, num_limbs: usize) -> Vec<u64> {
+    let mut limbs = value.to_u64_digits();
+    limbs.resize(num_limbs, 0);
+    limbs
+}
+
+/// Returns the lower 128 bits of the given field element.
+fn to_u128<E: Environment>(value: &console::Field<E::Network>) -> u128 {
+    value.to_bits_le().iter().take(128).rev().fold(0u128, |value, bit| (value << 1) | (*bit as u128))
+}
+
+/// Returns the given nonnegative integer as a `u128`, or zero if it is out of range.
+fn to_u128_from_bigint(value: &BigInt) -> u128 {
+    match value.to_biguint().map(|value| value.to_u64_digits()) {
+        Some(digits) if digits.len() <= 2 => {
+            digits.iter().rev().fold(0u128, |value, digit| (value << 64) | *digit as u128)
+        }
+        _ => 0,
+    }
+}
+
+/// Returns a new witness of the given value, range-checked to the given number of bits.
+fn new_range_checked<E: Environment>(mode: Mode, value: u128, num_bits: usize) -> Field<E> {
+    let field = Field::new(mode, console::Field::from_u128(value));
+    field.to_lower_bits_le(num_bits);
+    field
+}
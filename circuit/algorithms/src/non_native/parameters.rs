@@ -0,0 +1,45 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+/// The parameters of a non-native prime field, with a modulus of at most 256 bits.
+pub trait NonNativeParameters {
+    /// The modulus, as little-endian 64-bit limbs.
+    const MODULUS: [u64; 4];
+}
+
+/// The base field of secp256k1, with modulus `2^256 - 2^32 - 977`.
+pub struct Secp256k1BaseField;
+
+impl NonNativeParameters for Secp256k1BaseField {
+    const MODULUS: [u64; 4] =
+        [0xFFFF_FFFE_FFFF_FC2F, 0xFFFF_FFFF_FFFF_FFFF, 0xFFFF_FFFF_FFFF_FFFF, 0xFFFF_FFFF_FFFF_FFFF];
+}
+
+/// The base field of BN254 (alt_bn128).
+pub struct Bn254BaseField;
+
+impl NonNativeParameters for Bn254BaseField {
+    const MODULUS: [u64; 4] =
+        [0x3C20_8C16_D87C_FD47, 0x9781_6A91_6871_CA8D, 0xB850_45B6_8181_585D, 0x3064_4E72_E131_A029];
+}
+
+/// The base field of edwards25519, with modulus `2^255 - 19`.
+pub struct Ed25519BaseField;
+
+impl NonNativeParameters for Ed25519BaseField {
+    const MODULUS: [u64; 4] =
+        [0xFFFF_FFFF_FFFF_FFED, 0xFFFF_FFFF_FFFF_FFFF, 0xFFFF_FFFF_FFFF_FFFF, 0x7FFF_FFFF_FFFF_FFFF];
+}
@@ -0,0 +1,172 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, P: NonNativeParameters> NonNativeField<E, P> {
+    /// Enforces that `self` is less than the modulus, by witnessing `d := (modulus - 1) - self`
+    /// and enforcing `self + d == modulus - 1` limb-wise, where `d` is range-checked.
+    pub fn enforce_reduced(&self) {
+        let mode = Self::witness_mode(&[self]);
+        let modulus_minus_one = Self::modulus() - BigUint::from(1u64);
+        let limbs = to_limbs(&modulus_minus_one, NUM_LIMBS);
+
+        // Compute the difference, or zero if `self` is not reduced.
+        let value = self.eject_value();
+        let difference = match value <= modulus_minus_one {
+            true => to_limbs(&(&modulus_minus_one - value), NUM_LIMBS),
+            false => vec![0; NUM_LIMBS],
+        };
+
+        let two_pow_limb_bits = Field::constant(console::Field::from_u128(1u128 << LIMB_BITS));
+        let mut carry_in = (Field::zero(), 0u128);
+        for (i, ((a, d), m)) in self.limbs.iter().zip_eq(difference).zip_eq(limbs).enumerate() {
+            // Witness the difference limb.
+            let d_limb = new_range_checked(mode, d.into(), LIMB_BITS);
+            // Compute the carry out of this limb.
+            let sum = to_u128::<E>(&a.eject_value()) + u128::from(d) + carry_in.1;
+            let carry_out = match i == NUM_LIMBS - 1 {
+                true => (Field::zero(), 0),
+                false => (Field::from_boolean(&Boolean::new(mode, sum >> LIMB_BITS == 1)), sum >> LIMB_BITS),
+            };
+            // Ensure `a + d + carry_in == m + carry_out * 2^64`.
+            E::assert_eq(
+                a + &d_limb + &carry_in.0,
+                Field::constant(console::Field::from_u64(m)) + &carry_out.0 * &two_pow_limb_bits,
+            );
+            carry_in = carry_out;
+        }
+    }
+
+    /// Enforces `Σ a_i * b_i - Σ c_j * d_j == q * modulus + r` over the integers, and returns `r`.
+    /// If `has_remainder` is `false`, then `r` is enforced to be zero.
+    ///
+    /// To ensure the left-hand side is nonnegative, a multiple of the modulus, `k * modulus`,
+    /// that exceeds the negative products is added to both sides. The identity is then enforced
+    /// column-by-column on the 64-bit limbs, with range-checked carries between the columns.
+    pub(super) fn enforce_sum_of_products(
+        positive: &[(&Self, &Self)],
+        negative: &[(&Self, &Self)],
+        has_remainder: bool,
+    ) -> Self {
+        // Ensure the number of terms is within the supported bound.
+        if positive.len() + negative.len() > MAX_TERMS {
+            E::halt(format!("NonNativeField field arithmetic supports at most {MAX_TERMS} products"))
+        }
+
+        let modulus = Self::modulus();
+        let modulus_limbs = P::MODULUS.iter().map(|limb| BigInt::from(*limb)).collect::<Vec<_>>();
+
+        // Determine the mode of the witnesses.
+        let mode = match positive.iter().chain(negative).all(|(a, b)| a.is_constant() && b.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        };
+
+        // Compute the multiple of the modulus, `k * modulus`, that exceeds the negative products.
+        let max_product = BigUint::from(1u64) << (2 * NUM_LIMBS * LIMB_BITS);
+        let offset =
+            (BigUint::from(negative.len()) * &max_product + &modulus - BigUint::from(1u64)) / &modulus * &modulus;
+        // Compute the number of limbs in the quotient.
+        let max_quotient = (BigUint::from(positive.len()) * &max_product + &offset) / &modulus;
+        let num_quotient_limbs = ((max_quotient.bits() as usize + LIMB_BITS - 1) / LIMB_BITS).max(1);
+        // Compute the number of columns.
+        let num_columns = [
+            2 * NUM_LIMBS - 1,
+            num_quotient_limbs + NUM_LIMBS - 1,
+            (offset.bits() as usize + LIMB_BITS - 1) / LIMB_BITS,
+        ]
+        .into_iter()
+        .max()
+        .unwrap_or_default();
+
+        // Compute the quotient and remainder.
+        let lhs = positive.iter().map(|(a, b)| a.eject_value() * b.eject_value()).sum::<BigUint>() + &offset;
+        let rhs = negative.iter().map(|(a, b)| a.eject_value() * b.eject_value()).sum::<BigUint>();
+        let value = match lhs >= rhs {
+            true => lhs - rhs,
+            false => BigUint::default(),
+        };
+        let (quotient, remainder) = (&value / &modulus, &value % &modulus);
+
+        // Witness the quotient and remainder.
+        let quotient_limbs = to_limbs(&quotient, num_quotient_limbs);
+        let quotient =
+            quotient_limbs.iter().map(|limb| new_range_checked(mode, (*limb).into(), LIMB_BITS)).collect::<Vec<_>>();
+        let remainder = match has_remainder {
+            true => Self::new_witness(mode, &remainder),
+            false => Self::zero(),
+        };
+
+        // Prepare the constants.
+        let offset_limbs = to_limbs(&offset, num_columns);
+        let two_pow_limb_bits = Field::constant(console::Field::from_u128(1u128 << LIMB_BITS));
+        let carry_offset = Field::constant(console::Field::from_u128(1u128 << CARRY_OFFSET_BITS));
+
+        // Returns the column `k` of the given products, as a field element and an integer.
+        let column = |products: &[(&Self, &Self)], k: usize| {
+            let mut sum = (Field::zero(), BigInt::default());
+            for (a, b) in products {
+                for i in k.saturating_sub(NUM_LIMBS - 1)..=k.min(NUM_LIMBS - 1) {
+                    sum.0 += &a.limbs[i] * &b.limbs[k - i];
+                    sum.1 += BigInt::from(to_u128::<E>(&a.limbs[i].eject_value()))
+                        * BigInt::from(to_u128::<E>(&b.limbs[k - i].eject_value()));
+                }
+            }
+            sum
+        };
+
+        let mut carry_in: Option<(Field<E>, BigInt)> = None;
+        for k in 0..num_columns {
+            // Compute the positive and negative sums of the column.
+            let (mut lhs, mut lhs_value) = column(positive, k);
+            let (mut rhs, mut rhs_value) = column(negative, k);
+            lhs += Field::constant(console::Field::from_u64(offset_limbs[k]));
+            lhs_value += BigInt::from(offset_limbs[k]);
+            for i in k.saturating_sub(NUM_LIMBS - 1)..=k.min(num_quotient_limbs - 1) {
+                rhs += &quotient[i] * Field::constant(console::Field::from_u64(P::MODULUS[k - i]));
+                rhs_value += BigInt::from(quotient_limbs[i]) * &modulus_limbs[k - i];
+            }
+            if k < NUM_LIMBS {
+                rhs += &remainder.limbs[k];
+                rhs_value += BigInt::from(to_u128::<E>(&remainder.limbs[k].eject_value()));
+            }
+
+            // Add the carry from the previous column, as `carry_in - 2^80`.
+            if let Some((carry, value)) = &carry_in {
+                lhs += carry;
+                rhs += &carry_offset;
+                lhs_value += value - (BigInt::from(1u64) << CARRY_OFFSET_BITS);
+            }
+
+            match k == num_columns - 1 {
+                // Ensure the final column sums to zero.
+                true => E::assert_eq(lhs, rhs),
+                // Ensure the column sums to `carry_out * 2^64`, where the carry out is witnessed as `carry_out + 2^80`.
+                false => {
+                    let carry_value = (lhs_value - rhs_value) >> LIMB_BITS;
+                    let shifted_value = carry_value + (BigInt::from(1u64) << CARRY_OFFSET_BITS);
+                    let shifted = to_u128_from_bigint(&shifted_value);
+                    let carry_out = new_range_checked(mode, shifted, CARRY_OFFSET_BITS + 1);
+                    E::assert_eq(lhs + &carry_offset * &two_pow_limb_bits, rhs + &carry_out * &two_pow_limb_bits);
+                    carry_in = Some((carry_out, shifted_value));
+                }
+            }
+        }
+
+        remainder
+    }
+}
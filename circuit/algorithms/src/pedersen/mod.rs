@@ -18,6 +18,9 @@ mod commit;
 mod commit_uncompressed;
 mod hash;
 mod hash_uncompressed;
+mod sponge;
+
+pub use sponge::PedersenSponge;
 
 #[cfg(all(test, console))]
 use snarkvm_circuit_types::environment::{assert_count, assert_output_mode, assert_scope};
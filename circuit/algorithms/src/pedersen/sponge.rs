@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A streaming interface to the Pedersen hash function, for inputs whose length is not known up front.
+///
+/// As the Pedersen hash is a sum over the set bits of the input, the streaming hash
+/// of an input is equal to `Pedersen::hash` of the same input.
+pub struct PedersenSponge<'a, E: Environment, const NUM_BITS: u8> {
+    /// The Pedersen hash function.
+    pedersen: &'a Pedersen<E, NUM_BITS>,
+    /// The digest of the absorbed bits.
+    digest: Group<E>,
+    /// The total number of absorbed bits.
+    num_absorbed: usize,
+}
+
+impl<E: Environment, const NUM_BITS: u8> Pedersen<E, NUM_BITS> {
+    /// Returns a new streaming instance of the Pedersen hash function.
+    pub fn sponge(&self) -> PedersenSponge<'_, E, NUM_BITS> {
+        PedersenSponge { pedersen: self, digest: Group::zero(), num_absorbed: 0 }
+    }
+}
+
+impl<E: Environment, const NUM_BITS: u8> PedersenSponge<'_, E, NUM_BITS> {
+    /// Absorbs the given input bits.
+    pub fn absorb(&mut self, input: &[Boolean<E>]) {
+        // Ensure the input is within the size bounds.
+        let num_absorbed = self.num_absorbed.saturating_add(input.len());
+        if num_absorbed > NUM_BITS as usize {
+            E::halt(format!("The Pedersen hash input cannot exceed {NUM_BITS} bits."))
+        }
+
+        // Add base_i^{input_i} to the digest, for all i.
+        for (bit, base) in input.iter().zip_eq(&self.pedersen.base_window[self.num_absorbed..num_absorbed]) {
+            self.digest += Group::ternary(bit, base, &Group::zero());
+        }
+        self.num_absorbed = num_absorbed;
+    }
+
+    /// Returns the Pedersen hash of the absorbed input as an affine group element.
+    pub fn squeeze_uncompressed(&self) -> Group<E> {
+        self.digest.clone()
+    }
+
+    /// Returns the Pedersen hash of the absorbed input as a field element.
+    pub fn squeeze(&self) -> Field<E> {
+        self.digest.to_x_coordinate()
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: u64 = 10;
+    const MESSAGE: &str = "PedersenSpongeCircuit";
+
+    fn check_sponge<const NUM_BITS: u8>(mode: Mode, rng: &mut TestRng) {
+        use console::Hash as H;
+
+        // Initialize the Pedersen hash.
+        let native = console::Pedersen::<<Circuit as Environment>::Network, NUM_BITS>::setup(MESSAGE);
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let num_bits = u8::rand(rng) as usize % (NUM_BITS as usize + 1);
+            let input = (0..num_bits).map(|_| bool::rand(rng)).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = native.hash(&input).expect("Failed to hash native input");
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("Pedersen Sponge {mode} {i}"), || {
+                // Absorb the input in random-sized pieces.
+                let mut sponge = circuit.sponge();
+                let mut remaining = &circuit_input[..];
+                while !remaining.is_empty() {
+                    let (piece, rest) = remaining.split_at(1 + u8::rand(rng) as usize % remaining.len());
+                    sponge.absorb(piece);
+                    remaining = rest;
+                }
+                // Ensure the streaming hash matches the one-shot hash.
+                assert_eq!(expected, sponge.squeeze().eject_value());
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_pedersen64_sponge() {
+        let mut rng = TestRng::default();
+        check_sponge::<64>(Mode::Constant, &mut rng);
+        check_sponge::<64>(Mode::Public, &mut rng);
+        check_sponge::<64>(Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_pedersen128_sponge() {
+        let mut rng = TestRng::default();
+        check_sponge::<128>(Mode::Constant, &mut rng);
+        check_sponge::<128>(Mode::Public, &mut rng);
+        check_sponge::<128>(Mode::Private, &mut rng);
+    }
+}
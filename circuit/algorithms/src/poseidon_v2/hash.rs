@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const RATE: usize> Hash for PoseidonV2<E, RATE> {
+    type Input = Field<E>;
+    type Output = Field<E>;
+
+    #[inline]
+    fn hash(&self, input: &[Self::Input]) -> Self::Output {
+        self.hash_many(input, 1).swap_remove(0)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "PoseidonV2Circuit";
+    const ITERATIONS: usize = 10;
+
+    fn check_hash<const RATE: usize>(mode: Mode, num_inputs: usize, rng: &mut TestRng) -> Result<()> {
+        use console::Hash as H;
+
+        let native = console::PoseidonV2::<<Circuit as Environment>::Network, RATE>::setup(DOMAIN)?;
+        let poseidon = PoseidonV2::<Circuit, RATE>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Prepare the preimage.
+            let native_input = (0..num_inputs)
+                .map(|_| console::Field::<<Circuit as Environment>::Network>::rand(rng))
+                .collect::<Vec<_>>();
+            let input = native_input.iter().map(|v| Field::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+            // Compute the native hash.
+            let expected = native.hash(&native_input).expect("Failed to hash native input");
+
+            // Compute the circuit hash.
+            Circuit::scope(format!("PoseidonV2 {mode} {i}"), || {
+                let candidate = poseidon.hash(&input);
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(mode.is_constant() || num_inputs == 0, candidate.is_constant());
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    fn check_hash_all_rates(mode: Mode) -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_inputs in 0..=10 {
+            check_hash::<2>(mode, num_inputs, &mut rng)?;
+            check_hash::<3>(mode, num_inputs, &mut rng)?;
+            check_hash::<7>(mode, num_inputs, &mut rng)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_constant() -> Result<()> {
+        check_hash_all_rates(Mode::Constant)
+    }
+
+    #[test]
+    fn test_hash_public() -> Result<()> {
+        check_hash_all_rates(Mode::Public)
+    }
+
+    #[test]
+    fn test_hash_private() -> Result<()> {
+        check_hash_all_rates(Mode::Private)
+    }
+}
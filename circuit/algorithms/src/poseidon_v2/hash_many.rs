@@ -0,0 +1,162 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const RATE: usize> HashMany for PoseidonV2<E, RATE> {
+    type Input = Field<E>;
+    type Output = Field<E>;
+
+    #[inline]
+    fn hash_many(&self, input: &[Self::Input], num_outputs: u16) -> Vec<Self::Output> {
+        // Construct the preimage: [ DOMAIN || LENGTH(INPUT) || [0; RATE-2] || INPUT ].
+        let mut preimage = Vec::with_capacity(RATE + input.len());
+        preimage.push(self.domain.clone());
+        preimage.push(Field::constant(console::Field::from_u128(input.len() as u128)));
+        preimage.resize(RATE, Field::zero()); // Pad up to RATE.
+        preimage.extend_from_slice(input);
+
+        // Absorb the preimage, `RATE` elements at a time, permuting between each chunk.
+        let mut state = vec![Field::zero(); RATE + CAPACITY];
+        for (i, chunk) in preimage.chunks(RATE).enumerate() {
+            if i > 0 {
+                self.permute(&mut state);
+            }
+            for (element, input) in state[CAPACITY..].iter_mut().zip(chunk) {
+                *element += input;
+            }
+        }
+
+        // Squeeze the output, `RATE` elements at a time, permuting before each chunk.
+        let mut output = Vec::with_capacity(num_outputs as usize);
+        while output.len() < num_outputs as usize {
+            self.permute(&mut state);
+            let num_remaining = num_outputs as usize - output.len();
+            output.extend(state[CAPACITY..].iter().take(num_remaining).cloned());
+        }
+        output
+    }
+}
+
+impl<E: Environment, const RATE: usize> PoseidonV2<E, RATE> {
+    /// Apply the permutation for all rounds in-place.
+    #[inline]
+    fn permute(&self, state: &mut [Field<E>]) {
+        let full_rounds_over_2 = self.full_rounds / 2;
+
+        // Apply the external matrix, before the first round.
+        self.apply_external_matrix(state);
+        // Apply the first half of the full rounds.
+        for ark in &self.external_ark[..full_rounds_over_2] {
+            self.apply_full_round(state, ark);
+        }
+        // Apply the partial rounds.
+        for ark in &self.internal_ark {
+            state[0] += ark;
+            state[0] = (&state[0]).pow(&self.alpha);
+            self.apply_internal_matrix(state);
+        }
+        // Apply the second half of the full rounds.
+        for ark in &self.external_ark[full_rounds_over_2..] {
+            self.apply_full_round(state, ark);
+        }
+    }
+
+    /// Apply a full round, with the given additive round keys, in-place.
+    #[inline]
+    fn apply_full_round(&self, state: &mut [Field<E>], ark: &[Field<E>]) {
+        for (element, ark) in state.iter_mut().zip_eq(ark) {
+            *element = (&*element + ark).pow(&self.alpha);
+        }
+        self.apply_external_matrix(state);
+    }
+
+    /// Apply the external matrix in-place.
+    #[inline]
+    fn apply_external_matrix(&self, state: &mut [Field<E>]) {
+        let new_state = self
+            .external_matrix
+            .iter()
+            .map(|row| row.iter().zip_eq(state.iter()).fold(Field::zero(), |acc, (m, x)| acc + m * x))
+            .collect::<Vec<_>>();
+        state.clone_from_slice(&new_state);
+    }
+
+    /// Apply the internal matrix `J + diag(d)` in-place, as `x_i := sum(x) + d_i * x_i`.
+    #[inline]
+    fn apply_internal_matrix(&self, state: &mut [Field<E>]) {
+        let sum = state.iter().fold(Field::zero(), |acc, element| acc + element);
+        for (element, diagonal) in state.iter_mut().zip_eq(&self.internal_diagonal) {
+            *element = &sum + &*element * diagonal;
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "PoseidonV2Circuit";
+    const ITERATIONS: usize = 5;
+    const RATE: usize = 2;
+
+    fn check_hash_many(mode: Mode, num_inputs: usize, num_outputs: u16, rng: &mut TestRng) -> Result<()> {
+        use console::HashMany as H;
+
+        let native = console::PoseidonV2::<<Circuit as Environment>::Network, RATE>::setup(DOMAIN)?;
+        let poseidon = PoseidonV2::<Circuit, RATE>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Prepare the preimage.
+            let native_input = (0..num_inputs)
+                .map(|_| console::Field::<<Circuit as Environment>::Network>::rand(rng))
+                .collect::<Vec<_>>();
+            let input = native_input.iter().map(|v| Field::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+            // Compute the native hash.
+            let expected = native.hash_many(&native_input, num_outputs);
+
+            // Compute the circuit hash.
+            Circuit::scope(format!("PoseidonV2 {mode} {i}"), || {
+                let candidate = poseidon.hash_many(&input, num_outputs);
+                assert_eq!(expected.len(), candidate.len());
+                for (expected, candidate) in expected.iter().zip_eq(&candidate) {
+                    assert_eq!(*expected, candidate.eject_value());
+                }
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_many() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for num_inputs in 0..=5 {
+                for num_outputs in 0..=5 {
+                    check_hash_many(mode, num_inputs, num_outputs, &mut rng)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod hash;
+mod hash_many;
+
+#[cfg(test)]
+use snarkvm_utilities::{TestRng, Uniform};
+
+use crate::{Hash, HashMany};
+use snarkvm_circuit_types::{environment::prelude::*, Field};
+
+/// Poseidon2V2 is the Poseidon2 hash function of input rate 2.
+pub type Poseidon2V2<E> = PoseidonV2<E, 2>;
+
+const CAPACITY: usize = 1;
+
+#[derive(Clone)]
+pub struct PoseidonV2<E: Environment, const RATE: usize> {
+    /// The domain separator for the Poseidon2 hash function.
+    domain: Field<E>,
+    /// The number of rounds in a full-round operation.
+    full_rounds: usize,
+    /// The number of rounds in a partial-round operation.
+    partial_rounds: usize,
+    /// The exponent used in S-boxes.
+    alpha: Field<E>,
+    /// The additive round keys for the full rounds, indexed by `external_ark[round_number][state_element_index]`.
+    external_ark: Vec<Vec<Field<E>>>,
+    /// The additive round keys for the partial rounds, which are added to the first state element.
+    internal_ark: Vec<Field<E>>,
+    /// The matrix used in the full rounds.
+    external_matrix: Vec<Vec<Field<E>>>,
+    /// The diagonal of the matrix `J + diag(d)` used in the partial rounds.
+    internal_diagonal: Vec<Field<E>>,
+}
+
+#[cfg(console)]
+impl<E: Environment, const RATE: usize> Inject for PoseidonV2<E, RATE> {
+    type Primitive = console::PoseidonV2<E::Network, RATE>;
+
+    fn new(_mode: Mode, poseidon: Self::Primitive) -> Self {
+        // Initialize the domain separator.
+        let domain = Field::constant(poseidon.domain());
+
+        // Initialize the Poseidon2 parameters.
+        let parameters = poseidon.parameters();
+        let full_rounds = parameters.full_rounds;
+        let partial_rounds = parameters.partial_rounds;
+        let alpha = Field::constant(console::Field::from_u128(parameters.alpha as u128));
+        // Cache the bits for the field element.
+        alpha.to_bits_le();
+        let constant = |field| Field::constant(console::Field::new(field));
+        let external_ark =
+            parameters.external_ark.iter().map(|round| round.iter().copied().map(constant).collect()).collect();
+        let internal_ark = parameters.internal_ark.iter().copied().map(constant).collect();
+        let external_matrix =
+            parameters.external_matrix.iter().map(|row| row.iter().copied().map(constant).collect()).collect();
+        let internal_diagonal = parameters.internal_diagonal.iter().copied().map(constant).collect();
+
+        Self {
+            domain,
+            full_rounds,
+            partial_rounds,
+            alpha,
+            external_ark,
+            internal_ark,
+            external_matrix,
+            internal_diagonal,
+        }
+    }
+}
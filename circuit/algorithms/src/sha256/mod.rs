@@ -0,0 +1,129 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod hash;
+
+use crate::Hash;
+use snarkvm_circuit_types::prelude::*;
+
+use core::marker::PhantomData;
+
+/// The round constants of SHA-256.
+const ROUND_CONSTANTS: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5, 0xd807aa98,
+    0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786,
+    0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8,
+    0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13,
+    0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819,
+    0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a,
+    0x5b9cca4f, 0x682e6ff3, 0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7,
+    0xc67178f2,
+];
+
+/// The initial hash value of SHA-256.
+const INITIAL_STATE: [u32; 8] =
+    [0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19];
+
+/// The number of bits in a SHA-256 block.
+const BLOCK_SIZE_IN_BITS: usize = 512;
+
+/// SHA-256 is the 256-bit hash function of the SHA-2 family, as specified in FIPS 180-4.
+/// Each 32-bit word is represented as 32 **little-endian** bits.
+pub struct Sha256<E: Environment>(PhantomData<E>);
+
+impl<E: Environment> Sha256<E> {
+    /// Initializes a new instance of SHA-256.
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+
+    /// Applies the SHA-256 compression function to the given state and block of 16 words.
+    fn compress(state: &mut [Vec<Boolean<E>>; 8], block: &[Vec<Boolean<E>>]) {
+        // Prepare the message schedule.
+        let mut w = block.to_vec();
+        for t in 16..64 {
+            let s0 = Self::xor3(&Self::rotr(&w[t - 15], 7), &Self::rotr(&w[t - 15], 18), &Self::shr(&w[t - 15], 3));
+            let s1 = Self::xor3(&Self::rotr(&w[t - 2], 17), &Self::rotr(&w[t - 2], 19), &Self::shr(&w[t - 2], 10));
+            let word = Self::add_many(&[&w[t - 16], &s0, &w[t - 7], &s1]);
+            w.push(word);
+        }
+
+        // Perform the rounds.
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state.clone();
+        for (t, w) in w.iter().enumerate() {
+            let s1 = Self::xor3(&Self::rotr(&e, 6), &Self::rotr(&e, 11), &Self::rotr(&e, 25));
+            // Compute `ch := (e & f) ^ (!e & g)` as `g ^ (e & (f ^ g))`.
+            let ch = g.iter().zip_eq(&e).zip_eq(&f).map(|((g, e), f)| g ^ &(e & &(f ^ g))).collect::<Vec<_>>();
+            let k = Self::constant(ROUND_CONSTANTS[t]);
+            let t1 = Self::add_many(&[&h, &s1, &ch, &k, w]);
+            let s0 = Self::xor3(&Self::rotr(&a, 2), &Self::rotr(&a, 13), &Self::rotr(&a, 22));
+            // Compute `maj := (a & b) ^ (a & c) ^ (b & c)` as `(a & b) ^ (c & (a ^ b))`.
+            let maj = a.iter().zip_eq(&b).zip_eq(&c).map(|((a, b), c)| &(a & b) ^ &(c & &(a ^ b))).collect::<Vec<_>>();
+            h = g;
+            g = f;
+            f = e;
+            e = Self::add_many(&[&d, &t1]);
+            d = c;
+            c = b;
+            b = a;
+            a = Self::add_many(&[&t1, &s0, &maj]);
+        }
+
+        // Update the state.
+        for (word, value) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *word = Self::add_many(&[&*word, &value]);
+        }
+    }
+
+    /// Returns the given 32-bit constant as a word.
+    fn constant(value: u32) -> Vec<Boolean<E>> {
+        (0..32).map(|i| Boolean::constant((value >> i) & 1 == 1)).collect()
+    }
+
+    /// Returns the given word rotated right by `n` bits.
+    fn rotr(word: &[Boolean<E>], n: usize) -> Vec<Boolean<E>> {
+        (0..32).map(|i| word[(i + n) % 32].clone()).collect()
+    }
+
+    /// Returns the given word shifted right by `n` bits.
+    fn shr(word: &[Boolean<E>], n: usize) -> Vec<Boolean<E>> {
+        (0..32).map(|i| if i + n < 32 { word[i + n].clone() } else { Boolean::constant(false) }).collect()
+    }
+
+    /// Returns the bitwise XOR of the three given words.
+    fn xor3(a: &[Boolean<E>], b: &[Boolean<E>], c: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        a.iter().zip_eq(b).zip_eq(c).map(|((a, b), c)| &(a ^ b) ^ c).collect()
+    }
+
+    /// Returns the sum of the given words, modulo `2^32`.
+    /// Note: The words are summed as field elements, and only the lower 32 bits of the sum are kept.
+    fn add_many(words: &[&Vec<Boolean<E>>]) -> Vec<Boolean<E>> {
+        // Compute the sum of the words as a field element.
+        let sum = words.iter().fold(Field::zero(), |sum, word| sum + Field::from_bits_le(word));
+        // Determine the number of carry bits in the sum.
+        let num_carry_bits = (usize::BITS - words.len().leading_zeros()) as usize;
+        // Extract the lower bits of the sum, and drop the carry bits.
+        let mut bits_le = sum.to_lower_bits_le(32 + num_carry_bits);
+        bits_le.truncate(32);
+        bits_le
+    }
+}
+
+impl<E: Environment> Default for Sha256<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
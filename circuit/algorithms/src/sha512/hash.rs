@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Hash for Sha512<E> {
+    type Input = Boolean<E>;
+    type Output = Vec<Boolean<E>>;
+
+    /// Returns the SHA-512 hash of the given input as a list of bits.
+    /// A byte string is hashed by passing each byte as **little-endian** bits, in order,
+    /// and the digest is returned in the same form. The input length must be a multiple of 8.
+    fn hash(&self, input: &[Self::Input]) -> Self::Output {
+        // Ensure the input is a byte string.
+        if input.len() % 8 != 0 {
+            E::halt(format!("SHA-512 expects a multiple of 8 input bits, found {}", input.len()))
+        }
+
+        // Pad the input with `0x80`, then zeros, then the input length as a big-endian `u128`.
+        let num_padded_bits =
+            (input.len() + 8 + 128 + BLOCK_SIZE_IN_BITS - 1) / BLOCK_SIZE_IN_BITS * BLOCK_SIZE_IN_BITS;
+        let mut padded = Vec::with_capacity(num_padded_bits);
+        padded.extend_from_slice(input);
+        padded.extend((0..8).map(|i| Boolean::constant(i == 7)));
+        padded.resize(num_padded_bits - 128, Boolean::constant(false));
+        let length = input.len() as u128;
+        padded.extend(
+            length.to_be_bytes().iter().flat_map(|byte| (0..8).map(move |i| Boolean::constant((byte >> i) & 1 == 1))),
+        );
+
+        // Compress each block into the state.
+        let mut state = INITIAL_STATE.map(Self::constant);
+        for block in padded.chunks(BLOCK_SIZE_IN_BITS) {
+            // Each word is composed of 8 big-endian bytes, of little-endian bits.
+            let words = block
+                .chunks(64)
+                .map(|bits| bits.chunks(8).rev().flatten().cloned().collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            Self::compress(&mut state, &words);
+        }
+
+        // Output the state as big-endian bytes, of little-endian bits.
+        state.iter().flat_map(|word| word.chunks(8).rev().flatten().cloned().collect::<Vec<_>>()).collect()
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 3;
+
+    fn check_hash(mode: Mode, num_input_bytes: usize) -> Result<()> {
+        use console::Hash as H;
+
+        let native = console::Sha512::new();
+        let circuit = Sha512::<Circuit>::new();
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let input = (0..num_input_bytes * 8).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = native.hash(&input)?;
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("Sha512 {mode} {i}"), || {
+                // Perform the hash operation.
+                let candidate = circuit.hash(&circuit_input);
+                assert_eq!(expected, candidate.eject_value());
+                if mode.is_constant() {
+                    assert!(candidate.iter().all(|bit| bit.is_constant()));
+                }
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_constant() -> Result<()> {
+        for num_input_bytes in [0, 1, 111, 112, 128, 160] {
+            check_hash(Mode::Constant, num_input_bytes)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_public() -> Result<()> {
+        for num_input_bytes in [1, 111, 112, 128, 160] {
+            check_hash(Mode::Public, num_input_bytes)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_private() -> Result<()> {
+        for num_input_bytes in [1, 111, 112, 128, 160] {
+            check_hash(Mode::Private, num_input_bytes)?;
+        }
+        Ok(())
+    }
+}
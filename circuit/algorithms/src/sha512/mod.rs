@@ -0,0 +1,209 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod hash;
+
+use crate::Hash;
+use snarkvm_circuit_types::prelude::*;
+
+use core::marker::PhantomData;
+
+/// The round constants of SHA-512.
+const ROUND_CONSTANTS: [u64; 80] = [
+    0x428a2f98d728ae22,
+    0x7137449123ef65cd,
+    0xb5c0fbcfec4d3b2f,
+    0xe9b5dba58189dbbc,
+    0x3956c25bf348b538,
+    0x59f111f1b605d019,
+    0x923f82a4af194f9b,
+    0xab1c5ed5da6d8118,
+    0xd807aa98a3030242,
+    0x12835b0145706fbe,
+    0x243185be4ee4b28c,
+    0x550c7dc3d5ffb4e2,
+    0x72be5d74f27b896f,
+    0x80deb1fe3b1696b1,
+    0x9bdc06a725c71235,
+    0xc19bf174cf692694,
+    0xe49b69c19ef14ad2,
+    0xefbe4786384f25e3,
+    0x0fc19dc68b8cd5b5,
+    0x240ca1cc77ac9c65,
+    0x2de92c6f592b0275,
+    0x4a7484aa6ea6e483,
+    0x5cb0a9dcbd41fbd4,
+    0x76f988da831153b5,
+    0x983e5152ee66dfab,
+    0xa831c66d2db43210,
+    0xb00327c898fb213f,
+    0xbf597fc7beef0ee4,
+    0xc6e00bf33da88fc2,
+    0xd5a79147930aa725,
+    0x06ca6351e003826f,
+    0x142929670a0e6e70,
+    0x27b70a8546d22ffc,
+    0x2e1b21385c26c926,
+    0x4d2c6dfc5ac42aed,
+    0x53380d139d95b3df,
+    0x650a73548baf63de,
+    0x766a0abb3c77b2a8,
+    0x81c2c92e47edaee6,
+    0x92722c851482353b,
+    0xa2bfe8a14cf10364,
+    0xa81a664bbc423001,
+    0xc24b8b70d0f89791,
+    0xc76c51a30654be30,
+    0xd192e819d6ef5218,
+    0xd69906245565a910,
+    0xf40e35855771202a,
+    0x106aa07032bbd1b8,
+    0x19a4c116b8d2d0c8,
+    0x1e376c085141ab53,
+    0x2748774cdf8eeb99,
+    0x34b0bcb5e19b48a8,
+    0x391c0cb3c5c95a63,
+    0x4ed8aa4ae3418acb,
+    0x5b9cca4f7763e373,
+    0x682e6ff3d6b2b8a3,
+    0x748f82ee5defb2fc,
+    0x78a5636f43172f60,
+    0x84c87814a1f0ab72,
+    0x8cc702081a6439ec,
+    0x90befffa23631e28,
+    0xa4506cebde82bde9,
+    0xbef9a3f7b2c67915,
+    0xc67178f2e372532b,
+    0xca273eceea26619c,
+    0xd186b8c721c0c207,
+    0xeada7dd6cde0eb1e,
+    0xf57d4f7fee6ed178,
+    0x06f067aa72176fba,
+    0x0a637dc5a2c898a6,
+    0x113f9804bef90dae,
+    0x1b710b35131c471b,
+    0x28db77f523047d84,
+    0x32caab7b40c72493,
+    0x3c9ebe0a15c9bebc,
+    0x431d67c49c100d4c,
+    0x4cc5d4becb3e42b6,
+    0x597f299cfc657e2a,
+    0x5fcb6fab3ad6faec,
+    0x6c44198c4a475817,
+];
+
+/// The initial hash value of SHA-512.
+const INITIAL_STATE: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+/// The number of bits in a SHA-512 block.
+const BLOCK_SIZE_IN_BITS: usize = 1024;
+
+/// SHA-512 is the 512-bit hash function of the SHA-2 family, as specified in FIPS 180-4.
+/// Each 64-bit word is represented as 64 **little-endian** bits.
+pub struct Sha512<E: Environment>(PhantomData<E>);
+
+impl<E: Environment> Sha512<E> {
+    /// Initializes a new instance of SHA-512.
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+
+    /// Applies the SHA-512 compression function to the given state and block of 16 words.
+    fn compress(state: &mut [Vec<Boolean<E>>; 8], block: &[Vec<Boolean<E>>]) {
+        // Prepare the message schedule.
+        let mut w = block.to_vec();
+        for t in 16..80 {
+            let s0 = Self::xor3(&Self::rotr(&w[t - 15], 1), &Self::rotr(&w[t - 15], 8), &Self::shr(&w[t - 15], 7));
+            let s1 = Self::xor3(&Self::rotr(&w[t - 2], 19), &Self::rotr(&w[t - 2], 61), &Self::shr(&w[t - 2], 6));
+            let word = Self::add_many(&[&w[t - 16], &s0, &w[t - 7], &s1]);
+            w.push(word);
+        }
+
+        // Perform the rounds.
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state.clone();
+        for (t, w) in w.iter().enumerate() {
+            let s1 = Self::xor3(&Self::rotr(&e, 14), &Self::rotr(&e, 18), &Self::rotr(&e, 41));
+            // Compute `ch := (e & f) ^ (!e & g)` as `g ^ (e & (f ^ g))`.
+            let ch = g.iter().zip_eq(&e).zip_eq(&f).map(|((g, e), f)| g ^ &(e & &(f ^ g))).collect::<Vec<_>>();
+            let k = Self::constant(ROUND_CONSTANTS[t]);
+            let t1 = Self::add_many(&[&h, &s1, &ch, &k, w]);
+            let s0 = Self::xor3(&Self::rotr(&a, 28), &Self::rotr(&a, 34), &Self::rotr(&a, 39));
+            // Compute `maj := (a & b) ^ (a & c) ^ (b & c)` as `(a & b) ^ (c & (a ^ b))`.
+            let maj = a.iter().zip_eq(&b).zip_eq(&c).map(|((a, b), c)| &(a & b) ^ &(c & &(a ^ b))).collect::<Vec<_>>();
+            h = g;
+            g = f;
+            f = e;
+            e = Self::add_many(&[&d, &t1]);
+            d = c;
+            c = b;
+            b = a;
+            a = Self::add_many(&[&t1, &s0, &maj]);
+        }
+
+        // Update the state.
+        for (word, value) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *word = Self::add_many(&[&*word, &value]);
+        }
+    }
+
+    /// Returns the given 64-bit constant as a word.
+    fn constant(value: u64) -> Vec<Boolean<E>> {
+        (0..64).map(|i| Boolean::constant((value >> i) & 1 == 1)).collect()
+    }
+
+    /// Returns the given word rotated right by `n` bits.
+    fn rotr(word: &[Boolean<E>], n: usize) -> Vec<Boolean<E>> {
+        (0..64).map(|i| word[(i + n) % 64].clone()).collect()
+    }
+
+    /// Returns the given word shifted right by `n` bits.
+    fn shr(word: &[Boolean<E>], n: usize) -> Vec<Boolean<E>> {
+        (0..64).map(|i| if i + n < 64 { word[i + n].clone() } else { Boolean::constant(false) }).collect()
+    }
+
+    /// Returns the bitwise XOR of the three given words.
+    fn xor3(a: &[Boolean<E>], b: &[Boolean<E>], c: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        a.iter().zip_eq(b).zip_eq(c).map(|((a, b), c)| &(a ^ b) ^ c).collect()
+    }
+
+    /// Returns the sum of the given words, modulo `2^64`.
+    /// Note: The words are summed as field elements, and only the lower 64 bits of the sum are kept.
+    fn add_many(words: &[&Vec<Boolean<E>>]) -> Vec<Boolean<E>> {
+        // Compute the sum of the words as a field element.
+        let sum = words.iter().fold(Field::zero(), |sum, word| sum + Field::from_bits_le(word));
+        // Determine the number of carry bits in the sum.
+        let num_carry_bits = (usize::BITS - words.len().leading_zeros()) as usize;
+        // Extract the lower bits of the sum, and drop the carry bits.
+        let mut bits_le = sum.to_lower_bits_le(64 + num_carry_bits);
+        bits_le.truncate(64);
+        bits_le
+    }
+}
+
+impl<E: Environment> Default for Sha512<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
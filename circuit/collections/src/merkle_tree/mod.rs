@@ -18,11 +18,12 @@ mod helpers;
 use helpers::{LeafHash, PathHash};
 
 mod verify;
+mod verify_with_depth;
 
 #[cfg(all(test, console))]
 use snarkvm_circuit_types::environment::assert_scope;
 
-use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field, U64};
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field, U64, U8};
 
 pub struct MerklePath<E: Environment, const DEPTH: u8> {
     /// The leaf index for the path.
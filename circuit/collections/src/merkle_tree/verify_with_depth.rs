@@ -0,0 +1,230 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const MAX_DEPTH: u8> MerklePath<E, MAX_DEPTH> {
+    /// Returns `true` if the Merkle path is valid for the given root and leaf, in a tree of the given depth.
+    ///
+    /// The Merkle path must contain `MAX_DEPTH` siblings, of which only the first `depth` siblings are used,
+    /// and the leaf index must be less than `2^depth`.
+    pub fn verify_with_depth<LH: LeafHash<E, Hash = PH::Hash>, PH: PathHash<E, Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        leaf: &LH::Leaf,
+        depth: &U8<E>,
+    ) -> Boolean<E> {
+        // Retrieve the depth of the tree.
+        let depth_value = *depth.eject_value();
+
+        // Ensure the depth is within the maximum depth.
+        if depth_value > MAX_DEPTH {
+            E::halt("Found a Merkle tree depth that exceeds the maximum depth")
+        }
+        // Ensure the leaf index is within the tree depth.
+        else if (*self.leaf_index.eject_value() as u128) >= (1u128 << depth_value) {
+            E::halt("Found an out of bounds Merkle leaf index")
+        }
+        // Ensure the path length matches the maximum depth.
+        else if self.siblings.len() != MAX_DEPTH as usize {
+            E::halt("Found an incorrect Merkle path length")
+        }
+
+        // Compute the indicators of the levels that are within the tree depth,
+        // which are of the form `[true; depth] || [false; MAX_DEPTH - depth]`.
+        let mode = match depth.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        };
+        let is_levels: Vec<Boolean<E>> = (0..MAX_DEPTH).map(|level| Boolean::new(mode, level < depth_value)).collect();
+        // Ensure each level within the tree depth is preceded by a level within the tree depth.
+        for (previous, current) in is_levels.iter().tuple_windows() {
+            E::assert(previous | !current);
+        }
+        // Ensure the number of levels within the tree depth matches the depth.
+        let num_levels = is_levels.iter().fold(Field::zero(), |sum, is_level| sum + Field::from_boolean(is_level));
+        E::assert_eq(num_levels, depth.to_field());
+
+        // Retrieve the bits of the leaf index for the levels up to the maximum depth.
+        let leaf_index_bits = self.leaf_index.to_bits_le().into_iter().take(MAX_DEPTH as usize).collect::<Vec<_>>();
+        // Ensure the leaf index is less than `2^depth`.
+        for (bit, is_level) in leaf_index_bits.iter().zip_eq(&is_levels) {
+            E::assert(is_level | !bit);
+        }
+
+        // Initialize a tracker for the current hash, by computing the leaf hash to start.
+        let mut current_hash = leaf_hasher.hash_leaf(leaf);
+
+        // Compute the ordering of the current hash and sibling hash on each level.
+        // If the indicator bit is `true`, then the ordering is (current_hash, sibling_hash).
+        // If the indicator bit is `false`, then the ordering is (sibling_hash, current_hash).
+        let indicators = leaf_index_bits.into_iter().map(|b| !b);
+
+        // Check levels between leaf level and root.
+        for ((indicator, sibling_hash), is_level) in indicators.zip_eq(&self.siblings).zip_eq(&is_levels) {
+            // Construct the ordering of the left & right child hash for this level.
+            let left = Field::ternary(&indicator, &current_hash, sibling_hash);
+            let right = Field::ternary(&indicator, sibling_hash, &current_hash);
+
+            // Update the current hash for the next level, if the level is within the tree depth.
+            let candidate_hash = path_hasher.hash_children(&left, &right);
+            current_hash = Field::ternary(is_level, &candidate_hash, &current_hash);
+        }
+
+        // Ensure the final hash matches the given root.
+        root.is_equal(&current_hash)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_algorithms::{Poseidon2, Poseidon4};
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    type CurrentNetwork = <Circuit as Environment>::Network;
+
+    const ITERATIONS: u128 = 10;
+    const DOMAIN: &str = "MerkleTreeCircuit0";
+    const MAX_DEPTH: u8 = 8;
+
+    fn check_verify_with_depth<const DEPTH: u8>(mode: Mode) -> Result<()> {
+        // Initialize the leaf hasher.
+        let native_leaf_hasher = snarkvm_console_algorithms::Poseidon4::<CurrentNetwork>::setup(DOMAIN)?;
+        let circuit_leaf_hasher = Poseidon4::<Circuit>::constant(native_leaf_hasher.clone());
+
+        // Initialize the path hasher.
+        let native_path_hasher = snarkvm_console_algorithms::Poseidon2::<CurrentNetwork>::setup(DOMAIN)?;
+        let circuit_path_hasher = Poseidon2::<Circuit>::constant(native_path_hasher.clone());
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Determine the number of leaves.
+            let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), i);
+            // Compute the leaves.
+            let leaves = (0..num_leaves)
+                .map(|_| (0..4).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            // Compute the Merkle tree.
+            let merkle_tree = console::merkle_tree::MerkleTree::<_, _, _, DEPTH>::new(
+                &native_leaf_hasher,
+                &native_path_hasher,
+                &leaves,
+            )?;
+
+            for (index, merkle_leaf) in leaves.iter().enumerate() {
+                // Compute the Merkle path, and pad it with zeros to the maximum depth.
+                let merkle_path = merkle_tree.prove(index, merkle_leaf)?;
+                let mut siblings = merkle_path.siblings().to_vec();
+                siblings.resize(MAX_DEPTH as usize, console::Field::zero());
+                let merkle_path = console::merkle_tree::MerklePath::<CurrentNetwork, MAX_DEPTH>::try_from((
+                    merkle_path.leaf_index(),
+                    siblings,
+                ))?;
+
+                // Initialize the Merkle path.
+                let path = MerklePath::<Circuit, MAX_DEPTH>::new(mode, merkle_path);
+                // Initialize the Merkle root.
+                let root = Field::new(mode, *merkle_tree.root());
+                // Initialize the Merkle leaf.
+                let leaf: Vec<_> = Inject::new(mode, merkle_leaf.clone());
+
+                // Ensure the Merkle path is valid for the depth of the tree.
+                Circuit::scope(format!("Verify {mode}"), || {
+                    let depth = U8::new(mode, console::U8::new(DEPTH));
+                    let candidate =
+                        path.verify_with_depth(&circuit_leaf_hasher, &circuit_path_hasher, &root, &leaf, &depth);
+                    assert!(candidate.eject_value());
+                    assert!(Circuit::is_satisfied_in_scope());
+                });
+                Circuit::reset();
+
+                // Ensure the Merkle path is invalid for a greater depth.
+                Circuit::scope(format!("Verify (Incorrect Depth) {mode}"), || {
+                    let depth = U8::new(mode, console::U8::new(DEPTH + 1));
+                    let candidate =
+                        path.verify_with_depth(&circuit_leaf_hasher, &circuit_path_hasher, &root, &leaf, &depth);
+                    assert!(!candidate.eject_value());
+                    assert!(Circuit::is_satisfied_in_scope());
+                });
+                Circuit::reset();
+
+                // Ensure the Merkle path is invalid for an incorrect root.
+                let incorrect_root = root.clone() + Field::one();
+                Circuit::scope(format!("Verify (Incorrect Root) {mode}"), || {
+                    let depth = U8::new(mode, console::U8::new(DEPTH));
+                    let candidate = path.verify_with_depth(
+                        &circuit_leaf_hasher,
+                        &circuit_path_hasher,
+                        &incorrect_root,
+                        &leaf,
+                        &depth,
+                    );
+                    assert!(!candidate.eject_value());
+                    assert!(Circuit::is_satisfied_in_scope());
+                });
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_with_depth_constant() -> Result<()> {
+        check_verify_with_depth::<1>(Mode::Constant)?;
+        check_verify_with_depth::<4>(Mode::Constant)
+    }
+
+    #[test]
+    fn test_verify_with_depth_public() -> Result<()> {
+        check_verify_with_depth::<1>(Mode::Public)?;
+        check_verify_with_depth::<4>(Mode::Public)
+    }
+
+    #[test]
+    fn test_verify_with_depth_private() -> Result<()> {
+        check_verify_with_depth::<1>(Mode::Private)?;
+        check_verify_with_depth::<4>(Mode::Private)
+    }
+
+    #[test]
+    fn test_verify_with_max_depth() -> Result<()> {
+        check_verify_with_depth::<{ MAX_DEPTH - 1 }>(Mode::Private)
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_verify_with_depth_exceeding_max_depth() {
+        let path = MerklePath::<Circuit, MAX_DEPTH> {
+            leaf_index: U64::new(Mode::Private, console::U64::new(0)),
+            siblings: vec![Field::zero(); MAX_DEPTH as usize],
+        };
+        let leaf_hasher = Poseidon4::<Circuit>::constant(
+            snarkvm_console_algorithms::Poseidon4::<CurrentNetwork>::setup(DOMAIN).unwrap(),
+        );
+        let path_hasher = Poseidon2::<Circuit>::constant(
+            snarkvm_console_algorithms::Poseidon2::<CurrentNetwork>::setup(DOMAIN).unwrap(),
+        );
+        let depth = U8::new(Mode::Private, console::U8::new(MAX_DEPTH + 1));
+        path.verify_with_depth(&leaf_hasher, &path_hasher, &Field::zero(), &vec![Field::zero(); 4], &depth);
+    }
+}
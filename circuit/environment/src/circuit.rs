@@ -14,7 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{helpers::Constraint, Mode, *};
+use crate::{
+    helpers::{Constraint, Profiler, UnsatisfiedConstraint},
+    Mode,
+    *,
+};
 
 use core::{cell::RefCell, fmt};
 use std::rc::Rc;
@@ -24,6 +28,9 @@ type Field = <console::Testnet3 as console::Environment>::Field;
 thread_local! {
     pub(super) static CIRCUIT: Rc<RefCell<R1CS<Field>>> = Rc::new(RefCell::new(R1CS::new()));
     pub(super) static IN_WITNESS: Rc<RefCell<bool>> = Rc::new(RefCell::new(false));
+    pub(super) static WITNESS_ONLY: Rc<RefCell<bool>> = Rc::new(RefCell::new(false));
+    pub(super) static PROFILER: Rc<RefCell<Option<Profiler>>> = Rc::new(RefCell::new(None));
+    pub(super) static LABEL: Rc<RefCell<Option<Rc<String>>>> = Rc::new(RefCell::new(None));
     pub(super) static ZERO: LinearCombination<Field> = LinearCombination::zero();
     pub(super) static ONE: LinearCombination<Field> = LinearCombination::one();
 }
@@ -52,6 +59,12 @@ impl Environment for Circuit {
         IN_WITNESS.with(|in_witness| {
             // Ensure we are not in witness mode.
             if !(*(**in_witness).borrow()) {
+                // Record the variable, if the profiler is enabled.
+                PROFILER.with(|profiler| {
+                    if let Some(profiler) = (**profiler).borrow_mut().as_mut() {
+                        profiler.add_variable(mode);
+                    }
+                });
                 CIRCUIT.with(|circuit| match mode {
                     Mode::Constant => (**circuit).borrow_mut().new_constant(value),
                     Mode::Public => (**circuit).borrow_mut().new_public(value),
@@ -79,6 +92,27 @@ impl Environment for Circuit {
         })
     }
 
+    /// Runs the given logic in witness-only mode, which computes witness values without allocating constraints.
+    ///
+    /// Circuits synthesized in witness-only mode are unconstrained, and must not be used to produce proofs.
+    fn witness_only<Fn, Output>(logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        // Set the entire environment to witness-only mode.
+        // Note: The mode is kept across `Self::reset`, so the logic may reset the circuit.
+        // Note: The guard returns the entire environment to the previous mode, even if the logic panics.
+        let _guard = WitnessOnlyGuard(WITNESS_ONLY.with(|witness_only| (**witness_only).replace(true)));
+
+        // Run the logic.
+        logic()
+    }
+
+    /// Returns `true` if the environment is in witness-only mode.
+    fn is_witness_only() -> bool {
+        WITNESS_ONLY.with(|witness_only| *(**witness_only).borrow())
+    }
+
     // /// Appends the given scope to the current environment.
     // fn push_scope(name: &str) {
     //     CIRCUIT.with(|circuit| {
@@ -142,6 +176,11 @@ impl Environment for Circuit {
         B: Into<LinearCombination<Self::BaseField>>,
         C: Into<LinearCombination<Self::BaseField>>,
     {
+        // If the environment is in witness-only mode, then skip the constraint.
+        if Self::is_witness_only() {
+            return;
+        }
+
         IN_WITNESS.with(|in_witness| {
             // Ensure we are not in witness mode.
             if !(*(**in_witness).borrow()) {
@@ -168,8 +207,15 @@ impl Environment for Circuit {
                             // }
                         }
                         false => {
-                            // Construct the constraint object.
-                            let constraint = Constraint((**circuit).borrow().scope(), a, b, c);
+                            // Construct the constraint object, with the current label.
+                            let label = LABEL.with(|label| (**label).borrow().clone());
+                            let constraint = Constraint((**circuit).borrow().scope(), a, b, c, label);
+                            // Record the constraint, if the profiler is enabled.
+                            PROFILER.with(|profiler| {
+                                if let Some(profiler) = (**profiler).borrow_mut().as_mut() {
+                                    profiler.add_constraint(&constraint);
+                                }
+                            });
                             // Append the constraint.
                             (**circuit).borrow_mut().enforce(constraint)
                         }
@@ -179,6 +225,49 @@ impl Environment for Circuit {
         })
     }
 
+    /// Runs the given logic under the given label, which is attached to every constraint enforced in the logic.
+    ///
+    /// Nested labels are joined with ` > `, from the outermost to the innermost label.
+    fn label<L: fmt::Display, Fn, Output>(label: L, logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        // If the environment is in witness-only mode, then skip the label, as no constraints are enforced.
+        if Self::is_witness_only() {
+            return logic();
+        }
+
+        // Set the label, nested under the current label.
+        // Note: The guard restores the previous label, even if the logic panics.
+        let _guard = LABEL.with(|current| {
+            let label = match &*(**current).borrow() {
+                Some(parent) => format!("{parent} > {label}"),
+                None => label.to_string(),
+            };
+            LabelGuard((**current).replace(Some(Rc::new(label))))
+        });
+
+        // Run the logic.
+        logic()
+    }
+
+    /// Returns a checkpoint of the environment, which can be restored with `Self::rollback`.
+    fn checkpoint() -> Checkpoint {
+        CIRCUIT.with(|circuit| (**circuit).borrow().checkpoint())
+    }
+
+    /// Discards all variables and constraints that were synthesized since the given checkpoint.
+    ///
+    /// The checkpoint must be rolled back in the scope it was created in, and any circuit
+    /// that was created after the checkpoint must not be used after rolling back.
+    fn rollback(checkpoint: &Checkpoint) {
+        CIRCUIT.with(|circuit| {
+            if let Err(error) = (**circuit).borrow_mut().rollback(checkpoint) {
+                Self::halt(error)
+            }
+        })
+    }
+
     /// Returns `true` if all constraints in the environment are satisfied.
     fn is_satisfied() -> bool {
         CIRCUIT.with(|circuit| (**circuit).borrow().is_satisfied())
@@ -189,6 +278,11 @@ impl Environment for Circuit {
         CIRCUIT.with(|circuit| (**circuit).borrow().is_satisfied_in_scope())
     }
 
+    /// Returns the diagnostics of the first constraint in the environment that is not satisfied, if one exists.
+    fn first_unsatisfied_constraint() -> Option<UnsatisfiedConstraint<Self::BaseField>> {
+        CIRCUIT.with(|circuit| (**circuit).borrow().first_unsatisfied_constraint())
+    }
+
     /// Returns the number of constants in the entire circuit.
     fn num_constants() -> u64 {
         CIRCUIT.with(|circuit| (**circuit).borrow().num_constants())
@@ -239,6 +333,42 @@ impl Environment for Circuit {
         CIRCUIT.with(|circuit| (**circuit).borrow().num_gates_in_scope())
     }
 
+    /// Enables the profiler, which attributes the circuit resources to the labels entered with `Self::profile`.
+    fn enable_profiler() {
+        PROFILER.with(|profiler| {
+            let mut profiler = (**profiler).borrow_mut();
+            if profiler.is_none() {
+                *profiler = Some(Profiler::default());
+            }
+        })
+    }
+
+    /// Disables the profiler, returning the profile recorded since it was enabled.
+    fn disable_profiler() -> Profile {
+        PROFILER.with(|profiler| match (**profiler).borrow_mut().take() {
+            Some(profiler) => profiler.into_profile(),
+            None => Profile::default(),
+        })
+    }
+
+    /// Runs the given logic under the given label, attributing its circuit resources to the label if the profiler is enabled.
+    fn profile<L: fmt::Display, Fn, Output>(label: L, logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        // Enter the label, if the profiler is enabled.
+        // Note: The guard exits the label, if the profiler is still enabled, even if the logic panics.
+        let _guard = PROFILER.with(|profiler| {
+            (**profiler).borrow_mut().as_mut().map(|profiler| {
+                profiler.push(label);
+                ProfileGuard
+            })
+        });
+
+        // Run the logic.
+        logic()
+    }
+
     /// Halts the program from further synthesis, evaluation, and execution in the current environment.
     fn halt<S: Into<String>, T>(message: S) -> T {
         let error = message.into();
@@ -308,6 +438,38 @@ impl Environment for Circuit {
             assert_eq!(0, (**circuit).borrow().num_private());
             assert_eq!(0, (**circuit).borrow().num_constraints());
         });
+        LABEL.with(|label| *(**label).borrow_mut() = None);
+    }
+}
+
+/// A guard that returns the environment to the given label when dropped.
+struct LabelGuard(Option<Rc<String>>);
+
+impl Drop for LabelGuard {
+    fn drop(&mut self) {
+        LABEL.with(|label| *(**label).borrow_mut() = self.0.take());
+    }
+}
+
+/// A guard that returns the environment to the given witness-only mode when dropped.
+struct WitnessOnlyGuard(bool);
+
+impl Drop for WitnessOnlyGuard {
+    fn drop(&mut self) {
+        WITNESS_ONLY.with(|witness_only| *(**witness_only).borrow_mut() = self.0);
+    }
+}
+
+/// A guard that exits the current profiler label when dropped.
+struct ProfileGuard;
+
+impl Drop for ProfileGuard {
+    fn drop(&mut self) {
+        PROFILER.with(|profiler| {
+            if let Some(profiler) = (**profiler).borrow_mut().as_mut() {
+                profiler.pop();
+            }
+        });
     }
 }
 
@@ -352,6 +514,201 @@ mod tests {
         println!("{output}");
     }
 
+    #[test]
+    fn test_profiler() {
+        Circuit::reset();
+
+        // Ensure nothing is recorded while the profiler is disabled.
+        Circuit::profile("disabled", create_example_circuit::<Circuit>);
+        assert!(Circuit::disable_profiler().is_empty());
+        Circuit::reset();
+
+        Circuit::enable_profiler();
+        Circuit::profile("outer", || {
+            Circuit::profile("inner", create_example_circuit::<Circuit>);
+        });
+        Circuit::reset();
+        let _candidate = create_example_circuit::<Circuit>();
+        let profile = Circuit::disable_profiler();
+
+        // Ensure the resources are attributed to the innermost label.
+        let inner = *profile.get("outer::inner").unwrap();
+        assert_eq!(1, inner.num_public);
+        assert_eq!(2 * 64 + 1, inner.num_private);
+        assert_eq!(64, inner.num_constraints);
+        assert!(inner.num_nonzeros >= 3 * 64);
+        assert!(profile.get("outer").is_none());
+        assert_eq!(inner, profile.total("outer"));
+        assert_eq!(Some(&inner), profile.get(""));
+        assert_eq!(inner + inner, profile.total(""));
+        assert!(profile.to_string().contains("outer::inner"));
+
+        // Ensure the profiler no longer records once disabled.
+        Circuit::profile("after", || Circuit::new_variable(Mode::Private, Default::default()));
+        assert!(Circuit::disable_profiler().is_empty());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_checkpoint_and_rollback() {
+        Circuit::reset();
+
+        Circuit::scope("test_checkpoint_and_rollback", || {
+            let _candidate = create_example_circuit::<Circuit>();
+            let count = Circuit::count();
+            let count_in_scope = Circuit::count_in_scope();
+
+            // Speculatively synthesize a branch, and measure it.
+            let checkpoint = Circuit::checkpoint();
+            assert_eq!(count, checkpoint.count());
+            let a = Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::from_u64(3));
+            let b = Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::from_u64(5));
+            let _product = a * b;
+            assert_eq!(3, Circuit::num_private() - count.2);
+            assert_eq!(1, Circuit::num_constraints() - count.3);
+
+            // Discard the branch.
+            Circuit::rollback(&checkpoint);
+            assert_eq!(count, Circuit::count());
+            assert_eq!(count_in_scope, Circuit::count_in_scope());
+            assert!(Circuit::is_satisfied());
+
+            // Ensure the checkpoint can be rolled back to again.
+            let one = Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::one());
+            Circuit::enforce(|| (&one, Circuit::one(), Circuit::zero()));
+            assert!(!Circuit::is_satisfied());
+            Circuit::rollback(&checkpoint);
+            assert_eq!(count, Circuit::count());
+            assert!(Circuit::is_satisfied());
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_witness_only() {
+        Circuit::reset();
+
+        let (a, b, product) = Circuit::witness_only(|| {
+            assert!(Circuit::is_witness_only());
+            let a = Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::from_u64(3));
+            let b = Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::from_u64(5));
+            let product = &a * &b;
+            (a, b, product)
+        });
+        assert!(!Circuit::is_witness_only());
+
+        // Ensure the witness was computed, without allocating any constraints.
+        assert_eq!(snarkvm_console_types::Field::from_u64(15), product.eject_value());
+        assert_eq!(3, Circuit::num_private());
+        assert_eq!(0, Circuit::num_constraints());
+
+        // Ensure constraints are allocated outside of witness-only mode.
+        let _product = a * b;
+        assert_eq!(1, Circuit::num_constraints());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_witness_only_restores_mode_on_panic() {
+        Circuit::reset();
+
+        // Ensure the environment leaves witness-only mode, even if the logic panics.
+        let result = std::panic::catch_unwind(|| Circuit::witness_only(|| Circuit::halt::<_, ()>("halt")));
+        assert!(result.is_err());
+        assert!(!Circuit::is_witness_only());
+
+        // Ensure a reset keeps witness-only mode.
+        Circuit::witness_only(|| {
+            Circuit::reset();
+            assert!(Circuit::is_witness_only());
+            let a = Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::from_u64(3));
+            let _product = &a * &a;
+            assert_eq!(0, Circuit::num_constraints());
+        });
+        assert!(!Circuit::is_witness_only());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_profile_exits_label_on_panic() {
+        Circuit::reset();
+
+        // Ensure the label is exited, even if the logic panics.
+        Circuit::enable_profiler();
+        let result = std::panic::catch_unwind(|| Circuit::profile("panics", || Circuit::halt::<_, ()>("halt")));
+        assert!(result.is_err());
+        Circuit::profile("after", || Circuit::new_variable(Mode::Private, Default::default()));
+        let profile = Circuit::disable_profiler();
+        assert!(profile.get("after").is_some());
+        assert!(profile.get("panics::after").is_none());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_label_restored_on_panic() {
+        Circuit::reset();
+
+        // Ensure the label is restored, even if the logic panics.
+        let result = std::panic::catch_unwind(|| Circuit::label("panics", || Circuit::halt::<_, ()>("halt")));
+        assert!(result.is_err());
+        let one = Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::one());
+        Circuit::assert_eq(&one, Circuit::zero());
+        assert_eq!(None, Circuit::first_unsatisfied_constraint().unwrap().label);
+
+        // Ensure a reset clears the label.
+        Circuit::reset();
+        Circuit::label("outer", || {
+            Circuit::reset();
+            let one = Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::one());
+            Circuit::assert_eq(&one, Circuit::zero());
+            assert_eq!(None, Circuit::first_unsatisfied_constraint().unwrap().label);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_first_unsatisfied_constraint() {
+        Circuit::reset();
+
+        let one = Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::one());
+        let two = Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::from_u64(2));
+
+        // Enforce a satisfied constraint, followed by two unsatisfied constraints.
+        Circuit::assert_eq_labeled("one == one", &one, &one);
+        Circuit::label("outer", || Circuit::assert_eq_labeled("one == two", &one, &two));
+        Circuit::assert_eq(&two, &one);
+        assert!(!Circuit::is_satisfied());
+
+        // Ensure the first unsatisfied constraint is surfaced, with its label and witnesses.
+        let constraint = Circuit::first_unsatisfied_constraint().unwrap();
+        assert_eq!(1, constraint.index);
+        assert_eq!(Some("outer > one == two".to_string()), constraint.label);
+        let (a, b, c) = constraint.values;
+        assert_eq!(*one.eject_value(), a);
+        assert_eq!(*one.eject_value(), b);
+        assert_eq!(*two.eject_value(), c);
+        assert_eq!(2, constraint.witnesses.len());
+        assert!(constraint.to_string().starts_with("Constraint 1 'outer > one == two' at"));
+
+        // Ensure the label does not persist outside of its logic.
+        Circuit::reset();
+        let one = Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::one());
+        Circuit::assert_eq(&one, Circuit::zero());
+        let constraint = Circuit::first_unsatisfied_constraint().unwrap();
+        assert_eq!(0, constraint.index);
+        assert_eq!(None, constraint.label);
+        Circuit::reset();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rollback_in_different_scope() {
+        Circuit::reset();
+        let checkpoint = Circuit::checkpoint();
+        Circuit::scope("test_rollback_in_different_scope", || Circuit::rollback(&checkpoint));
+    }
+
     #[test]
     fn test_circuit_scope() {
         Circuit::scope("test_circuit_scope", || {
@@ -14,7 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{witness_mode, Assignment, Inject, LinearCombination, Mode, Variable, R1CS};
+use crate::{
+    witness_mode,
+    Assignment,
+    Checkpoint,
+    Inject,
+    LinearCombination,
+    Mode,
+    Profile,
+    UnsatisfiedConstraint,
+    Variable,
+    R1CS,
+};
 use snarkvm_curves::AffineCurve;
 use snarkvm_fields::traits::*;
 
@@ -56,6 +67,16 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
     /// Returns a new witness of the given mode and value.
     fn new_witness<Fn: FnOnce() -> Output::Primitive, Output: Inject>(mode: Mode, value: Fn) -> Output;
 
+    /// Runs the given logic in witness-only mode, which computes witness values without allocating constraints.
+    ///
+    /// Circuits synthesized in witness-only mode are unconstrained, and must not be used to produce proofs.
+    fn witness_only<Fn, Output>(logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output;
+
+    /// Returns `true` if the environment is in witness-only mode.
+    fn is_witness_only() -> bool;
+
     /// Enters a new scope for the environment.
     fn scope<S: Into<String>, Fn, Output>(name: S, logic: Fn) -> Output
     where
@@ -105,12 +126,52 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
         Self::enforce(|| (a_minus_b, multiplier, Self::one()));
     }
 
+    /// Adds one constraint enforcing that the given boolean is `true`, under the given label.
+    fn assert_labeled<L: fmt::Display, Boolean: Into<LinearCombination<Self::BaseField>>>(label: L, boolean: Boolean) {
+        Self::label(label, || Self::assert(boolean))
+    }
+
+    /// Adds one constraint enforcing that the `A == B`, under the given label.
+    fn assert_eq_labeled<L: fmt::Display, A, B>(label: L, a: A, b: B)
+    where
+        A: Into<LinearCombination<Self::BaseField>>,
+        B: Into<LinearCombination<Self::BaseField>>,
+    {
+        Self::label(label, || Self::assert_eq(a, b))
+    }
+
+    /// Adds one constraint enforcing that the `A != B`, under the given label.
+    fn assert_neq_labeled<L: fmt::Display, A, B>(label: L, a: A, b: B)
+    where
+        A: Into<LinearCombination<Self::BaseField>>,
+        B: Into<LinearCombination<Self::BaseField>>,
+    {
+        Self::label(label, || Self::assert_neq(a, b))
+    }
+
+    /// Runs the given logic under the given label, which is attached to every constraint enforced in the logic.
+    fn label<L: fmt::Display, Fn, Output>(label: L, logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output;
+
+    /// Returns a checkpoint of the environment, which can be restored with `Self::rollback`.
+    fn checkpoint() -> Checkpoint;
+
+    /// Discards all variables and constraints that were synthesized since the given checkpoint.
+    ///
+    /// The checkpoint must be rolled back in the scope it was created in, and any circuit
+    /// that was created after the checkpoint must not be used after rolling back.
+    fn rollback(checkpoint: &Checkpoint);
+
     /// Returns `true` if all constraints in the environment are satisfied.
     fn is_satisfied() -> bool;
 
     /// Returns `true` if all constraints in the current scope are satisfied.
     fn is_satisfied_in_scope() -> bool;
 
+    /// Returns the diagnostics of the first constraint in the environment that is not satisfied, if one exists.
+    fn first_unsatisfied_constraint() -> Option<UnsatisfiedConstraint<Self::BaseField>>;
+
     /// Returns the number of constants in the entire environment.
     fn num_constants() -> u64;
 
@@ -157,6 +218,17 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
         )
     }
 
+    /// Enables the profiler, which attributes the circuit resources to the labels entered with `Self::profile`.
+    fn enable_profiler();
+
+    /// Disables the profiler, returning the profile recorded since it was enabled.
+    fn disable_profiler() -> Profile;
+
+    /// Runs the given logic under the given label, attributing its circuit resources to the label if the profiler is enabled.
+    fn profile<L: fmt::Display, Fn, Output>(label: L, logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output;
+
     /// Halts the program from further synthesis, evaluation, and execution in the current environment.
     fn halt<S: Into<String>, T>(message: S) -> T {
         <Self::Network as console::Environment>::halt(message)
@@ -15,7 +15,13 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::Index;
-use snarkvm_fields::PrimeField;
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{
+    biginteger::BigInteger,
+    error,
+    io::{Result as IoResult, Write},
+    ToBytes,
+};
 
 use indexmap::IndexMap;
 
@@ -103,6 +109,119 @@ impl<F: PrimeField> Assignment<F> {
     }
 }
 
+impl<F: PrimeField> Assignment<F> {
+    /// Writes the constraints of the assignment to the given writer, in the circom `.r1cs` binary format (version 1).
+    ///
+    /// Wire `0` is the first public variable, which is always the constant `1`, followed by
+    /// the remaining public variables as public inputs, and then the private variables, in order.
+    pub fn write_r1cs<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        let num_wires = to_u32(self.num_public() + self.num_private())?;
+
+        // Prepare the header section.
+        let mut header = Vec::new();
+        write_prime::<F, _>(&mut header)?;
+        num_wires.write_le(&mut header)?;
+        // Write the number of public outputs.
+        0u32.write_le(&mut header)?;
+        // Write the number of public inputs, excluding the constant wire.
+        to_u32(self.num_public().saturating_sub(1))?.write_le(&mut header)?;
+        // Write the number of private inputs, as every private variable is treated as an internal wire.
+        0u32.write_le(&mut header)?;
+        // Write the number of labels.
+        (num_wires as u64).write_le(&mut header)?;
+        to_u32(self.num_constraints())?.write_le(&mut header)?;
+
+        // Prepare the constraints section.
+        let mut constraints = Vec::new();
+        for (a, b, c) in &self.constraints {
+            for lc in [a, b, c] {
+                self.write_linear_combination(lc, &mut constraints)?;
+            }
+        }
+
+        // Prepare the wire-to-label section, which maps each wire to itself.
+        let mut labels = Vec::new();
+        for wire in 0..num_wires as u64 {
+            wire.write_le(&mut labels)?;
+        }
+
+        writer.write_all(b"r1cs")?;
+        1u32.write_le(&mut writer)?;
+        write_sections(&mut writer, &[(1, header), (2, constraints), (3, labels)])
+    }
+
+    /// Writes the values of the assignment to the given writer, in the circom `.wtns` binary format (version 2).
+    ///
+    /// The witness values are ordered to match the wires of `Self::write_r1cs`.
+    pub fn write_witness<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Prepare the header section.
+        let mut header = Vec::new();
+        write_prime::<F, _>(&mut header)?;
+        to_u32(self.num_public() + self.num_private())?.write_le(&mut header)?;
+
+        // Prepare the witness section.
+        let mut values = Vec::new();
+        for value in self.public.values().chain(self.private.values()) {
+            value.to_bigint().write_le(&mut values)?;
+        }
+
+        writer.write_all(b"wtns")?;
+        2u32.write_le(&mut writer)?;
+        write_sections(&mut writer, &[(1, header), (2, values)])
+    }
+
+    /// Writes the given linear combination as a list of `(wire, coefficient)` terms,
+    /// where the constant term is assigned to the constant wire `0`.
+    fn write_linear_combination<W: Write>(&self, lc: &AssignmentLC<F>, mut writer: W) -> IoResult<()> {
+        // Accumulate the constant terms into a single coefficient for the constant wire.
+        let mut constant = lc.constant;
+        let mut terms = IndexMap::<u64, F>::with_capacity(lc.terms.len());
+        for (variable, coefficient) in &lc.terms {
+            let wire = match variable {
+                AssignmentVariable::Constant(value) => {
+                    constant += *coefficient * value;
+                    continue;
+                }
+                AssignmentVariable::Public(index) => *index,
+                AssignmentVariable::Private(index) => self.num_public() + index,
+            };
+            *terms.entry(wire).or_insert_with(F::zero) += coefficient;
+        }
+        *terms.entry(0).or_insert_with(F::zero) += constant;
+        // Remove the terms that cancelled out.
+        terms.retain(|_, coefficient| !coefficient.is_zero());
+
+        to_u32(terms.len() as u64)?.write_le(&mut writer)?;
+        for (wire, coefficient) in terms {
+            to_u32(wire)?.write_le(&mut writer)?;
+            coefficient.to_bigint().write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the given count as a `u32`, as required by the circom binary formats.
+fn to_u32(value: u64) -> IoResult<u32> {
+    u32::try_from(value).map_err(|_| error("The circuit is too large to export in the circom format"))
+}
+
+/// Writes the size of a field element in bytes, followed by the little-endian modulus.
+fn write_prime<F: PrimeField, W: Write>(mut writer: W) -> IoResult<()> {
+    ((F::BigInteger::NUM_LIMBS * 8) as u32).write_le(&mut writer)?;
+    F::modulus().write_le(&mut writer)
+}
+
+/// Writes the given `(type, contents)` sections, each prefixed with its type and length.
+fn write_sections<W: Write>(mut writer: W, sections: &[(u32, Vec<u8>)]) -> IoResult<()> {
+    (sections.len() as u32).write_le(&mut writer)?;
+    for (section_type, contents) in sections {
+        section_type.write_le(&mut writer)?;
+        (contents.len() as u64).write_le(&mut writer)?;
+        writer.write_all(contents)?;
+    }
+    Ok(())
+}
+
 impl<F: PrimeField> snarkvm_r1cs::ConstraintSynthesizer<F> for Assignment<F> {
     /// Synthesizes the constraints from the environment into a `snarkvm_r1cs`-compliant constraint system.
     fn generate_constraints<CS: snarkvm_r1cs::ConstraintSystem<F>>(
@@ -268,6 +387,89 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_circom_export() {
+        use snarkvm_utilities::{io::Read, FromBytes, ToBytes};
+
+        /// Reads the next `u32` from the given bytes.
+        fn read_u32(reader: &mut &[u8]) -> u32 {
+            u32::read_le(reader).unwrap()
+        }
+
+        /// Reads the next field element from the given bytes.
+        fn read_field(reader: &mut &[u8]) -> Fr {
+            Fr::from_bigint(FromBytes::read_le(reader).unwrap()).unwrap()
+        }
+
+        /// Reads the next section from the given bytes, checking its type.
+        fn read_section<'a>(reader: &mut &'a [u8], expected_type: u32) -> &'a [u8] {
+            assert_eq!(expected_type, read_u32(reader));
+            let size = u64::read_le(&mut *reader).unwrap() as usize;
+            let (section, remaining) = reader.split_at(size);
+            *reader = remaining;
+            section
+        }
+
+        let _candidate_output = create_example_circuit::<Circuit>();
+        let assignment = Circuit::eject_assignment_and_reset();
+        let num_wires = assignment.num_public() + assignment.num_private();
+
+        // Export the witness, and read back the values.
+        let mut witness_bytes = Vec::new();
+        assignment.write_witness(&mut witness_bytes).unwrap();
+        let reader = &mut &witness_bytes[..];
+        let mut magic = [0u8; 4];
+        reader.read_exact(&mut magic).unwrap();
+        assert_eq!(b"wtns", &magic);
+        assert_eq!(2, read_u32(reader));
+        assert_eq!(2, read_u32(reader));
+        let header = &mut read_section(reader, 1);
+        assert_eq!(32, read_u32(header));
+        assert_eq!(Fr::modulus().to_bytes_le().unwrap(), header[..32]);
+        *header = &header[32..];
+        assert_eq!(num_wires, read_u32(header) as u64);
+        let values = &mut read_section(reader, 2);
+        let witness: Vec<Fr> = (0..num_wires).map(|_| read_field(values)).collect();
+        assert_eq!(Fr::one(), witness[0]);
+        assert!(values.is_empty() && reader.is_empty());
+
+        // Export the constraints, and check them against the witness.
+        let mut r1cs_bytes = Vec::new();
+        assignment.write_r1cs(&mut r1cs_bytes).unwrap();
+        let reader = &mut &r1cs_bytes[..];
+        reader.read_exact(&mut magic).unwrap();
+        assert_eq!(b"r1cs", &magic);
+        assert_eq!(1, read_u32(reader));
+        assert_eq!(3, read_u32(reader));
+        let header = &mut read_section(reader, 1);
+        assert_eq!(32, read_u32(header));
+        assert_eq!(Fr::modulus().to_bytes_le().unwrap(), header[..32]);
+        *header = &header[32..];
+        assert_eq!(num_wires, read_u32(header) as u64);
+        assert_eq!(0, read_u32(header));
+        assert_eq!(assignment.num_public() - 1, read_u32(header) as u64);
+        assert_eq!(0, read_u32(header));
+        assert_eq!(num_wires, u64::read_le(&mut *header).unwrap());
+        assert_eq!(assignment.num_constraints(), read_u32(header) as u64);
+
+        let constraints = &mut read_section(reader, 2);
+        for _ in 0..assignment.num_constraints() {
+            let mut evaluations = [Fr::zero(); 3];
+            for evaluation in evaluations.iter_mut() {
+                for _ in 0..read_u32(constraints) {
+                    let wire = read_u32(constraints) as usize;
+                    *evaluation += read_field(constraints) * witness[wire];
+                }
+            }
+            assert_eq!(evaluations[0] * evaluations[1], evaluations[2]);
+        }
+        assert!(constraints.is_empty());
+
+        let labels = read_section(reader, 3);
+        assert_eq!(num_wires as usize * 8, labels.len());
+        assert!(reader.is_empty());
+    }
+
     #[test]
     fn test_marlin() {
         let _candidate_output = create_example_circuit::<Circuit>();
@@ -0,0 +1,51 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Scope;
+
+/// A snapshot of the size of the environment, which the environment can be rolled back to.
+///
+/// A checkpoint must be rolled back in the same scope it was created in. Any circuit that was
+/// created after the checkpoint must not be used after rolling back, as its variables are discarded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Checkpoint {
+    /// The scope the checkpoint was created in.
+    pub(crate) scope: Scope,
+    /// The number of constants in the environment.
+    pub(crate) num_constants: u64,
+    /// The number of public variables in the environment.
+    pub(crate) num_public: u64,
+    /// The number of private variables in the environment.
+    pub(crate) num_private: u64,
+    /// The number of constraints in the environment.
+    pub(crate) num_constraints: u64,
+    /// The number of gates in the environment.
+    pub(crate) num_gates: u64,
+    /// The number of constants, public variables, private variables, constraints, and gates in the scope.
+    pub(crate) in_scope: (u64, u64, u64, u64, u64),
+}
+
+impl Checkpoint {
+    /// Returns the scope the checkpoint was created in.
+    pub fn scope(&self) -> &str {
+        &self.scope
+    }
+
+    /// Returns a tuple containing the number of constants, public variables, private variables, constraints, and gates at the checkpoint.
+    pub fn count(&self) -> (u64, u64, u64, u64, u64) {
+        (self.num_constants, self.num_public, self.num_private, self.num_constraints, self.num_gates)
+    }
+}
@@ -17,12 +17,16 @@
 use crate::{prelude::*, *};
 use snarkvm_fields::PrimeField;
 
+use indexmap::IndexSet;
+use std::rc::Rc;
+
 #[derive(Clone, Debug)]
 pub(crate) struct Constraint<F: PrimeField>(
     pub(crate) Scope,
     pub(crate) LinearCombination<F>,
     pub(crate) LinearCombination<F>,
     pub(crate) LinearCombination<F>,
+    pub(crate) Option<Rc<String>>,
 );
 
 impl<F: PrimeField> Constraint<F> {
@@ -32,6 +36,12 @@ impl<F: PrimeField> Constraint<F> {
         1 + a.num_additions() + b.num_additions() + c.num_additions()
     }
 
+    /// Returns the number of nonzero entries contributed by this constraint to the `A`, `B`, and `C` matrices.
+    pub(crate) fn num_nonzeros(&self) -> u64 {
+        let (a, b, c) = (&self.1, &self.2, &self.3);
+        a.num_nonzeros() + b.num_nonzeros() + c.num_nonzeros()
+    }
+
     /// Returns `true` if the constraint is satisfied.
     pub(crate) fn is_satisfied(&self) -> bool {
         let (scope, a, b, c) = (&self.0, &self.1, &self.2, &self.3);
@@ -42,12 +52,43 @@ impl<F: PrimeField> Constraint<F> {
         match a * b == c {
             true => true,
             false => {
-                eprintln!("Failed constraint at {scope}:\n\t({a} * {b}) != {c}");
+                match &self.4 {
+                    Some(label) => eprintln!("Failed constraint '{label}' at {scope}:\n\t({a} * {b}) != {c}"),
+                    None => eprintln!("Failed constraint at {scope}:\n\t({a} * {b}) != {c}"),
+                }
                 false
             }
         }
     }
 
+    /// Returns the label the constraint was enforced with, if one was set.
+    pub(crate) fn label(&self) -> Option<&str> {
+        self.4.as_deref().map(|label| label.as_str())
+    }
+
+    /// Returns the diagnostics for this constraint, if it is not satisfied.
+    /// The given index is the position of the constraint in the environment.
+    pub(crate) fn to_unsatisfied(&self, index: u64) -> Option<UnsatisfiedConstraint<F>> {
+        let (a, b, c) = (self.1.value(), self.2.value(), self.3.value());
+        match a * b == c {
+            true => None,
+            false => {
+                // Collect the distinct variables in `A`, `B`, and `C`, in order of appearance.
+                let mut witnesses = IndexSet::new();
+                for lc in [&self.1, &self.2, &self.3] {
+                    witnesses.extend(lc.to_terms().keys().cloned());
+                }
+                Some(UnsatisfiedConstraint {
+                    index,
+                    scope: self.0.clone(),
+                    label: self.label().map(|label| label.to_string()),
+                    values: (a, b, c),
+                    witnesses: witnesses.into_iter().collect(),
+                })
+            }
+        }
+    }
+
     /// Returns a reference to the terms `(a, b, c)`.
     pub(crate) fn to_terms(&self) -> (&LinearCombination<F>, &LinearCombination<F>, &LinearCombination<F>) {
         (&self.1, &self.2, &self.3)
@@ -92,6 +92,33 @@ impl<F: PrimeField> Counter<F> {
         Ok(())
     }
 
+    /// Returns the number of constants, public variables, private variables, constraints, and gates in scope.
+    pub(crate) fn checkpoint(&self) -> (u64, u64, u64, u64, u64) {
+        (self.constants, self.public, self.private, self.constraints.len() as u64, self.gates)
+    }
+
+    /// Reverts the members of the current scope to the given counts, as returned by `Self::checkpoint`.
+    pub(crate) fn rollback(&mut self, checkpoint: (u64, u64, u64, u64, u64)) -> Result<(), String> {
+        let (constants, public, private, constraints, gates) = checkpoint;
+
+        // Ensure the checkpoint does not exceed the members of the current scope.
+        if constants > self.constants
+            || public > self.public
+            || private > self.private
+            || constraints > self.constraints.len() as u64
+            || gates > self.gates
+        {
+            return Err("Invalid checkpoint. The checkpoint exceeds the current scope".to_string());
+        }
+
+        self.constants = constants;
+        self.public = public;
+        self.private = private;
+        self.constraints.truncate(constraints as usize);
+        self.gates = gates;
+        Ok(())
+    }
+
     /// Increments the number of constraints by 1.
     pub(crate) fn add_constraint(&mut self, constraint: Constraint<F>) {
         self.gates += constraint.num_gates();
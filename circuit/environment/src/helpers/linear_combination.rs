@@ -126,6 +126,11 @@ impl<F: PrimeField> LinearCombination<F> {
         &self.terms
     }
 
+    /// Returns the number of nonzero coefficients in the linear combination, including the constant.
+    pub(super) fn num_nonzeros(&self) -> u64 {
+        self.terms.len() as u64 + u64::from(!self.constant.is_zero())
+    }
+
     /// Returns the number of addition gates in the linear combination.
     pub(super) fn num_additions(&self) -> u64 {
         // Increment by one if the constant is nonzero and the number of terms is nonzero.
@@ -438,6 +443,15 @@ impl<F: PrimeField> Mul<&F> for &LinearCombination<F> {
     }
 }
 
+impl<F: PrimeField> PartialEq for LinearCombination<F> {
+    /// Returns `true` if the linear combinations have the same constant and terms.
+    fn eq(&self, other: &Self) -> bool {
+        self.constant == other.constant && self.terms == other.terms
+    }
+}
+
+impl<F: PrimeField> Eq for LinearCombination<F> {}
+
 impl<F: PrimeField> fmt::Debug for LinearCombination<F> {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         let mut output = format!("Constant({})", self.constant);
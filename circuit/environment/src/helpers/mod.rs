@@ -17,6 +17,9 @@
 mod assignment;
 pub use assignment::Assignment;
 
+mod checkpoint;
+pub use checkpoint::Checkpoint;
+
 pub mod circuit_type;
 pub use circuit_type::*;
 
@@ -37,6 +40,13 @@ pub use linear_combination::*;
 mod mode;
 pub use mode::*;
 
+pub(super) mod profiler;
+pub(super) use profiler::Profiler;
+pub use profiler::{Profile, ProfileEntry};
+
+mod unsatisfied;
+pub use unsatisfied::UnsatisfiedConstraint;
+
 pub mod variable;
 pub use variable::*;
 
@@ -0,0 +1,170 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{helpers::Constraint, prelude::*, Mode};
+use snarkvm_fields::PrimeField;
+
+/// The separator between nested labels in a profile.
+const LABEL_SEPARATOR: &str = "::";
+
+/// The circuit resources attributed to one label by the profiler.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProfileEntry {
+    /// The number of constants.
+    pub num_constants: u64,
+    /// The number of public variables.
+    pub num_public: u64,
+    /// The number of private variables.
+    pub num_private: u64,
+    /// The number of constraints.
+    pub num_constraints: u64,
+    /// The number of nonzero entries in the `A`, `B`, and `C` matrices.
+    pub num_nonzeros: u64,
+}
+
+impl Add for ProfileEntry {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            num_constants: self.num_constants + other.num_constants,
+            num_public: self.num_public + other.num_public,
+            num_private: self.num_private + other.num_private,
+            num_constraints: self.num_constraints + other.num_constraints,
+            num_nonzeros: self.num_nonzeros + other.num_nonzeros,
+        }
+    }
+}
+
+/// A profile of the circuit resources, attributed to the label that was active when they were synthesized.
+///
+/// Nested labels are joined with `::`, and resources synthesized outside of any label are attributed to `""`.
+/// Each entry is exclusive of its nested labels; see `Profile::total` for the inclusive count.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Profile {
+    entries: IndexMap<String, ProfileEntry>,
+}
+
+impl Profile {
+    /// Returns the resources attributed to the given label, excluding its nested labels.
+    pub fn get(&self, label: &str) -> Option<&ProfileEntry> {
+        self.entries.get(label)
+    }
+
+    /// Returns the resources attributed to the given label, including its nested labels.
+    pub fn total(&self, label: &str) -> ProfileEntry {
+        self.entries
+            .iter()
+            .filter(|(candidate, _)| match candidate.strip_prefix(label) {
+                Some(suffix) => label.is_empty() || suffix.is_empty() || suffix.starts_with(LABEL_SEPARATOR),
+                None => false,
+            })
+            .fold(ProfileEntry::default(), |total, (_, entry)| total + *entry)
+    }
+
+    /// Returns an iterator over the labels and their resources, in the order they were first synthesized.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &ProfileEntry)> {
+        self.entries.iter()
+    }
+
+    /// Returns `true` if no resources were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>12} {:>12} {:>12} {:>12} {:>12}  Label",
+            "Constants", "Public", "Private", "Constraints", "Nonzeros"
+        )?;
+        for (label, entry) in self.iter() {
+            writeln!(
+                f,
+                "{:>12} {:>12} {:>12} {:>12} {:>12}  {}",
+                entry.num_constants,
+                entry.num_public,
+                entry.num_private,
+                entry.num_constraints,
+                entry.num_nonzeros,
+                if label.is_empty() { "(unlabeled)" } else { label }
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The profiler, which records the circuit resources synthesized under the current label.
+#[derive(Debug, Default)]
+pub(crate) struct Profiler {
+    /// The current label, as the nested labels joined by `::`.
+    label: String,
+    /// The length of the current label, before each nested label was pushed.
+    parents: Vec<usize>,
+    /// The recorded profile.
+    profile: Profile,
+}
+
+impl Profiler {
+    /// Enters the given nested label.
+    pub(crate) fn push<L: Display>(&mut self, label: L) {
+        self.parents.push(self.label.len());
+        if !self.label.is_empty() {
+            self.label += LABEL_SEPARATOR;
+        }
+        self.label += &label.to_string();
+    }
+
+    /// Exits the current nested label.
+    pub(crate) fn pop(&mut self) {
+        if let Some(length) = self.parents.pop() {
+            self.label.truncate(length);
+        }
+    }
+
+    /// Records a new variable of the given mode.
+    pub(crate) fn add_variable(&mut self, mode: Mode) {
+        let entry = self.entry();
+        match mode {
+            Mode::Constant => entry.num_constants += 1,
+            Mode::Public => entry.num_public += 1,
+            Mode::Private => entry.num_private += 1,
+        }
+    }
+
+    /// Records the given constraint.
+    pub(crate) fn add_constraint<F: PrimeField>(&mut self, constraint: &Constraint<F>) {
+        let entry = self.entry();
+        entry.num_constraints += 1;
+        entry.num_nonzeros += constraint.num_nonzeros();
+    }
+
+    /// Returns the recorded profile, consuming the profiler.
+    pub(crate) fn into_profile(self) -> Profile {
+        self.profile
+    }
+
+    /// Returns the entry for the current label.
+    fn entry(&mut self) -> &mut ProfileEntry {
+        if !self.profile.entries.contains_key(&self.label) {
+            self.profile.entries.insert(self.label.clone(), ProfileEntry::default());
+        }
+        // Note: The entry is guaranteed to exist, as it was inserted above.
+        self.profile.entries.get_mut(&self.label).unwrap()
+    }
+}
@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    helpers::{Constraint, Counter},
+    helpers::{Checkpoint, Constraint, Counter, UnsatisfiedConstraint},
     prelude::*,
 };
 use snarkvm_fields::PrimeField;
@@ -57,6 +57,46 @@ impl<F: PrimeField> R1CS<F> {
         self.counter.pop(name)
     }
 
+    /// Returns a checkpoint of the current size of the constraint system.
+    pub(crate) fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            scope: self.scope(),
+            num_constants: self.num_constants(),
+            num_public: self.num_public(),
+            num_private: self.num_private(),
+            num_constraints: self.num_constraints(),
+            num_gates: self.num_gates(),
+            in_scope: self.counter.checkpoint(),
+        }
+    }
+
+    /// Discards all variables and constraints that were added since the given checkpoint.
+    pub(crate) fn rollback(&mut self, checkpoint: &Checkpoint) -> Result<(), String> {
+        // Ensure the checkpoint was created in the current scope.
+        if checkpoint.scope != self.scope() {
+            return Err("Mismatching scope. Checkpoints must be rolled back in the scope they are created".to_string());
+        }
+        // Ensure the checkpoint does not exceed the constraint system.
+        if checkpoint.num_constants > self.num_constants()
+            || checkpoint.num_public > self.num_public()
+            || checkpoint.num_private > self.num_private()
+            || checkpoint.num_constraints > self.num_constraints()
+            || checkpoint.num_gates > self.num_gates()
+        {
+            return Err("Invalid checkpoint. The checkpoint exceeds the constraint system".to_string());
+        }
+
+        // Revert the members of the current scope.
+        self.counter.rollback(checkpoint.in_scope)?;
+        // Revert the constraint system.
+        self.constants.truncate(checkpoint.num_constants as usize);
+        self.public.truncate(checkpoint.num_public as usize);
+        self.private.truncate(checkpoint.num_private as usize);
+        self.constraints.truncate(checkpoint.num_constraints as usize);
+        self.gates = checkpoint.num_gates;
+        Ok(())
+    }
+
     /// Returns a new constant with the given value and scope.
     pub(crate) fn new_constant(&mut self, value: F) -> Variable<F> {
         let variable = Variable::Constant(Rc::new(value));
@@ -103,6 +143,11 @@ impl<F: PrimeField> R1CS<F> {
         self.counter.scope()
     }
 
+    /// Returns the diagnostics of the first constraint that is not satisfied, if one exists.
+    pub(crate) fn first_unsatisfied_constraint(&self) -> Option<UnsatisfiedConstraint<F>> {
+        self.constraints.iter().enumerate().find_map(|(index, constraint)| constraint.to_unsatisfied(index as u64))
+    }
+
     /// Returns the number of constants in the constraint system.
     pub(crate) fn num_constants(&self) -> u64 {
         self.constants.len() as u64
@@ -0,0 +1,52 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{prelude::*, Scope, Variable};
+use snarkvm_fields::PrimeField;
+
+/// The diagnostics of a constraint that is not satisfied by its witness values.
+#[derive(Clone, Debug)]
+pub struct UnsatisfiedConstraint<F: PrimeField> {
+    /// The index of the constraint in the environment.
+    pub index: u64,
+    /// The scope the constraint was enforced in.
+    pub scope: Scope,
+    /// The label the constraint was enforced with, if one was set.
+    pub label: Option<String>,
+    /// The values of `(A, B, C)`, where `A * B != C`.
+    pub values: (F, F, F),
+    /// The distinct variables in `A`, `B`, and `C`, with their witness values.
+    pub witnesses: Vec<Variable<F>>,
+}
+
+impl<F: PrimeField> Display for UnsatisfiedConstraint<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (a, b, c) = &self.values;
+        write!(f, "Constraint {}", self.index)?;
+        if let Some(label) = &self.label {
+            write!(f, " '{label}'")?;
+        }
+        write!(f, " at '{}' is not satisfied: ({a} * {b}) != {c}", self.scope)?;
+        if !self.witnesses.is_empty() {
+            write!(
+                f,
+                "\n\twith witnesses: {}",
+                self.witnesses.iter().map(|witness| format!("{witness:?}")).join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -93,6 +93,12 @@ pub trait Aleo: Environment {
     /// Returns the Poseidon hash with an input rate of 8.
     fn hash_psd8(input: &[Field<Self>]) -> Field<Self>;
 
+    /// Returns the Poseidon2 hash with an input rate of 2.
+    fn hash_psd2v2(input: &[Field<Self>]) -> Field<Self>;
+
+    /// Returns the BLAKE3 hash of the given byte string, truncated to the data bits of a field element.
+    fn hash_blake3(input: &[Boolean<Self>]) -> Field<Self>;
+
     /// Returns the extended Poseidon hash with an input rate of 2.
     fn hash_many_psd2(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>>;
 
@@ -16,6 +16,7 @@
 
 use crate::Aleo;
 use snarkvm_circuit_algorithms::{
+    Blake3,
     Commit,
     CommitUncompressed,
     Hash,
@@ -25,6 +26,7 @@ use snarkvm_circuit_algorithms::{
     Pedersen128,
     Pedersen64,
     Poseidon2,
+    Poseidon2V2,
     Poseidon4,
     Poseidon8,
     BHP1024,
@@ -34,7 +36,7 @@ use snarkvm_circuit_algorithms::{
 };
 use snarkvm_circuit_collections::merkle_tree::MerklePath;
 use snarkvm_circuit_types::{
-    environment::{prelude::*, Assignment, Circuit, R1CS},
+    environment::{prelude::*, Assignment, Checkpoint, Circuit, Profile, UnsatisfiedConstraint, R1CS},
     Boolean,
     Field,
     Group,
@@ -82,6 +84,8 @@ thread_local! {
     static POSEIDON_4: Poseidon4<AleoV0> = Poseidon4::<AleoV0>::constant(console::POSEIDON_4.clone());
     /// The Poseidon hash function, using a rate of 8.
     static POSEIDON_8: Poseidon8<AleoV0> = Poseidon8::<AleoV0>::constant(console::POSEIDON_8.clone());
+    /// The Poseidon2 hash function, using a rate of 2.
+    static POSEIDON_2_V2: Poseidon2V2<AleoV0> = Poseidon2V2::<AleoV0>::constant(console::POSEIDON_2_V2.clone());
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
@@ -204,6 +208,17 @@ impl Aleo for AleoV0 {
         POSEIDON_8.with(|poseidon| poseidon.hash(input))
     }
 
+    /// Returns the Poseidon2 hash with an input rate of 2.
+    fn hash_psd2v2(input: &[Field<Self>]) -> Field<Self> {
+        POSEIDON_2_V2.with(|poseidon| poseidon.hash(input))
+    }
+
+    /// Returns the BLAKE3 hash of the given byte string, truncated to the data bits of a field element.
+    fn hash_blake3(input: &[Boolean<Self>]) -> Field<Self> {
+        let digest = Blake3::<Self>::new().hash(input);
+        Field::from_bits_le(&digest[..<Self as Environment>::BaseField::size_in_data_bits()])
+    }
+
     /// Returns the extended Poseidon hash with an input rate of 2.
     fn hash_many_psd2(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
         POSEIDON_2.with(|poseidon| poseidon.hash_many(input, num_outputs))
@@ -294,6 +309,19 @@ impl Environment for AleoV0 {
         E::new_witness(mode, logic)
     }
 
+    /// Runs the given logic in witness-only mode, which computes witness values without allocating constraints.
+    fn witness_only<Fn, Output>(logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        E::witness_only(logic)
+    }
+
+    /// Returns `true` if the environment is in witness-only mode.
+    fn is_witness_only() -> bool {
+        E::is_witness_only()
+    }
+
     /// Enters a new scope for the environment.
     fn scope<S: Into<String>, Fn, Output>(name: S, logic: Fn) -> Output
     where
@@ -313,6 +341,24 @@ impl Environment for AleoV0 {
         E::enforce(constraint)
     }
 
+    /// Runs the given logic under the given label, which is attached to every constraint enforced in the logic.
+    fn label<L: fmt::Display, Fn, Output>(label: L, logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        E::label(label, logic)
+    }
+
+    /// Returns a checkpoint of the environment, which can be restored with `Self::rollback`.
+    fn checkpoint() -> Checkpoint {
+        E::checkpoint()
+    }
+
+    /// Discards all variables and constraints that were synthesized since the given checkpoint.
+    fn rollback(checkpoint: &Checkpoint) {
+        E::rollback(checkpoint)
+    }
+
     /// Returns `true` if all constraints in the environment are satisfied.
     fn is_satisfied() -> bool {
         E::is_satisfied()
@@ -323,6 +369,11 @@ impl Environment for AleoV0 {
         E::is_satisfied_in_scope()
     }
 
+    /// Returns the diagnostics of the first constraint in the environment that is not satisfied, if one exists.
+    fn first_unsatisfied_constraint() -> Option<UnsatisfiedConstraint<Self::BaseField>> {
+        E::first_unsatisfied_constraint()
+    }
+
     /// Returns the number of constants in the entire circuit.
     fn num_constants() -> u64 {
         E::num_constants()
@@ -373,6 +424,24 @@ impl Environment for AleoV0 {
         E::num_gates_in_scope()
     }
 
+    /// Enables the profiler, which attributes the circuit resources to the labels entered with `Self::profile`.
+    fn enable_profiler() {
+        E::enable_profiler()
+    }
+
+    /// Disables the profiler, returning the profile recorded since it was enabled.
+    fn disable_profiler() -> Profile {
+        E::disable_profiler()
+    }
+
+    /// Runs the given logic under the given label, attributing its circuit resources to the label if the profiler is enabled.
+    fn profile<L: fmt::Display, Fn, Output>(label: L, logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        E::profile(label, logic)
+    }
+
     /// Halts the program from further synthesis, evaluation, and execution in the current environment.
     fn halt<S: Into<String>, T>(message: S) -> T {
         E::halt(message)
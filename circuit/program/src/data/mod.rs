@@ -27,7 +27,7 @@ mod plaintext;
 pub use plaintext::Plaintext;
 
 mod record;
-pub use record::{Balance, Entry, Owner, Record};
+pub use record::{Balance, Entry, EntryDisclosure, Owner, Record};
 
 mod value;
 pub use value::Value;
@@ -27,6 +27,8 @@ impl<A: Aleo> Plaintext<A> {
         match self {
             // Halts if the value is not a struct.
             Self::Literal(..) => A::halt("Literal is not a struct"),
+            // Halts if the value is not a struct.
+            Self::Array(..) => A::halt("Array is not a struct"),
             // Retrieve the value of the member (from the value).
             Self::Struct(members, ..) => {
                 // Initialize the members starting from the top-level.
@@ -41,7 +43,9 @@ impl<A: Aleo> Plaintext<A> {
                     if i != path.len() - 1 {
                         match submembers.get(identifier) {
                             // Halts if the member is not a struct.
-                            Some(Self::Literal(..)) => bail!("'{identifier}' must be a struct"),
+                            Some(Self::Literal(..)) | Some(Self::Array(..)) => {
+                                bail!("'{identifier}' must be a struct")
+                            }
                             // Retrieve the member and update `submembers` for the next iteration.
                             Some(Self::Struct(members, ..)) => submembers = members,
                             // Halts if the member does not exist.
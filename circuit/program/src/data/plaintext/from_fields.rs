@@ -41,8 +41,10 @@ impl<A: Aleo> FromFields for Plaintext<A> {
         }
 
         // Unpack the field elements into little-endian bits, and reverse the list for popping the terminus bit off.
-        let mut bits_le =
-            fields.iter().flat_map(|field| field.to_bits_le()[..A::BaseField::size_in_data_bits()].to_vec()).rev();
+        let mut bits_le = Field::to_bits_le_many(fields)
+            .into_iter()
+            .flat_map(|bits_le| bits_le[..A::BaseField::size_in_data_bits()].to_vec())
+            .rev();
         // Remove the terminus bit that was added during encoding.
         for boolean in bits_le.by_ref() {
             // Drop all extraneous `0` bits, in addition to the final `1` bit.
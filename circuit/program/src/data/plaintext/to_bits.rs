@@ -45,6 +45,18 @@ impl<A: Aleo> ToBits for Plaintext<A> {
                     bits_le
                 })
                 .clone(),
+            Self::Array(elements, bits_le) => bits_le
+                .get_or_init(|| {
+                    let mut bits_le = vec![Boolean::constant(true), Boolean::constant(false)]; // Variant bit.
+                    bits_le.extend(U32::constant(console::U32::new(elements.len() as u32)).to_bits_le());
+                    for element in elements {
+                        let element_bits = element.to_bits_le();
+                        bits_le.extend(U16::constant(console::U16::new(element_bits.len() as u16)).to_bits_le());
+                        bits_le.extend(element_bits);
+                    }
+                    bits_le
+                })
+                .clone(),
         }
     }
 
@@ -74,6 +86,18 @@ impl<A: Aleo> ToBits for Plaintext<A> {
                     bits_be
                 })
                 .clone(),
+            Self::Array(elements, bits_be) => bits_be
+                .get_or_init(|| {
+                    let mut bits_be = vec![Boolean::constant(true), Boolean::constant(false)]; // Variant bit.
+                    bits_be.extend(U32::constant(console::U32::new(elements.len() as u32)).to_bits_be());
+                    for element in elements {
+                        let element_bits = element.to_bits_be();
+                        bits_be.extend(U16::constant(console::U16::new(element_bits.len() as u16)).to_bits_be());
+                        bits_be.extend(element_bits);
+                    }
+                    bits_be
+                })
+                .clone(),
         }
     }
 }
@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod verify;
+
+use crate::{Entry, Identifier, Plaintext, ProgramID, Record};
+use snarkvm_circuit_collections::merkle_tree::MerklePath;
+use snarkvm_circuit_network::Aleo;
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field};
+
+/// The depth of the Merkle tree for the entries of a record.
+const RECORD_ENTRIES_DEPTH: u8 = console::RECORD_ENTRIES_DEPTH;
+
+type RecordEntriesPath<A> = MerklePath<A, RECORD_ENTRIES_DEPTH>;
+
+/// The entry disclosure proves the membership of a single record entry in the entries root of a record,
+/// without revealing the other entries of the record.
+pub struct EntryDisclosure<A: Aleo> {
+    /// The name of the entry.
+    identifier: Identifier<A>,
+    /// The entry.
+    entry: Entry<A, Plaintext<A>>,
+    /// The salt blinding the Merkle leaf of the entry.
+    leaf_salt: Field<A>,
+    /// The Merkle path for the entry.
+    path: RecordEntriesPath<A>,
+}
+
+impl<A: Aleo> EntryDisclosure<A> {
+    /// Returns the name of the entry.
+    pub const fn identifier(&self) -> &Identifier<A> {
+        &self.identifier
+    }
+
+    /// Returns the entry.
+    pub const fn entry(&self) -> &Entry<A, Plaintext<A>> {
+        &self.entry
+    }
+
+    /// Returns the Merkle leaf for the given entry, as `(leaf_salt || identifier || entry)`.
+    /// The identifier is encoded as a fixed-width field element, so the leaf is unambiguous.
+    pub fn to_leaf(
+        leaf_salt: &Field<A>,
+        identifier: &Identifier<A>,
+        entry: &Entry<A, Plaintext<A>>,
+    ) -> Vec<Boolean<A>> {
+        let mut leaf = leaf_salt.to_bits_le();
+        leaf.extend(identifier.to_field().to_bits_le().into_iter().take(A::BaseField::size_in_data_bits()));
+        leaf.extend(entry.to_bits_le());
+        leaf
+    }
+}
+
+#[cfg(console)]
+impl<A: Aleo> Inject for EntryDisclosure<A> {
+    type Primitive = console::EntryDisclosure<A::Network>;
+
+    /// Initializes a new entry disclosure circuit from a primitive.
+    fn new(mode: Mode, disclosure: Self::Primitive) -> Self {
+        Self {
+            identifier: Identifier::new(mode, *disclosure.identifier()),
+            entry: Entry::new(mode, disclosure.entry().clone()),
+            leaf_salt: Field::new(mode, *disclosure.leaf_salt()),
+            path: RecordEntriesPath::new(mode, disclosure.path().clone()),
+        }
+    }
+}
+
+#[cfg(console)]
+impl<A: Aleo> Eject for EntryDisclosure<A> {
+    type Primitive = console::EntryDisclosure<A::Network>;
+
+    /// Ejects the mode of the entry disclosure.
+    fn eject_mode(&self) -> Mode {
+        Mode::combine(self.identifier.eject_mode(), [
+            self.entry.eject_mode(),
+            self.leaf_salt.eject_mode(),
+            self.path.eject_mode(),
+        ])
+    }
+
+    /// Ejects the entry disclosure.
+    fn eject_value(&self) -> Self::Primitive {
+        Self::Primitive::from(
+            self.identifier.eject_value(),
+            self.entry.eject_value(),
+            self.leaf_salt.eject_value(),
+            self.path.eject_value(),
+        )
+    }
+}
@@ -0,0 +1,167 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> EntryDisclosure<A> {
+    /// Returns `true` if the disclosed entry is in the record with the given entries root.
+    pub fn verify(&self, entries_root: &Field<A>) -> Boolean<A> {
+        // Construct the Merkle leaf as `(leaf_salt || identifier || entry)`.
+        let leaf = Self::to_leaf(&self.leaf_salt, &self.identifier, &self.entry);
+        // Verify the Merkle path to the entries root.
+        A::verify_merkle_path_bhp(&self.path, entries_root, &leaf)
+    }
+
+    /// Returns `true` if the disclosed entry is in the given record, and the record has the given commitment.
+    /// The entries root is computed from the record in-circuit, which binds the disclosure to the record commitment.
+    pub fn verify_with_commitment(
+        &self,
+        record: &Record<A, Plaintext<A>>,
+        program_id: &ProgramID<A>,
+        record_name: &Identifier<A>,
+        salt: &Field<A>,
+        commitment: &Field<A>,
+    ) -> Boolean<A> {
+        // Ensure the record matches the commitment.
+        let is_commitment_valid = commitment.is_equal(&record.to_commitment(program_id, record_name));
+        // Verify the entry against the entries root of the record.
+        is_commitment_valid & self.verify(&record.to_entries_root(salt))
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::rand::{TestRng, Uniform};
+
+    type CurrentNetwork = <Circuit as Environment>::Network;
+
+    fn check_verify(mode: Mode) -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the console record.
+        let record = console::Record::<CurrentNetwork, console::Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add.private, gates: 5u64.private, amount: 100u64.private, _nonce: 0group.public }",
+        )?;
+        // Sample the salt.
+        let salt = console::Field::rand(rng);
+        // Compute the entries root.
+        let entries_root = record.to_entries_root(&salt)?;
+        // Disclose the entry.
+        let disclosure = record.disclose(&salt, &console::Identifier::from_str("amount")?)?;
+        assert!(disclosure.verify(&entries_root));
+
+        Circuit::scope(format!("Verify entry disclosure {mode}"), || {
+            // Inject the entries root.
+            let circuit_entries_root = Field::<Circuit>::new(Mode::Public, entries_root);
+            // Inject the entry disclosure.
+            let circuit_disclosure = EntryDisclosure::<Circuit>::new(mode, disclosure.clone());
+            assert_eq!(disclosure, circuit_disclosure.eject_value());
+
+            // Ensure the entry disclosure is valid.
+            assert!(circuit_disclosure.verify(&circuit_entries_root).eject_value());
+            assert!(Circuit::is_satisfied());
+
+            // Ensure the entry disclosure is invalid for a different root.
+            let circuit_other_root = Field::<Circuit>::new(Mode::Public, console::Field::rand(rng));
+            assert!(!circuit_disclosure.verify(&circuit_other_root).eject_value());
+        });
+        Circuit::reset();
+        Ok(())
+    }
+
+    fn check_verify_with_commitment(mode: Mode) -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the console record.
+        let record = console::Record::<CurrentNetwork, console::Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add.private, gates: 5u64.private, amount: 100u64.private, _nonce: 0group.public }",
+        )?;
+        let program_id = console::ProgramID::from_str("token.aleo")?;
+        let record_name = console::Identifier::from_str("token")?;
+        // Sample the salt.
+        let salt = console::Field::rand(rng);
+        // Compute the record commitment.
+        let commitment = record.to_commitment(&program_id, &record_name)?;
+        // Disclose the entry.
+        let disclosure = record.disclose(&salt, &console::Identifier::from_str("amount")?)?;
+
+        Circuit::scope(format!("Verify entry disclosure with commitment {mode}"), || {
+            // Inject the record, salt, and commitment.
+            let circuit_record = Record::<Circuit, Plaintext<Circuit>>::new(Mode::Private, record.clone());
+            let circuit_program_id = ProgramID::<Circuit>::new(Mode::Constant, program_id);
+            let circuit_record_name = Identifier::<Circuit>::new(Mode::Constant, record_name);
+            let circuit_salt = Field::<Circuit>::new(Mode::Private, salt);
+            let circuit_commitment = Field::<Circuit>::new(Mode::Public, commitment);
+            // Inject the entry disclosure.
+            let circuit_disclosure = EntryDisclosure::<Circuit>::new(mode, disclosure.clone());
+
+            // Ensure the entries root matches the console entries root.
+            let entries_root = circuit_record.to_entries_root(&circuit_salt).eject_value();
+            assert_eq!(record.to_entries_root(&salt).unwrap(), entries_root);
+
+            // Ensure the entry disclosure is valid for the record commitment.
+            let candidate = circuit_disclosure.verify_with_commitment(
+                &circuit_record,
+                &circuit_program_id,
+                &circuit_record_name,
+                &circuit_salt,
+                &circuit_commitment,
+            );
+            assert!(candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+
+            // Ensure the entry disclosure is invalid for a different commitment.
+            let circuit_other_commitment = Field::<Circuit>::new(Mode::Public, console::Field::rand(rng));
+            let candidate = circuit_disclosure.verify_with_commitment(
+                &circuit_record,
+                &circuit_program_id,
+                &circuit_record_name,
+                &circuit_salt,
+                &circuit_other_commitment,
+            );
+            assert!(!candidate.eject_value());
+        });
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_constant() -> Result<()> {
+        check_verify(Mode::Constant)
+    }
+
+    #[test]
+    fn test_verify_public() -> Result<()> {
+        check_verify(Mode::Public)
+    }
+
+    #[test]
+    fn test_verify_private() -> Result<()> {
+        check_verify(Mode::Private)
+    }
+
+    #[test]
+    fn test_verify_with_commitment_constant() -> Result<()> {
+        check_verify_with_commitment(Mode::Constant)
+    }
+
+    #[test]
+    fn test_verify_with_commitment_private() -> Result<()> {
+        check_verify_with_commitment(Mode::Private)
+    }
+}
@@ -17,6 +17,9 @@
 #[cfg(test)]
 use snarkvm_circuit_types::environment::assert_scope;
 
+mod disclosure;
+pub use disclosure::EntryDisclosure;
+
 mod entry;
 pub use entry::Entry;
 
@@ -32,6 +35,7 @@ mod serial_number;
 mod tag;
 mod to_bits;
 mod to_commitment;
+mod to_entries_root;
 mod to_fields;
 
 use crate::{Ciphertext, Identifier, Plaintext, ProgramID, Visibility};
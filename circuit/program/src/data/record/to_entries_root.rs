@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> Record<A, Plaintext<A>> {
+    /// Returns the Merkle root for the entries of the record, given the record owner's secret salt.
+    /// The leaves are the `owner`, the `gates`, and then the data entries, in order,
+    /// where each leaf is blinded by a leaf salt derived from the salt and the index of the entry.
+    pub fn to_entries_root(&self, salt: &Field<A>) -> Field<A> {
+        // Prepare the owner and gates entries.
+        let reserved = match (console::Identifier::from_str("owner"), console::Identifier::from_str("gates")) {
+            (Ok(owner), Ok(gates)) => [
+                (Identifier::constant(owner), self.owner.to_entry()),
+                (Identifier::constant(gates), self.gates.to_entry()),
+            ],
+            _ => A::halt("Failed to initialize the names of the reserved record entries"),
+        };
+
+        // Compute the Merkle leaf hashes.
+        let mut nodes = reserved
+            .into_iter()
+            .chain(self.data.iter().map(|(identifier, entry)| (identifier.clone(), entry.clone())))
+            .enumerate()
+            .map(|(index, (identifier, entry))| {
+                // Derive the leaf salt for the entry.
+                let leaf_salt = A::hash_psd2(&[salt.clone(), Field::constant(console::Field::from_u16(index as u16))]);
+                // Prepend the leaf with a `false` bit.
+                let mut input = vec![Boolean::constant(false)];
+                input.extend(EntryDisclosure::to_leaf(&leaf_salt, &identifier, &entry));
+                // Hash the leaf.
+                A::hash_bhp1024(&input)
+            })
+            .collect::<Vec<_>>();
+
+        // Compute the empty hash.
+        let empty_hash = Self::hash_children(&Field::zero(), &Field::zero());
+        // Pad the leaf hashes with the empty hash, up to the next power of two.
+        nodes.resize(nodes.len().next_power_of_two(), empty_hash.clone());
+
+        // Compute the hashes for each level, iterating from the leaf level to the root level.
+        let mut depth = 0u8;
+        while nodes.len() > 1 {
+            nodes = nodes.chunks(2).map(|children| Self::hash_children(&children[0], &children[1])).collect();
+            depth += 1;
+        }
+
+        // Compute the entries root, by hashing the root hash with the empty hash up to the depth of the tree.
+        let mut root = nodes.remove(0);
+        for _ in depth..console::RECORD_ENTRIES_DEPTH {
+            root = Self::hash_children(&root, &empty_hash);
+        }
+        root
+    }
+
+    /// Returns the hash of the given child nodes in the entries tree.
+    fn hash_children(left: &Field<A>, right: &Field<A>) -> Field<A> {
+        // Prepend the nodes with a `true` bit.
+        let mut input = vec![Boolean::constant(true)];
+        input.extend(left.to_bits_le());
+        input.extend(right.to_bits_le());
+        // Hash the input.
+        A::hash_bhp512(&input)
+    }
+}
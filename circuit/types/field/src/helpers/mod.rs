@@ -20,6 +20,7 @@ pub mod from_bits;
 pub mod from_boolean;
 pub mod one;
 pub mod to_bits;
+pub mod to_bits_many;
 pub mod to_lower_bits;
 pub mod to_upper_bits;
 pub mod zero;
@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Outputs the little-endian bit representations of the given field elements *without* trailing zeros.
+    ///
+    /// The decomposition is shared between elements with the same linear combination,
+    /// and elements that were previously decomposed reuse their bits at no additional cost.
+    pub fn to_bits_le_many(fields: &[Self]) -> Vec<Vec<Boolean<E>>> {
+        let mut bits_le: Vec<Vec<Boolean<E>>> = Vec::with_capacity(fields.len());
+        for (index, field) in fields.iter().enumerate() {
+            let bits = match field.bits_le.get() {
+                // Reuse the bits of an element that was previously decomposed.
+                Some(bits) => bits.clone(),
+                None => {
+                    match fields[..index].iter().position(|other| other.linear_combination == field.linear_combination)
+                    {
+                        // Reuse the bits of a duplicate element in the batch, and cache them for the element.
+                        Some(position) => {
+                            let bits = bits_le[position].clone();
+                            let _ = field.bits_le.set(bits.clone());
+                            bits
+                        }
+                        // Otherwise, decompose the element.
+                        None => field.to_bits_le(),
+                    }
+                }
+            };
+            bits_le.push(bits);
+        }
+        bits_le
+    }
+
+    /// Outputs the big-endian bit representations of the given field elements *without* leading zeros.
+    pub fn to_bits_be_many(fields: &[Self]) -> Vec<Vec<Boolean<E>>> {
+        let mut bits_be = Self::to_bits_le_many(fields);
+        bits_be.iter_mut().for_each(|bits| bits.reverse());
+        bits_be
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_to_bits_many(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample random elements, along with a duplicate of the first element.
+            let expected: Vec<console::Field<<Circuit as Environment>::Network>> =
+                (0..4).map(|_| Uniform::rand(&mut rng)).collect();
+            let mut candidates: Vec<Field<Circuit>> = expected.iter().map(|value| Field::new(mode, *value)).collect();
+            candidates.push(candidates[0].clone());
+
+            Circuit::scope(&format!("{mode} {i}"), || {
+                let candidate_bits_le = Field::to_bits_le_many(&candidates);
+                assert_eq!(candidates.len(), candidate_bits_le.len());
+                for (expected, candidate_bits) in expected.iter().chain([&expected[0]]).zip_eq(&candidate_bits_le) {
+                    assert_eq!(expected.to_bits_le(), candidate_bits.eject_value());
+                }
+
+                // Ensure the duplicate element does not incur additional costs.
+                match mode.is_constant() {
+                    true => assert_eq!(0, Circuit::num_constraints_in_scope()),
+                    false => assert_eq!(4 * 254, Circuit::num_constraints_in_scope()),
+                }
+
+                // Ensure a second call reuses the bits, without incurring additional costs.
+                let candidate_bits_be = Field::to_bits_be_many(&candidates);
+                for (expected, candidate_bits) in expected.iter().chain([&expected[0]]).zip_eq(&candidate_bits_be) {
+                    assert_eq!(expected.to_bits_be(), candidate_bits.eject_value());
+                }
+                match mode.is_constant() {
+                    true => assert_eq!(0, Circuit::num_constraints_in_scope()),
+                    false => assert_eq!(4 * 254, Circuit::num_constraints_in_scope()),
+                }
+            });
+        }
+    }
+
+    #[test]
+    fn test_to_bits_many_constant() {
+        check_to_bits_many(Mode::Constant);
+    }
+
+    #[test]
+    fn test_to_bits_many_public() {
+        check_to_bits_many(Mode::Public);
+    }
+
+    #[test]
+    fn test_to_bits_many_private() {
+        check_to_bits_many(Mode::Private);
+    }
+}
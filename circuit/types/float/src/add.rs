@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Add<F64<E>> for F64<E> {
+    type Output = Self;
+
+    /// Returns the `sum` of `self` and `other`.
+    fn add(self, other: F64<E>) -> Self::Output {
+        &self + &other
+    }
+}
+
+impl<E: Environment> Add<&F64<E>> for F64<E> {
+    type Output = Self;
+
+    /// Returns the `sum` of `self` and `other`.
+    fn add(self, other: &F64<E>) -> Self::Output {
+        &self + other
+    }
+}
+
+impl<E: Environment> Add<&F64<E>> for &F64<E> {
+    type Output = F64<E>;
+
+    /// Returns the `sum` of `self` and `other`.
+    fn add(self, other: &F64<E>) -> Self::Output {
+        // Order the operands by magnitude, such that `|x| >= |y|`.
+        let is_swap = self.magnitude().is_less_than(&other.magnitude());
+        let x = F64::ternary(&is_swap, other, self);
+        let y = F64::ternary(&is_swap, self, other);
+        let (x_exponent, x_significand) = x.unpack();
+        let (y_exponent, y_significand) = y.unpack();
+
+        // Extend the significands with the guard, round, and sticky bits.
+        let extend = |significand: U64<E>| {
+            let mut bits_le = vec![Boolean::constant(false); 3];
+            bits_le.extend_from_slice(&significand.to_bits_le()[..FRACTION_BITS + 1]);
+            U64::from_bits_le(&bits_le)
+        };
+        let x_significand = extend(x_significand);
+        let y_significand = extend(y_significand);
+
+        // Compute the shift to align `y` to `x`, which is clamped to `63`, as `y` is entirely shifted out beyond `56`.
+        let max_shift = F64::integer(63);
+        let shift = x_exponent.sub_wrapped(&y_exponent);
+        let shift = U64::ternary(&shift.is_greater_than(&max_shift), &max_shift, &shift);
+        let shift = U8::from_bits_le(&shift.to_bits_le()[..6]);
+
+        // Align `y` to `x`, folding any shifted out bits into the sticky bit.
+        let y_aligned = y_significand.shr_wrapped(&shift);
+        let is_sticky = y_aligned.shl_wrapped(&shift).is_not_equal(&y_significand);
+        let mut y_aligned = y_aligned.to_bits_le();
+        y_aligned[0] = &y_aligned[0] | &is_sticky;
+        let y_aligned = U64::from_bits_le(&y_aligned);
+
+        // Add or subtract the significands, depending on the signs.
+        // Note that the subtraction does not underflow, as `|x| >= |y|`.
+        let is_subtraction = x.sign() ^ y.sign();
+        let result = U64::ternary(
+            &is_subtraction,
+            &x_significand.sub_wrapped(&y_aligned),
+            &x_significand.add_wrapped(&y_aligned),
+        );
+        let is_zero = result.is_equal(&F64::integer(0));
+
+        // If the result carries into the 57th bit, shift it right by one, folding the shifted out bit into the sticky bit.
+        let bits_le = result.to_bits_le();
+        let is_carry = bits_le[56].clone();
+        let mut shifted = bits_le[1..].to_vec();
+        shifted[0] = &shifted[0] | &bits_le[0];
+        let result = U64::ternary(&is_carry, &U64::from_bits_le(&shifted), &result);
+        let exponent = x_exponent.add_wrapped(&U64::from_bits_le(&[is_carry]));
+
+        // Normalize the result, and round it to the nearest float.
+        let (result, shift) = F64::normalize(&result, &exponent, 55);
+        let sum = F64::round_and_pack(x.sign(), &exponent.sub_wrapped(&shift), &result);
+
+        // If the result is exactly zero, it is negative only if both operands are negative.
+        let zero = F64::from_sign_and_magnitude(&(self.sign() & other.sign()), &F64::integer(0));
+        let sum = F64::ternary(&is_zero, &zero, &sum);
+
+        // If an operand is infinite, return the infinity.
+        let sum = F64::ternary(&self.is_infinite(), self, &sum);
+        let sum = F64::ternary(&other.is_infinite(), other, &sum);
+
+        // If an operand is NaN, or the operands are infinities of opposite signs, return NaN.
+        let is_nan = self.is_nan() | other.is_nan() | (self.is_infinite() & other.is_infinite() & is_subtraction);
+        F64::ternary(&is_nan, &F64::nan(), &sum)
+    }
+}
+
+impl<E: Environment> AddAssign<F64<E>> for F64<E> {
+    /// Adds `other` to `self`.
+    fn add_assign(&mut self, other: F64<E>) {
+        *self = &*self + &other;
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use crate::helpers::tests::check_binary;
+
+    #[test]
+    fn test_add() {
+        check_binary("Add", |a, b| a + b, |a, b| a + b);
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> F64<E> {
+    /// Returns a key that orders the non-NaN floats as integers, where `-0` and `+0` have the same key.
+    fn to_ordered_key(&self) -> U64<E> {
+        // Negative floats are ordered by the complement of their magnitude, below the positive floats.
+        let is_negative = self.sign() & !self.is_zero();
+        let mut bits_le: Vec<_> = self.bits_le[..63].iter().map(|bit| bit ^ &is_negative).collect();
+        bits_le.push(!is_negative);
+        U64::from_bits_le(&bits_le)
+    }
+}
+
+impl<E: Environment> Compare<Self> for F64<E> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` is less than `other`.
+    fn is_less_than(&self, other: &Self) -> Self::Output {
+        self.to_ordered_key().is_less_than(&other.to_ordered_key()) & !self.is_nan() & !other.is_nan()
+    }
+
+    /// Returns `true` if `self` is greater than `other`.
+    fn is_greater_than(&self, other: &Self) -> Self::Output {
+        other.is_less_than(self)
+    }
+
+    /// Returns `true` if `self` is less than or equal to `other`.
+    fn is_less_than_or_equal(&self, other: &Self) -> Self::Output {
+        self.to_ordered_key().is_less_than_or_equal(&other.to_ordered_key()) & !self.is_nan() & !other.is_nan()
+    }
+
+    /// Returns `true` if `self` is greater than or equal to `other`.
+    fn is_greater_than_or_equal(&self, other: &Self) -> Self::Output {
+        other.is_less_than_or_equal(self)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::helpers::tests::check_compare;
+
+    #[test]
+    fn test_is_less_than() {
+        check_compare("LessThan", |a, b| *a.is_less_than(&b), |a, b| a.is_less_than(b));
+    }
+
+    #[test]
+    fn test_is_greater_than() {
+        check_compare("GreaterThan", |a, b| *a.is_greater_than(&b), |a, b| a.is_greater_than(b));
+    }
+
+    #[test]
+    fn test_is_less_than_or_equal() {
+        check_compare("LessThanOrEqual", |a, b| *a.is_less_than_or_equal(&b), |a, b| a.is_less_than_or_equal(b));
+    }
+
+    #[test]
+    fn test_is_greater_than_or_equal() {
+        check_compare(
+            "GreaterThanOrEqual",
+            |a, b| *a.is_greater_than_or_equal(&b),
+            |a, b| a.is_greater_than_or_equal(b),
+        );
+    }
+}
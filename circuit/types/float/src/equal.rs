@@ -0,0 +1,54 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Equal<Self> for F64<E> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` and `other` are equal, where NaN is not equal to any value, and `-0 == +0`.
+    fn is_equal(&self, other: &Self) -> Self::Output {
+        // Determine if the encodings are equal.
+        let is_identical = self
+            .bits_le
+            .iter()
+            .zip_eq(&other.bits_le)
+            .fold(Boolean::constant(true), |is_identical, (a, b)| is_identical & a.is_equal(b));
+        // The floats are equal if the encodings are equal, or both are zero, and neither is NaN.
+        (is_identical | (self.is_zero() & other.is_zero())) & !self.is_nan() & !other.is_nan()
+    }
+
+    /// Returns `true` if `self` and `other` are *not* equal, where NaN is not equal to any value, and `-0 == +0`.
+    fn is_not_equal(&self, other: &Self) -> Self::Output {
+        !self.is_equal(other)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::helpers::tests::check_compare;
+
+    #[test]
+    fn test_is_equal() {
+        check_compare("Equal", |a, b| *a.is_equal(&b), |a, b| a.is_equal(b));
+    }
+
+    #[test]
+    fn test_is_not_equal() {
+        check_compare("NotEqual", |a, b| *a.is_not_equal(&b), |a, b| a.is_not_equal(b));
+    }
+}
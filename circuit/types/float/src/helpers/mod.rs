@@ -0,0 +1,267 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The number of bits in the fraction of the encoding.
+pub(crate) const FRACTION_BITS: usize = 52;
+/// The encoding of positive infinity, which is also the smallest magnitude with the maximum exponent.
+pub(crate) const INFINITY: u64 = 0x7FF0_0000_0000_0000;
+/// The largest exponent of a finite float.
+pub(crate) const MAX_EXPONENT: u64 = 2046;
+
+impl<E: Environment> F64<E> {
+    /// Returns the canonical quiet NaN.
+    pub(crate) fn nan() -> Self {
+        Self::from_sign_and_magnitude(&Boolean::constant(false), &Self::integer(0x7FF8_0000_0000_0000))
+    }
+
+    /// Returns a float with the given sign and magnitude, which must be less than `2^63`.
+    pub(crate) fn from_sign_and_magnitude(sign: &Boolean<E>, magnitude: &U64<E>) -> Self {
+        let mut bits_le = magnitude.to_bits_le();
+        bits_le[63] = sign.clone();
+        Self { bits_le }
+    }
+
+    /// Returns `self` with the sign bit flipped.
+    pub(crate) fn flip_sign(&self) -> Self {
+        let mut bits_le = self.bits_le.clone();
+        bits_le[63] = !&bits_le[63];
+        Self { bits_le }
+    }
+
+    /// Returns a constant integer with the given value.
+    pub(crate) fn integer(value: u64) -> U64<E> {
+        U64::constant(console::Integer::new(value))
+    }
+
+    /// Returns the sign bit.
+    pub(crate) fn sign(&self) -> &Boolean<E> {
+        &self.bits_le[63]
+    }
+
+    /// Returns the magnitude, which is the encoding without the sign bit.
+    pub(crate) fn magnitude(&self) -> U64<E> {
+        U64::from_bits_le(&self.bits_le[..63])
+    }
+
+    /// Returns `true` if the exponent bits are all set.
+    fn is_exponent_max(&self) -> Boolean<E> {
+        self.bits_le[FRACTION_BITS..63].iter().fold(Boolean::constant(true), |is_max, bit| is_max & bit)
+    }
+
+    /// Returns `true` if the fraction bits are all unset.
+    fn is_fraction_zero(&self) -> Boolean<E> {
+        !self.bits_le[..FRACTION_BITS].iter().fold(Boolean::constant(false), |is_set, bit| is_set | bit)
+    }
+
+    /// Returns `true` if `self` is NaN.
+    pub fn is_nan(&self) -> Boolean<E> {
+        self.is_exponent_max() & !self.is_fraction_zero()
+    }
+
+    /// Returns `true` if `self` is positive or negative infinity.
+    pub fn is_infinite(&self) -> Boolean<E> {
+        self.is_exponent_max() & self.is_fraction_zero()
+    }
+
+    /// Returns `true` if `self` is positive or negative zero.
+    pub fn is_zero(&self) -> Boolean<E> {
+        !self.bits_le[..63].iter().fold(Boolean::constant(false), |is_set, bit| is_set | bit)
+    }
+
+    /// Returns the exponent and significand of `self`, such that the magnitude of a finite float
+    /// is `significand * 2^(exponent - 1075)`.
+    ///
+    /// The exponent is the biased exponent, or `1` for subnormals, and the significand
+    /// includes the implicit leading bit, which is set for normal floats.
+    pub(crate) fn unpack(&self) -> (U64<E>, U64<E>) {
+        // Determine if the float is normal, in which case the exponent is nonzero.
+        let is_normal =
+            self.bits_le[FRACTION_BITS..63].iter().fold(Boolean::constant(false), |is_set, bit| is_set | bit);
+        // Compute the exponent, which is `1` for subnormals.
+        let exponent =
+            U64::from_bits_le(&self.bits_le[FRACTION_BITS..63]).add_wrapped(&U64::from_bits_le(&[!&is_normal]));
+        // Compute the significand, by prepending the implicit leading bit to the fraction.
+        let mut significand = self.bits_le[..FRACTION_BITS].to_vec();
+        significand.push(is_normal);
+        (exponent, U64::from_bits_le(&significand))
+    }
+
+    /// Returns the given significand shifted left by the witnessed `shift`, such that it is normalized
+    /// to have its leading bit at `position`, without lowering the exponent below `1`.
+    /// The significand must be less than `2^(position + 1)`.
+    ///
+    /// Returns the normalized significand and the shift.
+    pub(crate) fn normalize(significand: &U64<E>, exponent: &U64<E>, position: u32) -> (U64<E>, U64<E>) {
+        // Compute the shift, which is the number of leading zeros, up to the exponent minus one.
+        let shift: U8<E> = witness!(|significand, exponent| {
+            let leading_zeros = match *significand == 0 {
+                true => 0,
+                false => position.saturating_sub(63 - significand.leading_zeros()) as u64,
+            };
+            console::Integer::new(leading_zeros.min(exponent.saturating_sub(1)).min(63) as u8)
+        });
+        // Restrict the shift to the range of the shift operations.
+        let shift = U8::from_bits_le(&shift.to_bits_le()[..6]);
+
+        // Shift the significand.
+        let normalized = significand.shl_wrapped(&shift);
+
+        // Ensure the shift does not discard any bits.
+        E::assert(normalized.is_less_than(&Self::integer(1 << (position + 1))));
+        E::assert(normalized.shr_wrapped(&shift).is_equal(significand));
+        // Ensure the shift does not lower the exponent below `1`.
+        let shift = U64::from_bits_le(&shift.to_bits_le());
+        E::assert(shift.is_less_than(exponent));
+        // Ensure the significand is normalized, unless the exponent is lowered to `1`, or the significand is zero.
+        let is_normalized = normalized.to_bits_le()[position as usize].clone();
+        let is_minimum = exponent.sub_wrapped(&shift).is_equal(&Self::integer(1));
+        let is_zero = significand.is_equal(&Self::integer(0));
+        E::assert(is_normalized | is_minimum | is_zero);
+
+        (normalized, shift)
+    }
+
+    /// Returns the float with the given sign, whose magnitude is `significand * 2^(exponent - 1078)`
+    /// rounded to nearest, ties to even.
+    ///
+    /// The significand must be less than `2^56`, where the lowest three bits are the guard, round, and sticky bits,
+    /// and must be normalized to at least `2^55`, unless the exponent is `1`. The exponent must be at least `1`.
+    pub(crate) fn round_and_pack(sign: &Boolean<E>, exponent: &U64<E>, significand: &U64<E>) -> Self {
+        // Clamp the exponent, tracking if it overflows.
+        let max_exponent = Self::integer(MAX_EXPONENT);
+        let is_overflow = exponent.is_greater_than(&max_exponent);
+        let exponent = U64::ternary(&is_overflow, &max_exponent, exponent);
+
+        // Round up if the round bit is set, and either the sticky bits or the lowest bit of the mantissa is set.
+        let bits_le = significand.to_bits_le();
+        let is_round_up = &bits_le[2] & (&bits_le[0] | &bits_le[1] | &bits_le[3]);
+        let mantissa = U64::from_bits_le(&bits_le[3..]).add_wrapped(&U64::from_bits_le(&[is_round_up]));
+
+        // Pack the exponent and mantissa, by adding the mantissa to `(exponent - 1) * 2^52`.
+        // The leading bit of the mantissa carries into the exponent, which accounts for normal floats,
+        // subnormal floats that round up to normal, and mantissas that round up to the next exponent.
+        let mut encoding = vec![Boolean::constant(false); FRACTION_BITS];
+        encoding.extend_from_slice(&exponent.sub_wrapped(&Self::integer(1)).to_bits_le()[..11]);
+        let encoding = U64::from_bits_le(&encoding).add_wrapped(&mantissa);
+
+        // If the exponent overflows, or the encoding rounds up to the maximum exponent, return infinity.
+        let infinity = Self::integer(INFINITY);
+        let is_overflow = is_overflow | encoding.is_greater_than_or_equal(&infinity);
+        Self::from_sign_and_magnitude(sign, &U64::ternary(&is_overflow, &infinity, &encoding))
+    }
+}
+
+#[cfg(all(test, console))]
+pub(crate) mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    pub(crate) type CurrentF64 = console::F64<<Circuit as Environment>::Network>;
+
+    const ITERATIONS: u64 = 100;
+
+    /// Returns a list of floats that exercise the edge cases of IEEE-754.
+    pub(crate) fn special_values() -> Vec<CurrentF64> {
+        [
+            0x0000_0000_0000_0000, // 0
+            0x0000_0000_0000_0001, // The smallest subnormal.
+            0x000F_FFFF_FFFF_FFFF, // The largest subnormal.
+            0x0010_0000_0000_0000, // The smallest normal.
+            0x3FF0_0000_0000_0000, // 1
+            0x3FF0_0000_0000_0001, // The successor of 1.
+            0x3FB9_9999_9999_999A, // 0.1
+            0x4008_0000_0000_0000, // 3
+            0x7FEF_FFFF_FFFF_FFFF, // The largest finite.
+            0x7FF0_0000_0000_0000, // Infinity
+            0x7FF8_0000_0000_0000, // NaN
+            0x7FF0_0000_0000_0001, // A signaling NaN.
+        ]
+        .into_iter()
+        .flat_map(|bits| [CurrentF64::from_bits(bits), CurrentF64::from_bits(bits | 0x8000_0000_0000_0000)])
+        .collect()
+    }
+
+    /// Returns pairs of floats, comprising the special values, random floats, and nearby floats.
+    fn sample_pairs() -> Vec<(CurrentF64, CurrentF64)> {
+        let mut rng = TestRng::default();
+
+        let mut pairs = Vec::new();
+        // Add the pairs of special values.
+        for a in special_values() {
+            for b in special_values() {
+                pairs.push((a, b));
+            }
+        }
+        for _ in 0..ITERATIONS {
+            // Add a pair of random floats.
+            let a: CurrentF64 = Uniform::rand(&mut rng);
+            pairs.push((a, Uniform::rand(&mut rng)));
+            // Add a pair of floats with nearby magnitudes and opposite signs, to exercise cancellation.
+            let delta = u64::rand(&mut rng) >> (u8::rand(&mut rng) % 64);
+            pairs.push((a, CurrentF64::from_bits((a.to_bits() ^ delta) ^ 0x8000_0000_0000_0000)));
+            // Add a pair of floats with small exponents, to exercise subnormals.
+            let b = CurrentF64::from_bits(u64::rand(&mut rng) >> 10);
+            pairs.push((b, CurrentF64::from_bits(u64::rand(&mut rng) >> 2)));
+        }
+        pairs
+    }
+
+    /// Checks the given binary operation against the console implementation.
+    pub(crate) fn check_binary(
+        name: &str,
+        expected: impl Fn(CurrentF64, CurrentF64) -> CurrentF64,
+        candidate: impl Fn(&F64<Circuit>, &F64<Circuit>) -> F64<Circuit>,
+    ) {
+        for (i, (a, b)) in sample_pairs().into_iter().enumerate() {
+            for (mode_a, mode_b) in
+                [(Mode::Constant, Mode::Constant), (Mode::Constant, Mode::Private), (Mode::Private, Mode::Private)]
+            {
+                let first = F64::<Circuit>::new(mode_a, a);
+                let second = F64::<Circuit>::new(mode_b, b);
+
+                Circuit::scope(format!("{name} {mode_a} {mode_b} {i}"), || {
+                    let result = candidate(&first, &second);
+                    assert_eq!(expected(a, b), result.eject_value(), "{name} failed on {a} and {b}");
+                    assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    /// Checks the given comparison against the console implementation.
+    pub(crate) fn check_compare(
+        name: &str,
+        expected: impl Fn(CurrentF64, CurrentF64) -> bool,
+        candidate: impl Fn(&F64<Circuit>, &F64<Circuit>) -> Boolean<Circuit>,
+    ) {
+        for (i, (a, b)) in sample_pairs().into_iter().enumerate() {
+            for (a, b) in [(a, b), (b, a), (a, a)] {
+                let first = F64::<Circuit>::new(Mode::Private, a);
+                let second = F64::<Circuit>::new(Mode::Private, b);
+
+                Circuit::scope(format!("{name} {i}"), || {
+                    let result = candidate(&first, &second);
+                    assert_eq!(expected(a, b), result.eject_value(), "{name} failed on {a} and {b}");
+                    assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+                });
+                Circuit::reset();
+            }
+        }
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#![forbid(unsafe_code)]
+#![allow(clippy::too_many_arguments)]
+#![cfg_attr(test, allow(clippy::assertions_on_result_states))]
+
+mod helpers;
+use helpers::*;
+
+pub mod add;
+pub mod compare;
+pub mod equal;
+pub mod mul;
+pub mod neg;
+pub mod sub;
+pub mod ternary;
+
+#[cfg(test)]
+use snarkvm_utilities::{TestRng, Uniform};
+
+use snarkvm_circuit_environment::prelude::*;
+use snarkvm_circuit_types_boolean::Boolean;
+use snarkvm_circuit_types_integers::{U128, U64, U8};
+
+/// An IEEE-754 binary64 floating-point number.
+///
+/// Arithmetic follows IEEE-754 with rounding to nearest, ties to even, except that every NaN
+/// produced by an operation is the canonical quiet NaN, matching the console implementation.
+#[derive(Clone)]
+pub struct F64<E: Environment> {
+    /// The little-endian bits of the IEEE-754 encoding.
+    bits_le: Vec<Boolean<E>>,
+}
+
+#[cfg(console)]
+impl<E: Environment> Inject for F64<E> {
+    type Primitive = console::F64<E::Network>;
+
+    /// Initializes a new float from its IEEE-754 encoding.
+    fn new(mode: Mode, value: Self::Primitive) -> Self {
+        Self { bits_le: Inject::new(mode, value.to_bits_le()) }
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> Eject for F64<E> {
+    type Primitive = console::F64<E::Network>;
+
+    /// Ejects the mode of the float.
+    fn eject_mode(&self) -> Mode {
+        self.bits_le.eject_mode()
+    }
+
+    /// Ejects the float circuit as a console float value.
+    fn eject_value(&self) -> Self::Primitive {
+        console::F64::from_bits(
+            self.bits_le.iter().rev().fold(0u64, |bits, bit| (bits << 1) | bit.eject_value() as u64),
+        )
+    }
+}
+
+impl<E: Environment> ToBits for F64<E> {
+    type Boolean = Boolean<E>;
+
+    /// Outputs the little-endian bit representation of the IEEE-754 encoding of `self`.
+    fn to_bits_le(&self) -> Vec<Self::Boolean> {
+        self.bits_le.clone()
+    }
+
+    /// Outputs the big-endian bit representation of the IEEE-754 encoding of `self`.
+    fn to_bits_be(&self) -> Vec<Self::Boolean> {
+        let mut bits_be = self.to_bits_le();
+        bits_be.reverse();
+        bits_be
+    }
+}
+
+impl<E: Environment> FromBits for F64<E> {
+    type Boolean = Boolean<E>;
+
+    /// Initializes a new float from the little-endian bits of its IEEE-754 encoding.
+    fn from_bits_le(bits_le: &[Self::Boolean]) -> Self {
+        match bits_le.len() == 64 {
+            true => Self { bits_le: bits_le.to_vec() },
+            false => E::halt(format!("F64::from_bits_le expects a list of 64 booleans, found {}", bits_le.len())),
+        }
+    }
+
+    /// Initializes a new float from the big-endian bits of its IEEE-754 encoding.
+    fn from_bits_be(bits_be: &[Self::Boolean]) -> Self {
+        let mut bits_le = bits_be.to_vec();
+        bits_le.reverse();
+        Self::from_bits_le(&bits_le)
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> TypeName for F64<E> {
+    /// Returns the type name of the circuit as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        console::F64::<E::Network>::type_name()
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> Debug for F64<E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> Display for F64<E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.eject_value(), self.eject_mode())
+    }
+}
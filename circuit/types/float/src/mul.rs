@@ -0,0 +1,129 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Mul<F64<E>> for F64<E> {
+    type Output = Self;
+
+    /// Returns the `product` of `self` and `other`.
+    fn mul(self, other: F64<E>) -> Self::Output {
+        &self * &other
+    }
+}
+
+impl<E: Environment> Mul<&F64<E>> for F64<E> {
+    type Output = Self;
+
+    /// Returns the `product` of `self` and `other`.
+    fn mul(self, other: &F64<E>) -> Self::Output {
+        &self * other
+    }
+}
+
+impl<E: Environment> Mul<&F64<E>> for &F64<E> {
+    type Output = F64<E>;
+
+    /// Returns the `product` of `self` and `other`.
+    fn mul(self, other: &F64<E>) -> Self::Output {
+        // The sign of the product is the exclusive-or of the signs.
+        let sign = self.sign() ^ other.sign();
+
+        // Normalize the significands to 53 bits, tracking the shifts in the exponents.
+        // Note that the exponent `64` permits any shift, as each significand has at most 53 bits.
+        let (a_exponent, a_significand) = self.unpack();
+        let (b_exponent, b_significand) = other.unpack();
+        let (a_significand, a_shift) = F64::normalize(&a_significand, &F64::integer(64), FRACTION_BITS as u32);
+        let (b_significand, b_shift) = F64::normalize(&b_significand, &F64::integer(64), FRACTION_BITS as u32);
+
+        // Compute the product of the significands, which is in `[2^104, 2^106)` for nonzero operands.
+        let product = U128::from_bits_le(&a_significand.to_bits_le())
+            .mul_wrapped(&U128::from_bits_le(&b_significand.to_bits_le()));
+        let bits_le = product.to_bits_le();
+        let is_carry = bits_le[105].clone();
+
+        // The magnitude of the product is `product * 2^(a_exponent - a_shift + b_exponent - b_shift - 2150)`.
+        // Compute the exponent of the result as `a_exponent + b_exponent - a_shift - b_shift - 1023 + carry`,
+        // offset by `104`, which is at least `2` for nonzero operands, as each shift is at most `52`.
+        let exponent = a_exponent
+            .add_wrapped(&b_exponent)
+            .add_wrapped(&F64::integer(104))
+            .sub_wrapped(&a_shift)
+            .sub_wrapped(&b_shift)
+            .add_wrapped(&U64::from_bits_le(core::slice::from_ref(&is_carry)));
+        let offset = F64::integer(1023 + 104);
+
+        // If the exponent is below `1`, the result is subnormal, and is shifted right by `1 - exponent`,
+        // which is clamped to `77`, as the product is entirely shifted out beyond `57`.
+        let is_subnormal = exponent.is_less_than_or_equal(&offset);
+        let max_subnormal_shift = F64::integer(77);
+        let subnormal_shift = offset.add_wrapped(&F64::integer(1)).sub_wrapped(&exponent);
+        let subnormal_shift = U64::ternary(
+            &is_subnormal,
+            &U64::ternary(
+                &subnormal_shift.is_greater_than(&max_subnormal_shift),
+                &max_subnormal_shift,
+                &subnormal_shift,
+            ),
+            &F64::integer(0),
+        );
+        let exponent = U64::ternary(&is_subnormal, &F64::integer(1), &exponent.sub_wrapped(&offset));
+
+        // Shift the product right to 56 bits, which is by `49 + carry`, plus the subnormal shift.
+        // The shift is at most `127`, which shifts out the entire product.
+        let shift = F64::integer(49).add_wrapped(&U64::from_bits_le(&[is_carry])).add_wrapped(&subnormal_shift);
+        let shift = U8::from_bits_le(&shift.to_bits_le()[..7]);
+        let result = product.shr_wrapped(&shift);
+        // Fold any shifted out bits into the sticky bit.
+        let is_sticky = result.shl_wrapped(&shift).is_not_equal(&product);
+        let mut result = result.to_bits_le();
+        result[0] = &result[0] | &is_sticky;
+        let result = U64::from_bits_le(&result[..64]);
+
+        // Round the result to the nearest float.
+        let product = F64::round_and_pack(&sign, &exponent, &result);
+
+        // If an operand is zero, return zero.
+        let is_zero = self.is_zero() | other.is_zero();
+        let product = F64::ternary(&is_zero, &F64::from_sign_and_magnitude(&sign, &F64::integer(0)), &product);
+
+        // If an operand is infinite, return infinity.
+        let is_infinite = self.is_infinite() | other.is_infinite();
+        let product =
+            F64::ternary(&is_infinite, &F64::from_sign_and_magnitude(&sign, &F64::integer(INFINITY)), &product);
+
+        // If an operand is NaN, or an infinity is multiplied by zero, return NaN.
+        let is_nan = self.is_nan() | other.is_nan() | (is_infinite & is_zero);
+        F64::ternary(&is_nan, &F64::nan(), &product)
+    }
+}
+
+impl<E: Environment> MulAssign<F64<E>> for F64<E> {
+    /// Multiplies `self` by `other`.
+    fn mul_assign(&mut self, other: F64<E>) {
+        *self = &*self * &other;
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use crate::helpers::tests::check_binary;
+
+    #[test]
+    fn test_mul() {
+        check_binary("Mul", |a, b| a * b, |a, b| a * b);
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Neg for F64<E> {
+    type Output = Self;
+
+    /// Returns the `negation` of `self`, by flipping the sign bit.
+    /// If `self` is NaN, returns the canonical quiet NaN.
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+impl<E: Environment> Neg for &F64<E> {
+    type Output = F64<E>;
+
+    /// Returns the `negation` of `self`, by flipping the sign bit.
+    /// If `self` is NaN, returns the canonical quiet NaN.
+    fn neg(self) -> Self::Output {
+        F64::ternary(&self.is_nan(), &F64::nan(), &self.flip_sign())
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::helpers::tests::{special_values, CurrentF64};
+    use snarkvm_circuit_environment::Circuit;
+
+    #[test]
+    fn test_neg() {
+        for (i, value) in special_values().into_iter().enumerate() {
+            let candidate = F64::<Circuit>::new(Mode::Private, value);
+            Circuit::scope(format!("Neg {i}"), || {
+                assert_eq!(-value, (-candidate).eject_value());
+            });
+        }
+    }
+
+    #[test]
+    fn test_neg_nan() {
+        // Ensure the negation of any NaN is the canonical quiet NaN.
+        for bits in [0x7FF8_0000_0000_0000, 0xFFF8_0000_0000_0000, 0x7FF0_0000_0000_0001, 0xFFF0_0000_0000_0001] {
+            let candidate = F64::<Circuit>::new(Mode::Private, CurrentF64::from_bits(bits));
+            assert_eq!(CurrentF64::CANONICAL_NAN, (-candidate).eject_value().to_bits());
+        }
+    }
+}
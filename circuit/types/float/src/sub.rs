@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Sub<F64<E>> for F64<E> {
+    type Output = Self;
+
+    /// Returns the `difference` of `self` and `other`.
+    fn sub(self, other: F64<E>) -> Self::Output {
+        &self - &other
+    }
+}
+
+impl<E: Environment> Sub<&F64<E>> for F64<E> {
+    type Output = Self;
+
+    /// Returns the `difference` of `self` and `other`.
+    fn sub(self, other: &F64<E>) -> Self::Output {
+        &self - other
+    }
+}
+
+impl<E: Environment> Sub<&F64<E>> for &F64<E> {
+    type Output = F64<E>;
+
+    /// Returns the `difference` of `self` and `other`, which is exactly `self + (-other)`.
+    fn sub(self, other: &F64<E>) -> Self::Output {
+        self + &other.flip_sign()
+    }
+}
+
+impl<E: Environment> SubAssign<F64<E>> for F64<E> {
+    /// Subtracts `other` from `self`.
+    fn sub_assign(&mut self, other: F64<E>) {
+        *self = &*self - &other;
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use crate::helpers::tests::check_binary;
+
+    #[test]
+    fn test_sub() {
+        check_binary("Sub", |a, b| a - b, |a, b| a - b);
+    }
+}
@@ -46,6 +46,7 @@ pub mod shr_wrapped;
 pub mod sub_checked;
 pub mod sub_wrapped;
 pub mod ternary;
+pub mod u256;
 pub mod xor;
 
 pub type I8<E> = Integer<E, i8>;
@@ -60,6 +61,8 @@ pub type U32<E> = Integer<E, u32>;
 pub type U64<E> = Integer<E, u64>;
 pub type U128<E> = Integer<E, u128>;
 
+pub use u256::U256;
+
 #[cfg(test)]
 use snarkvm_circuit_environment::{
     assert_count,
@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> U256<E> {
+    /// Returns the sum of `self`, `other`, and `carry` modulo `2^256`, and the carry out of the most significant limb.
+    pub(super) fn add_with_carry(&self, other: &Self, carry: &Boolean<E>) -> (Self, Boolean<E>) {
+        let mut bits_le = Vec::with_capacity(Self::size_in_bits() as usize);
+        let mut carry = carry.clone();
+        for (a, b) in self.limbs().iter().zip_eq(other.limbs().iter()) {
+            // Compute the sum of the limbs and the carry in, which fits in `LIMB_BITS + 1` bits.
+            let sum = a + b + Field::from_boolean(&carry);
+            let sum_bits = sum.to_lower_bits_le(LIMB_BITS + 1);
+            // Split the sum into the output limb and the carry out.
+            bits_le.extend_from_slice(&sum_bits[..LIMB_BITS]);
+            carry = sum_bits[LIMB_BITS].clone();
+        }
+        (Self { bits_le }, carry)
+    }
+}
+
+impl<E: Environment> Add<U256<E>> for U256<E> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        self + &other
+    }
+}
+
+impl<E: Environment> Add<U256<E>> for &U256<E> {
+    type Output = U256<E>;
+
+    fn add(self, other: U256<E>) -> Self::Output {
+        self + &other
+    }
+}
+
+impl<E: Environment> Add<&U256<E>> for U256<E> {
+    type Output = Self;
+
+    fn add(self, other: &Self) -> Self::Output {
+        &self + other
+    }
+}
+
+impl<E: Environment> Add<&U256<E>> for &U256<E> {
+    type Output = U256<E>;
+
+    fn add(self, other: &U256<E>) -> Self::Output {
+        self.add_checked(other)
+    }
+}
+
+impl<E: Environment> AddAssign<U256<E>> for U256<E> {
+    fn add_assign(&mut self, other: U256<E>) {
+        *self += &other;
+    }
+}
+
+impl<E: Environment> AddAssign<&U256<E>> for U256<E> {
+    fn add_assign(&mut self, other: &U256<E>) {
+        // Stores the sum of `self` and `other` in `self`.
+        *self = self.add_checked(other);
+    }
+}
+
+impl<E: Environment> AddChecked<Self> for U256<E> {
+    type Output = Self;
+
+    /// Returns the sum of `self` and `other`, enforcing that the sum does not overflow.
+    fn add_checked(&self, other: &Self) -> Self::Output {
+        let (sum, carry) = self.add_with_carry(other, &Boolean::constant(false));
+        // Ensure the carry out is zero.
+        Self::assert_no_overflow(carry, "U256 overflow on addition of two constants");
+        sum
+    }
+}
+
+impl<E: Environment> AddWrapped<Self> for U256<E> {
+    type Output = Self;
+
+    /// Returns the sum of `self` and `other`, modulo `2^256`.
+    fn add_wrapped(&self, other: &Self) -> Self::Output {
+        self.add_with_carry(other, &Boolean::constant(false)).0
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::{test_helpers::*, *};
+    use crate::test_utilities::check_operation_halts;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_add(first: [u64; NUM_LIMBS], second: [u64; NUM_LIMBS], mode_a: Mode, mode_b: Mode) {
+        let (expected, is_overflow) = add_with_carry(&first, &second, false);
+        let a = U256::<Circuit>::new(mode_a, first);
+        let b = U256::<Circuit>::new(mode_b, second);
+
+        // Check the wrapped sum.
+        Circuit::scope(format!("AddWrapped {mode_a} {mode_b}"), || {
+            assert_eq!(expected, a.add_wrapped(&b).eject_value());
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+
+        // Check the checked sum.
+        match is_overflow && mode_a.is_constant() && mode_b.is_constant() {
+            true => check_operation_halts(&a, &b, U256::add_checked),
+            false => {
+                Circuit::scope(format!("AddChecked {mode_a} {mode_b}"), || {
+                    assert_eq!(expected, a.add_checked(&b).eject_value());
+                });
+                assert_eq!(!is_overflow, Circuit::is_satisfied());
+            }
+        }
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_add() {
+        let mut rng = TestRng::default();
+        let max = [u64::MAX; NUM_LIMBS];
+        let one = [1, 0, 0, 0];
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                for _ in 0..ITERATIONS {
+                    check_add(sample(&mut rng), sample(&mut rng), mode_a, mode_b);
+                }
+                // Check the carry propagates across every limb.
+                check_add(max, one, mode_a, mode_b);
+                check_add([u64::MAX, u64::MAX, 0, 0], one, mode_a, mode_b);
+            }
+        }
+    }
+}
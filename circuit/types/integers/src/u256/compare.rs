@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Compare<Self> for U256<E> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` is less than `other`.
+    fn is_less_than(&self, other: &Self) -> Self::Output {
+        // The subtraction `self - other` underflows if and only if `self` is less than `other`.
+        self.sub_with_borrow(other).1
+    }
+
+    /// Returns `true` if `self` is greater than `other`.
+    fn is_greater_than(&self, other: &Self) -> Self::Output {
+        other.is_less_than(self)
+    }
+
+    /// Returns `true` if `self` is less than or equal to `other`.
+    fn is_less_than_or_equal(&self, other: &Self) -> Self::Output {
+        other.is_greater_than_or_equal(self)
+    }
+
+    /// Returns `true` if `self` is greater than or equal to `other`.
+    fn is_greater_than_or_equal(&self, other: &Self) -> Self::Output {
+        !self.is_less_than(other)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::{test_helpers::*, *};
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    /// Returns the limbs in big-endian order, so that they compare as the integer does.
+    fn to_be(limbs: [u64; NUM_LIMBS]) -> [u64; NUM_LIMBS] {
+        [limbs[3], limbs[2], limbs[1], limbs[0]]
+    }
+
+    fn check_compare(first: [u64; NUM_LIMBS], second: [u64; NUM_LIMBS], mode_a: Mode, mode_b: Mode) {
+        let a = U256::<Circuit>::new(mode_a, first);
+        let b = U256::<Circuit>::new(mode_b, second);
+
+        Circuit::scope(format!("Compare {mode_a} {mode_b}"), || {
+            assert_eq!(to_be(first) < to_be(second), a.is_less_than(&b).eject_value());
+            assert_eq!(to_be(first) > to_be(second), a.is_greater_than(&b).eject_value());
+            assert_eq!(to_be(first) <= to_be(second), a.is_less_than_or_equal(&b).eject_value());
+            assert_eq!(to_be(first) >= to_be(second), a.is_greater_than_or_equal(&b).eject_value());
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_compare() {
+        let mut rng = TestRng::default();
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                for _ in 0..ITERATIONS {
+                    let (first, second) = (sample(&mut rng), sample(&mut rng));
+                    check_compare(first, second, mode_a, mode_b);
+                    check_compare(first, first, mode_a, mode_b);
+                }
+                // Check integers that differ only in the least significant limb.
+                check_compare([1, 2, 3, 4], [2, 2, 3, 4], mode_a, mode_b);
+            }
+        }
+    }
+}
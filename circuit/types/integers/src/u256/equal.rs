@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Equal<Self> for U256<E> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` and `other` are equal.
+    fn is_equal(&self, other: &Self) -> Self::Output {
+        // Compare the integers limb-wise, as each 64-bit limb fits in a field element.
+        self.limbs()
+            .iter()
+            .zip_eq(other.limbs().iter())
+            .fold(Boolean::constant(true), |is_equal, (a, b)| is_equal & a.is_equal(b))
+    }
+
+    /// Returns `true` if `self` and `other` are *not* equal.
+    fn is_not_equal(&self, other: &Self) -> Self::Output {
+        !self.is_equal(other)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::{test_helpers::*, *};
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_equal(first: [u64; NUM_LIMBS], second: [u64; NUM_LIMBS], mode_a: Mode, mode_b: Mode) {
+        let a = U256::<Circuit>::new(mode_a, first);
+        let b = U256::<Circuit>::new(mode_b, second);
+
+        Circuit::scope(format!("Equal {mode_a} {mode_b}"), || {
+            assert_eq!(first == second, a.is_equal(&b).eject_value());
+            assert_eq!(first != second, a.is_not_equal(&b).eject_value());
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_equal() {
+        let mut rng = TestRng::default();
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                for _ in 0..ITERATIONS {
+                    let (first, second) = (sample(&mut rng), sample(&mut rng));
+                    check_equal(first, second, mode_a, mode_b);
+                    check_equal(first, first, mode_a, mode_b);
+                }
+                // Check integers that differ only in the most significant limb.
+                check_equal([1, 2, 3, 4], [1, 2, 3, 5], mode_a, mode_b);
+            }
+        }
+    }
+}
@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod add;
+mod compare;
+mod equal;
+mod mul;
+mod sub;
+mod ternary;
+
+use super::*;
+
+/// The number of bits in each limb of a `U256`.
+const LIMB_BITS: usize = 64;
+/// The number of limbs in a `U256`.
+const NUM_LIMBS: usize = 4;
+
+/// A 256-bit unsigned integer.
+///
+/// As a 256-bit value exceeds the capacity of the base field, the arithmetic is computed
+/// over 64-bit limbs with carry chains, rather than in a single field element as for `Integer`.
+#[derive(Clone)]
+pub struct U256<E: Environment> {
+    bits_le: Vec<Boolean<E>>,
+}
+
+impl<E: Environment> U256<E> {
+    /// Returns the number of bits in a `U256`.
+    pub const fn size_in_bits() -> u16 {
+        (LIMB_BITS * NUM_LIMBS) as u16
+    }
+
+    /// Returns the little-endian 64-bit limbs of `self`, as field elements.
+    fn limbs(&self) -> Vec<Field<E>> {
+        self.bits_le.chunks(LIMB_BITS).map(Field::from_bits_le).collect()
+    }
+
+    /// Ensures the given overflow flag is `false`, halting if it is a constant.
+    fn assert_no_overflow(is_overflow: Boolean<E>, message: &str) {
+        if is_overflow.is_constant() && is_overflow.eject_value() {
+            E::halt(message)
+        }
+        E::assert_eq(is_overflow, E::zero());
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> Inject for U256<E> {
+    type Primitive = [u64; NUM_LIMBS];
+
+    /// Initializes a new `U256` from its little-endian 64-bit limbs.
+    fn new(mode: Mode, value: Self::Primitive) -> Self {
+        let bits_le = value
+            .iter()
+            .flat_map(|limb| (0..LIMB_BITS).map(move |i| Boolean::new(mode, (limb >> i) & 1 == 1)))
+            .collect();
+        Self { bits_le }
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> Eject for U256<E> {
+    type Primitive = [u64; NUM_LIMBS];
+
+    /// Ejects the mode of the `U256`.
+    fn eject_mode(&self) -> Mode {
+        self.bits_le.eject_mode()
+    }
+
+    /// Ejects the `U256` as its little-endian 64-bit limbs.
+    fn eject_value(&self) -> Self::Primitive {
+        let mut limbs = [0u64; NUM_LIMBS];
+        for (limb, bits_le) in limbs.iter_mut().zip_eq(self.bits_le.chunks(LIMB_BITS)) {
+            *limb = bits_le.iter().rev().fold(0u64, |value, bit| (value << 1) | bit.eject_value() as u64);
+        }
+        limbs
+    }
+}
+
+impl<E: Environment> ToBits for U256<E> {
+    type Boolean = Boolean<E>;
+
+    /// Outputs the little-endian bit representation of `self` *with* trailing zeros.
+    fn to_bits_le(&self) -> Vec<Self::Boolean> {
+        self.bits_le.clone()
+    }
+
+    /// Outputs the big-endian bit representation of `self` *with* leading zeros.
+    fn to_bits_be(&self) -> Vec<Self::Boolean> {
+        let mut bits_le = self.to_bits_le();
+        bits_le.reverse();
+        bits_le
+    }
+}
+
+impl<E: Environment> FromBits for U256<E> {
+    type Boolean = Boolean<E>;
+
+    /// Initializes a new `U256` from a list of little-endian bits *with* trailing zeros.
+    fn from_bits_le(bits_le: &[Self::Boolean]) -> Self {
+        let num_bits = Self::size_in_bits() as usize;
+        // Ensure any excess bits are zero.
+        if bits_le.len() > num_bits {
+            let should_be_zero = bits_le[num_bits..].iter().fold(Boolean::constant(false), |acc, bit| acc | bit);
+            E::assert_eq(E::zero(), should_be_zero);
+        }
+
+        // Construct the sanitized list of bits, resizing up if necessary.
+        let mut bits_le = bits_le.iter().take(num_bits).cloned().collect::<Vec<_>>();
+        bits_le.resize(num_bits, Boolean::constant(false));
+
+        Self { bits_le }
+    }
+
+    /// Initializes a new `U256` from a list of big-endian bits *with* leading zeros.
+    fn from_bits_be(bits_be: &[Self::Boolean]) -> Self {
+        let mut bits_le = bits_be.to_vec();
+        bits_le.reverse();
+        Self::from_bits_le(&bits_le)
+    }
+}
+
+#[cfg(all(test, console))]
+mod test_helpers {
+    use super::*;
+
+    /// Samples a random `U256`, as little-endian 64-bit limbs.
+    pub(super) fn sample(rng: &mut TestRng) -> [u64; NUM_LIMBS] {
+        [u64::rand(rng), u64::rand(rng), u64::rand(rng), u64::rand(rng)]
+    }
+
+    /// Returns the limbs of `a + b + carry`, and the carry out.
+    pub(super) fn add_with_carry(a: &[u64; NUM_LIMBS], b: &[u64; NUM_LIMBS], carry: bool) -> ([u64; NUM_LIMBS], bool) {
+        let mut output = [0u64; NUM_LIMBS];
+        let mut carry = carry as u128;
+        for ((output, a), b) in output.iter_mut().zip(a).zip(b) {
+            let sum = *a as u128 + *b as u128 + carry;
+            *output = sum as u64;
+            carry = sum >> LIMB_BITS;
+        }
+        (output, carry == 1)
+    }
+
+    /// Returns the limbs of `a - b`, and `true` if the subtraction underflows.
+    pub(super) fn sub_with_borrow(a: &[u64; NUM_LIMBS], b: &[u64; NUM_LIMBS]) -> ([u64; NUM_LIMBS], bool) {
+        let (output, carry) = add_with_carry(a, &[!b[0], !b[1], !b[2], !b[3]], true);
+        (output, !carry)
+    }
+
+    /// Returns the lower limbs of `a * b`, and `true` if the product overflows.
+    pub(super) fn mul_with_overflow(a: &[u64; NUM_LIMBS], b: &[u64; NUM_LIMBS]) -> ([u64; NUM_LIMBS], bool) {
+        let mut product = [0u64; 2 * NUM_LIMBS];
+        for i in 0..NUM_LIMBS {
+            let mut carry = 0u128;
+            for j in 0..NUM_LIMBS {
+                let sum = a[i] as u128 * b[j] as u128 + product[i + j] as u128 + carry;
+                product[i + j] = sum as u64;
+                carry = sum >> LIMB_BITS;
+            }
+            product[i + NUM_LIMBS] = carry as u64;
+        }
+        ([product[0], product[1], product[2], product[3]], product[NUM_LIMBS..].iter().any(|limb| *limb != 0))
+    }
+}
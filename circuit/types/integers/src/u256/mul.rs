@@ -0,0 +1,167 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The number of bits in a column of the product, with the carry in from the previous column.
+/// Note: Each column is at most `4 * (2^64 - 1)^2` plus a carry less than `2^67`, so it fits in 131 bits.
+const COLUMN_BITS: usize = 2 * LIMB_BITS + 3;
+
+impl<E: Environment> U256<E> {
+    /// Returns the product of `self` and `other` modulo `2^256`, and `true` if the product overflows.
+    pub(super) fn mul_with_overflow(&self, other: &Self) -> (Self, Boolean<E>) {
+        let (a, b) = (self.limbs(), other.limbs());
+
+        // Compute the lower columns of the schoolbook product, propagating the carries between columns.
+        let mut bits_le = Vec::with_capacity(Self::size_in_bits() as usize);
+        let mut carry = Field::zero();
+        for k in 0..NUM_LIMBS {
+            let column = (0..=k).fold(carry, |column, i| column + &a[i] * &b[k - i]);
+            let column_bits = column.to_lower_bits_le(COLUMN_BITS);
+            // Split the column into the output limb and the carry out.
+            bits_le.extend_from_slice(&column_bits[..LIMB_BITS]);
+            carry = Field::from_bits_le(&column_bits[LIMB_BITS..]);
+        }
+
+        // Sum the final carry and the products in the upper columns.
+        // Note: As each term is nonnegative and far smaller than the modulus of the base field,
+        // the sum is zero if and only if every term is zero, which is when the product does not overflow.
+        let upper = (1..NUM_LIMBS)
+            .flat_map(|i| (NUM_LIMBS - i..NUM_LIMBS).map(move |j| (i, j)))
+            .fold(carry, |upper, (i, j)| upper + &a[i] * &b[j]);
+
+        (Self { bits_le }, upper.is_not_equal(&Field::zero()))
+    }
+}
+
+impl<E: Environment> Mul<U256<E>> for U256<E> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self * &other
+    }
+}
+
+impl<E: Environment> Mul<U256<E>> for &U256<E> {
+    type Output = U256<E>;
+
+    fn mul(self, other: U256<E>) -> Self::Output {
+        self * &other
+    }
+}
+
+impl<E: Environment> Mul<&U256<E>> for U256<E> {
+    type Output = Self;
+
+    fn mul(self, other: &Self) -> Self::Output {
+        &self * other
+    }
+}
+
+impl<E: Environment> Mul<&U256<E>> for &U256<E> {
+    type Output = U256<E>;
+
+    fn mul(self, other: &U256<E>) -> Self::Output {
+        self.mul_checked(other)
+    }
+}
+
+impl<E: Environment> MulAssign<U256<E>> for U256<E> {
+    fn mul_assign(&mut self, other: U256<E>) {
+        *self *= &other;
+    }
+}
+
+impl<E: Environment> MulAssign<&U256<E>> for U256<E> {
+    fn mul_assign(&mut self, other: &U256<E>) {
+        // Stores the product of `self` and `other` in `self`.
+        *self = self.mul_checked(other);
+    }
+}
+
+impl<E: Environment> MulChecked<Self> for U256<E> {
+    type Output = Self;
+
+    /// Returns the product of `self` and `other`, enforcing that the product does not overflow.
+    fn mul_checked(&self, other: &Self) -> Self::Output {
+        let (product, is_overflow) = self.mul_with_overflow(other);
+        // Ensure the multiplication does not overflow.
+        Self::assert_no_overflow(is_overflow, "U256 overflow on multiplication of two constants");
+        product
+    }
+}
+
+impl<E: Environment> MulWrapped<Self> for U256<E> {
+    type Output = Self;
+
+    /// Returns the product of `self` and `other`, modulo `2^256`.
+    fn mul_wrapped(&self, other: &Self) -> Self::Output {
+        self.mul_with_overflow(other).0
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::{test_helpers::*, *};
+    use crate::test_utilities::check_operation_halts;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_mul(first: [u64; NUM_LIMBS], second: [u64; NUM_LIMBS], mode_a: Mode, mode_b: Mode) {
+        let (expected, is_overflow) = mul_with_overflow(&first, &second);
+        let a = U256::<Circuit>::new(mode_a, first);
+        let b = U256::<Circuit>::new(mode_b, second);
+
+        // Check the wrapped product.
+        Circuit::scope(format!("MulWrapped {mode_a} {mode_b}"), || {
+            assert_eq!(expected, a.mul_wrapped(&b).eject_value());
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+
+        // Check the checked product.
+        match is_overflow && mode_a.is_constant() && mode_b.is_constant() {
+            true => check_operation_halts(&a, &b, U256::mul_checked),
+            false => {
+                Circuit::scope(format!("MulChecked {mode_a} {mode_b}"), || {
+                    assert_eq!(expected, a.mul_checked(&b).eject_value());
+                });
+                assert_eq!(!is_overflow, Circuit::is_satisfied());
+            }
+        }
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_mul() {
+        let mut rng = TestRng::default();
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                for _ in 0..ITERATIONS {
+                    check_mul(sample(&mut rng), sample(&mut rng), mode_a, mode_b);
+                    // Check products that do not overflow.
+                    let [a0, a1, ..] = sample(&mut rng);
+                    let [b0, b1, ..] = sample(&mut rng);
+                    check_mul([a0, a1, 0, 0], [b0, b1, 0, 0], mode_a, mode_b);
+                }
+                // Check a product that only overflows in the final carry.
+                check_mul([0, 0, 0, 1 << 63], [2, 0, 0, 0], mode_a, mode_b);
+                check_mul([u64::MAX; NUM_LIMBS], [1, 0, 0, 0], mode_a, mode_b);
+            }
+        }
+    }
+}
@@ -0,0 +1,147 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> U256<E> {
+    /// Returns the difference of `self` and `other` modulo `2^256`, and `true` if the subtraction underflows.
+    pub(super) fn sub_with_borrow(&self, other: &Self) -> (Self, Boolean<E>) {
+        // Compute `self + !other + 1`, which equals `self - other + 2^256`.
+        let complement = Self { bits_le: other.bits_le.iter().map(|bit| !bit).collect() };
+        let (difference, carry) = self.add_with_carry(&complement, &Boolean::constant(true));
+        // The subtraction underflows if and only if the sum does not carry out.
+        (difference, !carry)
+    }
+}
+
+impl<E: Environment> Sub<U256<E>> for U256<E> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        self - &other
+    }
+}
+
+impl<E: Environment> Sub<U256<E>> for &U256<E> {
+    type Output = U256<E>;
+
+    fn sub(self, other: U256<E>) -> Self::Output {
+        self - &other
+    }
+}
+
+impl<E: Environment> Sub<&U256<E>> for U256<E> {
+    type Output = Self;
+
+    fn sub(self, other: &Self) -> Self::Output {
+        &self - other
+    }
+}
+
+impl<E: Environment> Sub<&U256<E>> for &U256<E> {
+    type Output = U256<E>;
+
+    fn sub(self, other: &U256<E>) -> Self::Output {
+        self.sub_checked(other)
+    }
+}
+
+impl<E: Environment> SubAssign<U256<E>> for U256<E> {
+    fn sub_assign(&mut self, other: U256<E>) {
+        *self -= &other;
+    }
+}
+
+impl<E: Environment> SubAssign<&U256<E>> for U256<E> {
+    fn sub_assign(&mut self, other: &U256<E>) {
+        // Stores the difference of `self` and `other` in `self`.
+        *self = self.sub_checked(other);
+    }
+}
+
+impl<E: Environment> SubChecked<Self> for U256<E> {
+    type Output = Self;
+
+    /// Returns the difference of `self` and `other`, enforcing that the difference does not underflow.
+    fn sub_checked(&self, other: &Self) -> Self::Output {
+        let (difference, is_underflow) = self.sub_with_borrow(other);
+        // Ensure the subtraction does not underflow.
+        Self::assert_no_overflow(is_underflow, "U256 underflow on subtraction of two constants");
+        difference
+    }
+}
+
+impl<E: Environment> SubWrapped<Self> for U256<E> {
+    type Output = Self;
+
+    /// Returns the difference of `self` and `other`, modulo `2^256`.
+    fn sub_wrapped(&self, other: &Self) -> Self::Output {
+        self.sub_with_borrow(other).0
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::{test_helpers::*, *};
+    use crate::test_utilities::check_operation_halts;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_sub(first: [u64; NUM_LIMBS], second: [u64; NUM_LIMBS], mode_a: Mode, mode_b: Mode) {
+        let (expected, is_underflow) = sub_with_borrow(&first, &second);
+        let a = U256::<Circuit>::new(mode_a, first);
+        let b = U256::<Circuit>::new(mode_b, second);
+
+        // Check the wrapped difference.
+        Circuit::scope(format!("SubWrapped {mode_a} {mode_b}"), || {
+            assert_eq!(expected, a.sub_wrapped(&b).eject_value());
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+
+        // Check the checked difference.
+        match is_underflow && mode_a.is_constant() && mode_b.is_constant() {
+            true => check_operation_halts(&a, &b, U256::sub_checked),
+            false => {
+                Circuit::scope(format!("SubChecked {mode_a} {mode_b}"), || {
+                    assert_eq!(expected, a.sub_checked(&b).eject_value());
+                });
+                assert_eq!(!is_underflow, Circuit::is_satisfied());
+            }
+        }
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_sub() {
+        let mut rng = TestRng::default();
+        let zero = [0; NUM_LIMBS];
+        let one = [1, 0, 0, 0];
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                for _ in 0..ITERATIONS {
+                    let (first, second) = (sample(&mut rng), sample(&mut rng));
+                    check_sub(first, second, mode_a, mode_b);
+                    check_sub(first, first, mode_a, mode_b);
+                }
+                // Check the borrow propagates across every limb.
+                check_sub(zero, one, mode_a, mode_b);
+                check_sub([0, 0, 1, 0], one, mode_a, mode_b);
+            }
+        }
+    }
+}
@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Ternary for U256<E> {
+    type Boolean = Boolean<E>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        Self {
+            bits_le: first
+                .bits_le
+                .iter()
+                .zip_eq(second.bits_le.iter())
+                .map(|(first_bit, second_bit)| Self::Boolean::ternary(condition, first_bit, second_bit))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::{test_helpers::*, *};
+    use snarkvm_circuit_environment::Circuit;
+
+    #[test]
+    fn test_ternary() {
+        let mut rng = TestRng::default();
+        for mode_condition in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                for flag in [true, false] {
+                    let (first, second) = (sample(&mut rng), sample(&mut rng));
+                    let condition = Boolean::<Circuit>::new(mode_condition, flag);
+                    let a = U256::<Circuit>::new(mode, first);
+                    let b = U256::<Circuit>::new(mode, second);
+
+                    Circuit::scope(format!("Ternary {mode_condition} {mode}"), || {
+                        let expected = if flag { first } else { second };
+                        assert_eq!(expected, U256::ternary(&condition, &a, &b).eject_value());
+                    });
+                    assert!(Circuit::is_satisfied());
+                    Circuit::reset();
+                }
+            }
+        }
+    }
+}
@@ -20,6 +20,7 @@ pub use snarkvm_circuit_environment as environment;
 pub use snarkvm_circuit_types_address as address;
 pub use snarkvm_circuit_types_boolean as boolean;
 pub use snarkvm_circuit_types_field as field;
+pub use snarkvm_circuit_types_float as float;
 pub use snarkvm_circuit_types_group as group;
 pub use snarkvm_circuit_types_integers as integers;
 pub use snarkvm_circuit_types_scalar as scalar;
@@ -29,8 +30,9 @@ pub use address::Address;
 pub use boolean::Boolean;
 pub use environment::prelude::*;
 pub use field::Field;
+pub use float::F64;
 pub use group::Group;
-pub use integers::{I128, I16, I32, I64, I8, U128, U16, U32, U64, U8};
+pub use integers::{I128, I16, I32, I64, I8, U128, U16, U256, U32, U64, U8};
 pub use scalar::Scalar;
 pub use string::StringType;
 
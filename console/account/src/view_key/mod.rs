@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod bytes;
+mod ct_eq;
 mod serialize;
 mod string;
 mod to_address;
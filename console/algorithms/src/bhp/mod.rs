@@ -21,6 +21,9 @@ mod commit;
 mod commit_uncompressed;
 mod hash;
 mod hash_uncompressed;
+mod sponge;
+
+pub use sponge::BHPSponge;
 
 use snarkvm_console_types::prelude::*;
 
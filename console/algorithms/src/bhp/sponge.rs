@@ -0,0 +1,166 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A streaming interface to the BHP hash function, for inputs whose length is not known up front.
+///
+/// ## Design
+/// As the input length is unknown when the first block is processed, the length is moved to a final block:
+/// ```text
+/// DIGEST_0 = BHP([ 0...0 || DOMAIN || 1...1 || INPUT[0..BLOCK_SIZE] ]);
+/// DIGEST_N+1 = BHP([ DIGEST_N[0..DATA_BITS] || INPUT[(N+1)*BLOCK_SIZE..(N+2)*BLOCK_SIZE] ]);
+/// OUTPUT = BHP([ DIGEST_N[0..DATA_BITS] || LENGTH(INPUT) ]);
+/// ```
+/// The 64-bit all-ones tag in the first block separates the streaming hash from `BHP::hash`,
+/// which encodes `LENGTH(INPUT)` in the same position. As such, the two outputs differ for the same input.
+#[derive(Clone)]
+pub struct BHPSponge<'a, E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> {
+    /// The BHP hash function.
+    bhp: &'a BHP<E, NUM_WINDOWS, WINDOW_SIZE>,
+    /// The digest of the processed blocks, if any.
+    digest: Option<Group<E>>,
+    /// The absorbed bits that have not been processed yet.
+    buffer: Vec<bool>,
+    /// The total number of absorbed bits.
+    num_absorbed: u64,
+}
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns a new streaming instance of the BHP hash function.
+    pub fn sponge(&self) -> BHPSponge<'_, E, NUM_WINDOWS, WINDOW_SIZE> {
+        BHPSponge { bhp: self, digest: None, buffer: Vec::new(), num_absorbed: 0 }
+    }
+}
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPSponge<'_, E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the number of input bits processed per block.
+    fn block_size() -> usize {
+        NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE - Field::<E>::size_in_data_bits()
+    }
+
+    /// Absorbs the given input bits, processing every full block.
+    pub fn absorb(&mut self, input: &[bool]) -> Result<()> {
+        // Update the number of absorbed bits.
+        self.num_absorbed = match self.num_absorbed.checked_add(input.len() as u64) {
+            Some(num_absorbed) => num_absorbed,
+            None => bail!("The BHP sponge cannot absorb more than {} bits", u64::MAX),
+        };
+        // Buffer the input, and process each full block.
+        self.buffer.extend_from_slice(input);
+        while self.buffer.len() >= Self::block_size() {
+            let block = self.buffer.drain(..Self::block_size()).collect::<Vec<_>>();
+            self.digest = Some(self.process_block(&block)?);
+        }
+        Ok(())
+    }
+
+    /// Returns the BHP hash of the absorbed input as an affine group element.
+    pub fn squeeze_uncompressed(&self) -> Result<Group<E>> {
+        // Process the remaining bits, ensuring at least one block is processed.
+        let digest = match (self.digest, self.buffer.is_empty()) {
+            (Some(digest), true) => digest,
+            _ => self.process_block(&self.buffer)?,
+        };
+        // Construct the final block as: [ DIGEST[0..DATA_BITS] || LENGTH(INPUT) ].
+        let mut preimage = Vec::with_capacity(Field::<E>::size_in_data_bits() + 64);
+        preimage.extend(digest.to_x_coordinate().to_bits_le().iter().take(Field::<E>::size_in_data_bits()));
+        preimage.extend(self.num_absorbed.to_bits_le());
+        self.bhp.hasher.hash_uncompressed(&preimage)
+    }
+
+    /// Returns the BHP hash of the absorbed input as a field element.
+    pub fn squeeze(&self) -> Result<Field<E>> {
+        Ok(self.squeeze_uncompressed()?.to_x_coordinate())
+    }
+
+    /// Returns the digest after processing the given block.
+    fn process_block(&self, block: &[bool]) -> Result<Group<E>> {
+        let mut preimage = Vec::with_capacity(Field::<E>::size_in_data_bits() + block.len());
+        match self.digest {
+            // Construct the first block as: [ 0...0 || DOMAIN || 1...1 || INPUT[0..BLOCK_SIZE] ].
+            None => {
+                preimage.extend(&self.bhp.domain);
+                preimage.extend([true; 64]);
+            }
+            // Construct the subsequent blocks as: [ PREVIOUS_HASH[0..DATA_BITS] || INPUT[I * BLOCK_SIZE..(I + 1) * BLOCK_SIZE] ].
+            Some(digest) => {
+                preimage.extend(digest.to_x_coordinate().to_bits_le().iter().take(Field::<E>::size_in_data_bits()))
+            }
+        }
+        preimage.extend_from_slice(block);
+        self.bhp.hasher.hash_uncompressed(&preimage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_sponge<const NUM_WINDOWS: u8, const WINDOW_SIZE: u8>(rng: &mut TestRng) -> Result<()> {
+        let bhp = BHP::<CurrentEnvironment, NUM_WINDOWS, WINDOW_SIZE>::setup("BHPSpongeTest")?;
+        let block_size = BHPSponge::<CurrentEnvironment, NUM_WINDOWS, WINDOW_SIZE>::block_size();
+
+        for num_bits in [0, 1, block_size - 1, block_size, block_size + 1, 3 * block_size + 7] {
+            let input = (0..num_bits).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+
+            // Absorb the input at once.
+            let mut sponge = bhp.sponge();
+            sponge.absorb(&input)?;
+            let expected = sponge.squeeze()?;
+
+            // Absorb the input in random-sized pieces.
+            let mut sponge = bhp.sponge();
+            let mut remaining = &input[..];
+            while !remaining.is_empty() {
+                let (piece, rest) = remaining.split_at(rng.gen_range(1..=remaining.len()));
+                sponge.absorb(piece)?;
+                remaining = rest;
+            }
+            assert_eq!(expected, sponge.squeeze()?);
+
+            // Ensure squeezing does not consume the sponge.
+            assert_eq!(expected, sponge.squeeze()?);
+
+            // Ensure the output depends on the input length.
+            sponge.absorb(&[false])?;
+            assert_ne!(expected, sponge.squeeze()?);
+
+            // Ensure the output is separated from the one-shot hash.
+            if num_bits > 0 {
+                assert_ne!(expected, bhp.hash(&input)?);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bhp_sponge() -> Result<()> {
+        let mut rng = TestRng::default();
+        for _ in 0..ITERATIONS {
+            check_sponge::<3, 57>(&mut rng)?;
+            check_sponge::<6, 43>(&mut rng)?;
+            check_sponge::<15, 23>(&mut rng)?;
+            check_sponge::<8, 54>(&mut rng)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,142 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl Hash for Blake3 {
+    type Input = bool;
+    type Output = Vec<bool>;
+
+    /// Returns the BLAKE3 hash of the given input as a list of bits.
+    /// A byte string is hashed by passing each byte as **little-endian** bits, in order,
+    /// and the digest is returned in the same form. The input length must be a multiple of 8.
+    fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        // Ensure the input is a byte string.
+        ensure!(input.len() % 8 == 0, "BLAKE3 expects a multiple of 8 input bits, found {}", input.len());
+
+        // Convert the input into bytes.
+        let bytes = input
+            .chunks(8)
+            .map(|bits| bits.iter().rev().fold(0u8, |byte, bit| (byte << 1) | (*bit as u8)))
+            .collect::<Vec<_>>();
+        // Split the input into chunks, where the empty input is a single empty chunk.
+        let chunks = match bytes.is_empty() {
+            true => vec![&bytes[..]],
+            false => bytes.chunks(CHUNK_SIZE_IN_BYTES).collect(),
+        };
+
+        // Output the root chaining value as little-endian bytes, of little-endian bits.
+        Ok(subtree(&chunks, 0, ROOT)
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1))
+            .collect())
+    }
+}
+
+/// Returns the chaining value of the subtree over the given chunks, where `counter` is the index of the first chunk.
+/// The given `flags` are set on the final compression of the subtree.
+fn subtree(chunks: &[&[u8]], counter: u64, flags: u32) -> [u32; 8] {
+    if chunks.len() == 1 {
+        return chunk(chunks[0], counter, flags);
+    }
+    // The left subtree holds the largest power-of-two number of chunks, while leaving at least one chunk on the right.
+    let num_left = 1usize << (usize::BITS - 1 - (chunks.len() - 1).leading_zeros());
+    let left = subtree(&chunks[..num_left], counter, 0);
+    let right = subtree(&chunks[num_left..], counter + num_left as u64, 0);
+
+    // Compress the chaining values of the children into the parent.
+    let mut block = [0u32; 16];
+    block[..8].copy_from_slice(&left);
+    block[8..].copy_from_slice(&right);
+    compress(&IV, &block, 0, BLOCK_SIZE_IN_BYTES, PARENT | flags)
+}
+
+/// Returns the chaining value of the given chunk, where `counter` is the index of the chunk.
+/// The given `flags` are set on the last block of the chunk.
+fn chunk(chunk: &[u8], counter: u64, flags: u32) -> [u32; 8] {
+    // Split the chunk into blocks, where the empty chunk is a single empty block.
+    let blocks = match chunk.is_empty() {
+        true => vec![chunk],
+        false => chunk.chunks(BLOCK_SIZE_IN_BYTES).collect(),
+    };
+
+    let mut chaining_value = IV;
+    for (i, block) in blocks.iter().enumerate() {
+        // Pad the block with zeros, and read it as little-endian words.
+        let mut padded = [0u8; BLOCK_SIZE_IN_BYTES];
+        padded[..block.len()].copy_from_slice(block);
+        let mut words = [0u32; 16];
+        for (word, bytes) in words.iter_mut().zip(padded.chunks(4)) {
+            *word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+        // Set the domain flags for the block.
+        let mut block_flags = 0;
+        if i == 0 {
+            block_flags |= CHUNK_START;
+        }
+        if i == blocks.len() - 1 {
+            block_flags |= CHUNK_END | flags;
+        }
+        chaining_value = compress(&chaining_value, &words, counter, block.len(), block_flags);
+    }
+    chaining_value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the BLAKE3 hash of the given bytes, as a hex string.
+    fn blake3(input: &[u8]) -> String {
+        let bits = input.iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)).collect::<Vec<_>>();
+        let hash = Blake3::new().hash(&bits).unwrap();
+        hash.chunks(8)
+            .map(|bits| bits.iter().rev().fold(0u8, |byte, bit| (byte << 1) | (*bit as u8)))
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+
+    #[test]
+    fn test_blake3() {
+        // BLAKE3 of the empty string.
+        assert_eq!(blake3(b""), "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262");
+        // BLAKE3 of "abc".
+        assert_eq!(blake3(b"abc"), "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85");
+    }
+
+    #[test]
+    fn test_blake3_multiple_chunks() {
+        // The official test vectors hash the repeating byte sequence `0, 1, ..., 250`.
+        let input = (0..4097).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+
+        for (num_bytes, expected) in [
+            (1023, "10108970eeda3eb932baac1428c7a2163b0e924c9a9e25b35bba72b28f70bd11"),
+            (1024, "42214739f095a406f3fc83deb889744ac00df831c10daa55189b5d121c855af7"),
+            (1025, "d00278ae47eb27b34faecf67b4fe263f82d5412916c1ffd97c8cb7fb814b8444"),
+            (2048, "e776b6028c7cd22a4d0ba182a8bf62205d2ef576467e838ed6f2529b85fba24a"),
+            (3072, "b98cb0ff3623be03326b373de6b9095218513e64f1ee2edd2525c7ad1e5cffd2"),
+            (4097, "9b4052b38f1c5fc8b1f9ff7ac7b27cd242487b3d890d15c96a1c25b8aa0fb995"),
+        ] {
+            assert_eq!(blake3(&input[..num_bytes]), expected, "BLAKE3 mismatch on {num_bytes} bytes");
+        }
+    }
+
+    #[test]
+    fn test_blake3_rejects_partial_bytes() {
+        assert!(Blake3::new().hash(&[true; 7]).is_err());
+    }
+}
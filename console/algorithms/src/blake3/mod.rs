@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod hash;
+
+use snarkvm_console_types::prelude::*;
+
+/// The initial chaining value of BLAKE3, which is the initial hash value of SHA-256.
+pub(crate) const IV: [u32; 8] =
+    [0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19];
+
+/// The permutation applied to the message words between rounds.
+pub(crate) const MESSAGE_PERMUTATION: [usize; 16] = [2, 6, 3, 10, 7, 0, 4, 13, 1, 11, 12, 5, 9, 14, 15, 8];
+
+/// The domain flag for the first block of a chunk.
+pub(crate) const CHUNK_START: u32 = 1 << 0;
+/// The domain flag for the last block of a chunk.
+pub(crate) const CHUNK_END: u32 = 1 << 1;
+/// The domain flag for a parent node.
+pub(crate) const PARENT: u32 = 1 << 2;
+/// The domain flag for the root node.
+pub(crate) const ROOT: u32 = 1 << 3;
+
+/// The number of bytes in a BLAKE3 block.
+pub(crate) const BLOCK_SIZE_IN_BYTES: usize = 64;
+/// The number of bytes in a BLAKE3 chunk.
+pub(crate) const CHUNK_SIZE_IN_BYTES: usize = 1024;
+
+/// BLAKE3 is a tree hash built on a reduced-round BLAKE2s compression function,
+/// as specified in the BLAKE3 paper. This implements the default (unkeyed) 256-bit hash.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Blake3;
+
+impl Blake3 {
+    /// Initializes a new instance of BLAKE3.
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// Applies the BLAKE3 compression function, and returns the new chaining value.
+/// Note: The counter is split into its low and high words, and the block length is at most 64 bytes.
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn compress(
+    chaining_value: &[u32; 8],
+    block: &[u32; 16],
+    counter: u64,
+    block_len: usize,
+    flags: u32,
+) -> [u32; 8] {
+    // Initialize the state from the chaining value, the IV, the counter, the block length, and the flags.
+    let mut state = [0u32; 16];
+    state[..8].copy_from_slice(chaining_value);
+    state[8..12].copy_from_slice(&IV[..4]);
+    state[12] = counter as u32;
+    state[13] = (counter >> 32) as u32;
+    state[14] = block_len as u32;
+    state[15] = flags;
+
+    // Perform the rounds, permuting the message words between rounds.
+    let mut m = *block;
+    for round in 0..7 {
+        // Mix the columns.
+        g(&mut state, 0, 4, 8, 12, m[0], m[1]);
+        g(&mut state, 1, 5, 9, 13, m[2], m[3]);
+        g(&mut state, 2, 6, 10, 14, m[4], m[5]);
+        g(&mut state, 3, 7, 11, 15, m[6], m[7]);
+        // Mix the diagonals.
+        g(&mut state, 0, 5, 10, 15, m[8], m[9]);
+        g(&mut state, 1, 6, 11, 12, m[10], m[11]);
+        g(&mut state, 2, 7, 8, 13, m[12], m[13]);
+        g(&mut state, 3, 4, 9, 14, m[14], m[15]);
+        if round < 6 {
+            m = MESSAGE_PERMUTATION.map(|i| m[i]);
+        }
+    }
+
+    // Output the first half of the state, masked by the second half.
+    let mut output = [0u32; 8];
+    for (i, word) in output.iter_mut().enumerate() {
+        *word = state[i] ^ state[i + 8];
+    }
+    output
+}
+
+/// The BLAKE3 quarter-round function, which mixes the message words `x` and `y` into the given state words.
+fn g(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize, x: u32, y: u32) {
+    state[a] = state[a].wrapping_add(state[b]).wrapping_add(x);
+    state[d] = (state[d] ^ state[a]).rotate_right(16);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_right(12);
+    state[a] = state[a].wrapping_add(state[b]).wrapping_add(y);
+    state[d] = (state[d] ^ state[a]).rotate_right(8);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_right(7);
+}
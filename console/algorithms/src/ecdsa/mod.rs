@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod verify;
+
+use num_bigint::BigUint;
+
+/// The base field modulus of secp256k1, as little-endian 64-bit limbs.
+const SECP256K1_BASE_MODULUS: [u64; 4] =
+    [0xFFFF_FFFE_FFFF_FC2F, 0xFFFF_FFFF_FFFF_FFFF, 0xFFFF_FFFF_FFFF_FFFF, 0xFFFF_FFFF_FFFF_FFFF];
+/// The scalar field modulus (the group order) of secp256k1, as little-endian 64-bit limbs.
+const SECP256K1_SCALAR_MODULUS: [u64; 4] =
+    [0xBFD2_5E8C_D036_4141, 0xBAAE_DCE6_AF48_A03B, 0xFFFF_FFFF_FFFF_FFFE, 0xFFFF_FFFF_FFFF_FFFF];
+/// The affine generator of secp256k1, as little-endian 64-bit limbs.
+const SECP256K1_GENERATOR: ([u64; 4], [u64; 4]) =
+    ([0x59F2_815B_16F8_1798, 0x029B_FCDB_2DCE_28D9, 0x55A0_6295_CE87_0B07, 0x79BE_667E_F9DC_BBAC], [
+        0x9C47_D08F_FB10_D4B8,
+        0xFD17_B448_A685_5419,
+        0x5DA4_FBFC_0E11_08A8,
+        0x483A_DA77_26A3_C465,
+    ]);
+/// The coefficient `B` of secp256k1, in `y^2 = x^3 + B`.
+const SECP256K1_B: u64 = 7;
+
+/// An affine point on secp256k1, where `None` is the point at infinity.
+type Point = Option<(BigUint, BigUint)>;
+
+/// ECDSA over secp256k1, as used by Bitcoin and Ethereum.
+///
+/// Public keys are encoded as the 64-byte concatenation of the big-endian affine coordinates `x || y`,
+/// and signatures as the 64-byte concatenation of the big-endian scalars `r || s`.
+/// Note: This implementation is intended for verification only, and is not constant-time.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ECDSA;
+
+impl ECDSA {
+    /// Initializes a new instance of ECDSA over secp256k1.
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// Returns the integer represented by the given little-endian 64-bit limbs.
+fn from_limbs(limbs: &[u64]) -> BigUint {
+    limbs.iter().rev().fold(BigUint::default(), |value, limb| (value << 64) + *limb)
+}
+
+/// Returns the inverse of `value` modulo the prime `modulus`.
+fn inverse(value: &BigUint, modulus: &BigUint) -> BigUint {
+    value.modpow(&(modulus - BigUint::from(2u64)), modulus)
+}
+
+/// Returns the sum of the given points on secp256k1.
+fn add(a: &Point, b: &Point) -> Point {
+    let p = from_limbs(&SECP256K1_BASE_MODULUS);
+    match (a, b) {
+        (None, _) => b.clone(),
+        (_, None) => a.clone(),
+        (Some((x1, y1)), Some((x2, y2))) => {
+            let lambda = if x1 == x2 {
+                // If the points are inverses, the sum is the point at infinity.
+                if (y1 + y2) % &p == BigUint::default() {
+                    return None;
+                }
+                // Otherwise, double the point.
+                (x1 * x1 * 3u64) % &p * inverse(&((y1 << 1) % &p), &p) % &p
+            } else {
+                (y2 + &p - y1) % &p * inverse(&((x2 + &p - x1) % &p), &p) % &p
+            };
+            let x3 = (&lambda * &lambda + (&p - x1) + (&p - x2)) % &p;
+            let y3 = (lambda * ((x1 + &p - &x3) % &p) + (&p - y1)) % &p;
+            Some((x3, y3))
+        }
+    }
+}
+
+/// Returns the product of the given point and scalar on secp256k1.
+fn mul(point: &Point, scalar: &BigUint) -> Point {
+    let bits_be = scalar.to_bytes_be().into_iter().flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1));
+    bits_be.fold(None, |accumulator, bit| {
+        let accumulator = add(&accumulator, &accumulator);
+        match bit {
+            true => add(&accumulator, point),
+            false => accumulator,
+        }
+    })
+}
@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl ECDSA {
+    /// Returns `true` if the signature is valid for the given public key and message digest.
+    /// The digest is the 32-byte hash of the message, such as its Keccak-256 or SHA-256 hash.
+    pub fn verify(&self, public_key: &[u8; 64], digest: &[u8; 32], signature: &[u8; 64]) -> bool {
+        let p = from_limbs(&SECP256K1_BASE_MODULUS);
+        let n = from_limbs(&SECP256K1_SCALAR_MODULUS);
+        let zero = BigUint::default();
+
+        // Ensure the public key is a point on the curve.
+        let x = BigUint::from_bytes_be(&public_key[..32]);
+        let y = BigUint::from_bytes_be(&public_key[32..]);
+        if x >= p || y >= p || (&y * &y) % &p != (&x * &x * &x + SECP256K1_B) % &p {
+            return false;
+        }
+
+        // Ensure the signature scalars are in `[1, n - 1]`.
+        let r = BigUint::from_bytes_be(&signature[..32]);
+        let s = BigUint::from_bytes_be(&signature[32..]);
+        if r == zero || r >= n || s == zero || s >= n {
+            return false;
+        }
+
+        // Compute `u1 := z / s` and `u2 := r / s`, where `z` is the digest reduced modulo `n`.
+        let z = BigUint::from_bytes_be(digest) % &n;
+        let s_inverse = inverse(&s, &n);
+        let u1 = (z * &s_inverse) % &n;
+        let u2 = (&r * &s_inverse) % &n;
+
+        // Compute `R := u1 * G + u2 * Q`, and ensure `R.x mod n == r`.
+        let generator = Some((from_limbs(&SECP256K1_GENERATOR.0), from_limbs(&SECP256K1_GENERATOR.1)));
+        match add(&mul(&generator, &u1), &mul(&Some((x, y)), &u2)) {
+            Some((rx, _)) => rx % &n == r,
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the test vectors as `(public_key, digest, signature)`.
+    fn test_vectors() -> Vec<([u8; 64], [u8; 32], [u8; 64])> {
+        [
+            (
+                "2c8c31fc9f990c6b55e3865a184a4ce50e09481f2eaeb3e60ec1cea13a6ae64564b95e4fdb6948c0386e189b006a29f686769b011704275e4459822dc3328085",
+                "af2bdbe1aa9b6ec1e2ade1d694f41fc71a831d0268e9891562113d8a62add1bf",
+                "432310e32cb80eb6503a26ce83cc165c783b870845fb8aad6d970889fcd7a6c8530128b6b81c548874a6305d93ed071ca6e05074d85863d4056ce89b02bfab69",
+            ),
+            (
+                "f973a0b87062c389d125d8199e803b832b6ac6bf7867a4f6cd87506060fc4c584b4a0a3f26c988c54c236b224c48bb605b265949e65c098ecd87a581ca10e25d",
+                "0827be68960cbca55a732db7d63111c5970d47077867f17ef14852457d8552ef",
+                "8bf5447ae65c5ebbeb7e474cf4e8a5c255dbfd33763d93535bfb4d970de72fc467670d3fe9a1056ca29fae905dc16eb46a7d83f09a7d65b7f2a822b788342389",
+            ),
+        ]
+        .iter()
+        .map(|(public_key, digest, signature)| {
+            (
+                hex::decode(public_key).unwrap().try_into().unwrap(),
+                hex::decode(digest).unwrap().try_into().unwrap(),
+                hex::decode(signature).unwrap().try_into().unwrap(),
+            )
+        })
+        .collect()
+    }
+
+    #[test]
+    fn test_verify() {
+        for (public_key, digest, signature) in test_vectors() {
+            assert!(ECDSA::new().verify(&public_key, &digest, &signature));
+        }
+    }
+
+    #[test]
+    fn test_verify_fails() {
+        let ecdsa = ECDSA::new();
+        for (public_key, digest, signature) in test_vectors() {
+            // Ensure a different digest fails.
+            let mut candidate = digest;
+            candidate[0] ^= 1;
+            assert!(!ecdsa.verify(&public_key, &candidate, &signature));
+
+            // Ensure a different signature fails.
+            for i in [0, 63] {
+                let mut candidate = signature;
+                candidate[i] ^= 1;
+                assert!(!ecdsa.verify(&public_key, &digest, &candidate));
+            }
+
+            // Ensure a public key that is not on the curve fails.
+            let mut candidate = public_key;
+            candidate[63] ^= 1;
+            assert!(!ecdsa.verify(&candidate, &digest, &signature));
+
+            // Ensure a zero signature fails.
+            assert!(!ecdsa.verify(&public_key, &digest, &[0u8; 64]));
+
+            // Ensure a signature with `s == n` fails.
+            let mut candidate = signature;
+            candidate[32..].copy_from_slice(&from_limbs(&SECP256K1_SCALAR_MODULUS).to_bytes_be());
+            assert!(!ecdsa.verify(&public_key, &digest, &candidate));
+        }
+    }
+}
@@ -0,0 +1,141 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod verify;
+
+use crate::Sha512;
+use snarkvm_console_types::prelude::*;
+
+use num_bigint::BigUint;
+
+/// The base field modulus of edwards25519, `2^255 - 19`, as little-endian 64-bit limbs.
+const ED25519_BASE_MODULUS: [u64; 4] =
+    [0xFFFF_FFFF_FFFF_FFED, 0xFFFF_FFFF_FFFF_FFFF, 0xFFFF_FFFF_FFFF_FFFF, 0x7FFF_FFFF_FFFF_FFFF];
+/// The order of the prime-order subgroup of edwards25519, as little-endian 64-bit limbs.
+const ED25519_SCALAR_MODULUS: [u64; 4] =
+    [0x5812_631A_5CF5_D3ED, 0x14DE_F9DE_A2F7_9CD6, 0x0000_0000_0000_0000, 0x1000_0000_0000_0000];
+/// The coefficient `D` of edwards25519, in `-x^2 + y^2 = 1 + D * x^2 * y^2`, as little-endian 64-bit limbs.
+const ED25519_D: [u64; 4] =
+    [0x75EB_4DCA_1359_78A3, 0x0070_0A4D_4141_D8AB, 0x8CC7_4079_7779_E898, 0x5203_6CEE_2B6F_FE73];
+/// The affine base point of edwards25519, as little-endian 64-bit limbs.
+const ED25519_GENERATOR: ([u64; 4], [u64; 4]) =
+    ([0xC956_2D60_8F25_D51A, 0x692C_C760_9525_A7B2, 0xC0A4_E231_FDD6_DC5C, 0x2169_36D3_CD6E_53FE], [
+        0x6666_6666_6666_6658,
+        0x6666_6666_6666_6666,
+        0x6666_6666_6666_6666,
+        0x6666_6666_6666_6666,
+    ]);
+/// A square root of `-1` in the base field, as little-endian 64-bit limbs.
+const SQRT_MINUS_ONE: [u64; 4] =
+    [0xC4EE_1B27_4A0E_A0B0, 0x2F43_1806_AD2F_E478, 0x2B4D_0099_3DFB_D7A7, 0x2B83_2480_4FC1_DF0B];
+
+/// An affine point on edwards25519.
+type Point = (BigUint, BigUint);
+
+/// Ed25519, the EdDSA signature scheme over edwards25519 with SHA-512, as specified in RFC 8032.
+///
+/// Public keys and the `R` component of signatures are encoded as compressed points, and the `S`
+/// component of signatures as a little-endian scalar, following RFC 8032.
+/// Note: This implementation is intended for verification only, and is not constant-time.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Ed25519;
+
+impl Ed25519 {
+    /// Initializes a new instance of Ed25519.
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// Returns the integer represented by the given little-endian 64-bit limbs.
+fn from_limbs(limbs: &[u64]) -> BigUint {
+    limbs.iter().rev().fold(BigUint::default(), |value, limb| (value << 64) + *limb)
+}
+
+/// Returns the inverse of `value` modulo the base field modulus.
+fn inverse(value: &BigUint) -> BigUint {
+    let p = from_limbs(&ED25519_BASE_MODULUS);
+    value.modpow(&(&p - BigUint::from(2u64)), &p)
+}
+
+/// Returns the x-coordinate of the point with the given y-coordinate and sign of x, if it exists.
+fn recover_x(y: &BigUint, is_odd: bool) -> Option<BigUint> {
+    let p = from_limbs(&ED25519_BASE_MODULUS);
+    let d = from_limbs(&ED25519_D);
+    let zero = BigUint::default();
+
+    // Compute `x^2 := (y^2 - 1) / (D * y^2 + 1)`.
+    let y2 = y * y % &p;
+    let x2 = (&y2 + &p - 1u64) * inverse(&((&d * &y2 + 1u64) % &p)) % &p;
+    if x2 == zero {
+        return match is_odd {
+            true => None,
+            false => Some(zero),
+        };
+    }
+
+    // Compute the square root of `x^2`, as `p = 5 mod 8`.
+    let mut x = x2.modpow(&((&p + 3u64) >> 3), &p);
+    if (&x * &x) % &p != x2 {
+        x = x * from_limbs(&SQRT_MINUS_ONE) % &p;
+    }
+    if (&x * &x) % &p != x2 {
+        return None;
+    }
+    // Select the root with the given sign.
+    match (&x % 2u64 == BigUint::from(1u64)) == is_odd {
+        true => Some(x),
+        false => Some(&p - x),
+    }
+}
+
+/// Returns the point encoded by the given 32 bytes, if it is a valid encoding.
+fn decompress(bytes: &[u8]) -> Option<Point> {
+    let mut bytes = bytes.to_vec();
+    let is_odd = bytes[31] >> 7 == 1;
+    bytes[31] &= 0x7F;
+    // Ensure the y-coordinate is less than the modulus.
+    let y = BigUint::from_bytes_le(&bytes);
+    if y >= from_limbs(&ED25519_BASE_MODULUS) {
+        return None;
+    }
+    recover_x(&y, is_odd).map(|x| (x, y))
+}
+
+/// Returns the sum of the given points on edwards25519.
+fn add(a: &Point, b: &Point) -> Point {
+    let p = from_limbs(&ED25519_BASE_MODULUS);
+    let d = from_limbs(&ED25519_D);
+    let ((x1, y1), (x2, y2)) = (a, b);
+    // Compute `t := D * x1 * x2 * y1 * y2`.
+    let t = &d * x1 * x2 % &p * y1 * y2 % &p;
+    let x3 = (x1 * y2 + y1 * x2) * inverse(&((&t + 1u64) % &p)) % &p;
+    let y3 = (y1 * y2 + x1 * x2) * inverse(&((&p + 1u64 - t) % &p)) % &p;
+    (x3, y3)
+}
+
+/// Returns the product of the given point and scalar on edwards25519.
+fn mul(point: &Point, scalar: &BigUint) -> Point {
+    let identity = (BigUint::default(), BigUint::from(1u64));
+    let bits_be = scalar.to_bytes_be().into_iter().flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1));
+    bits_be.fold(identity, |accumulator, bit| {
+        let accumulator = add(&accumulator, &accumulator);
+        match bit {
+            true => add(&accumulator, point),
+            false => accumulator,
+        }
+    })
+}
@@ -0,0 +1,115 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl Ed25519 {
+    /// Returns `true` if the signature is valid for the given public key and message.
+    /// Note: This checks the cofactorless verification equation `[S]B == R + [k]A`.
+    pub fn verify(&self, public_key: &[u8; 32], message: &[u8], signature: &[u8; 64]) -> bool {
+        // Decode the public key and the `R` component of the signature.
+        let (public_key_point, r) = match (decompress(public_key), decompress(&signature[..32])) {
+            (Some(public_key_point), Some(r)) => (public_key_point, r),
+            _ => return false,
+        };
+        // Ensure the `S` component of the signature is less than the subgroup order.
+        let l = from_limbs(&ED25519_SCALAR_MODULUS);
+        let s = BigUint::from_bytes_le(&signature[32..]);
+        if s >= l {
+            return false;
+        }
+
+        // Compute `k := SHA-512(R || A || M)`, as a little-endian integer modulo the subgroup order.
+        let preimage = signature[..32].iter().chain(public_key).chain(message);
+        let bits = preimage.flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)).collect::<Vec<_>>();
+        let hash = match Sha512::new().hash(&bits) {
+            Ok(hash) => hash,
+            Err(_) => return false,
+        };
+        let hash = hash.chunks(8).map(|bits| bits.iter().rev().fold(0u8, |byte, bit| (byte << 1) | (*bit as u8)));
+        let k = BigUint::from_bytes_le(&hash.collect::<Vec<_>>()) % &l;
+
+        // Ensure `[S]B == R + [k]A`.
+        let generator = (from_limbs(&ED25519_GENERATOR.0), from_limbs(&ED25519_GENERATOR.1));
+        mul(&generator, &s) == add(&r, &mul(&public_key_point, &k))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the test vectors from RFC 8032, as `(public_key, message, signature)`.
+    fn test_vectors() -> Vec<([u8; 32], Vec<u8>, [u8; 64])> {
+        [
+            (
+                "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+                "",
+                "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+            ),
+            (
+                "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+                "72",
+                "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+            ),
+        ]
+        .iter()
+        .map(|(public_key, message, signature)| {
+            (
+                hex::decode(public_key).unwrap().try_into().unwrap(),
+                hex::decode(message).unwrap(),
+                hex::decode(signature).unwrap().try_into().unwrap(),
+            )
+        })
+        .collect()
+    }
+
+    #[test]
+    fn test_verify() {
+        for (public_key, message, signature) in test_vectors() {
+            assert!(Ed25519::new().verify(&public_key, &message, &signature));
+        }
+    }
+
+    #[test]
+    fn test_verify_fails() {
+        let ed25519 = Ed25519::new();
+        for (public_key, message, signature) in test_vectors() {
+            // Ensure a different message fails.
+            let mut candidate = message.clone();
+            candidate.push(0);
+            assert!(!ed25519.verify(&public_key, &candidate, &signature));
+
+            // Ensure a different signature fails.
+            for i in [0, 32] {
+                let mut candidate = signature;
+                candidate[i] ^= 1;
+                assert!(!ed25519.verify(&public_key, &message, &candidate));
+            }
+
+            // Ensure a different public key fails.
+            let mut candidate = public_key;
+            candidate[0] ^= 1;
+            assert!(!ed25519.verify(&candidate, &message, &signature));
+
+            // Ensure a signature with `S + L` fails.
+            let s = BigUint::from_bytes_le(&signature[32..]) + from_limbs(&ED25519_SCALAR_MODULUS);
+            let mut candidate = signature;
+            candidate[32..].copy_from_slice(&s.to_bytes_le());
+            assert!(!ed25519.verify(&public_key, &message, &candidate));
+        }
+    }
+}
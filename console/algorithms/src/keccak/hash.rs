@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl Hash for Keccak256 {
+    type Input = bool;
+    type Output = Vec<bool>;
+
+    /// Returns the Keccak-256 hash of the given input as a list of **little-endian** bits.
+    /// A byte string is hashed by passing each byte as little-endian bits, in order.
+    fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        // Pad the input with `10*1`, to a multiple of the rate.
+        let num_padded_bits = (input.len() + 1 + KECCAK_256_RATE) / KECCAK_256_RATE * KECCAK_256_RATE;
+        let mut padded = Vec::with_capacity(num_padded_bits);
+        padded.extend_from_slice(input);
+        padded.push(true);
+        padded.resize(num_padded_bits - 1, false);
+        padded.push(true);
+
+        // Absorb each block into the state.
+        let mut state = [0u64; 25];
+        for block in padded.chunks(KECCAK_256_RATE) {
+            for (lane, bits) in state.iter_mut().zip(block.chunks(64)) {
+                *lane ^= bits.iter().rev().fold(0u64, |lane, bit| (lane << 1) | (*bit as u64));
+            }
+            keccak_f(&mut state);
+        }
+
+        // Squeeze the output from the state.
+        Ok(state.iter().flat_map(|lane| (0..64).map(move |i| (lane >> i) & 1 == 1)).take(KECCAK_256_OUTPUT).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the Keccak-256 hash of the given bytes, as bytes.
+    fn keccak256(input: &[u8]) -> Vec<u8> {
+        let bits = input.iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)).collect::<Vec<_>>();
+        let hash = Keccak256::new().hash(&bits).unwrap();
+        hash.chunks(8).map(|bits| bits.iter().rev().fold(0u8, |byte, bit| (byte << 1) | (*bit as u8))).collect()
+    }
+
+    #[test]
+    fn test_keccak256() {
+        // Keccak-256 of the empty string.
+        assert_eq!(keccak256(b"").as_slice(), &[
+            0xc5, 0xd2, 0x46, 0x01, 0x86, 0xf7, 0x23, 0x3c, 0x92, 0x7e, 0x7d, 0xb2, 0xdc, 0xc7, 0x03, 0xc0, 0xe5, 0x00,
+            0xb6, 0x53, 0xca, 0x82, 0x27, 0x3b, 0x7b, 0xfa, 0xd8, 0x04, 0x5d, 0x85, 0xa4, 0x70
+        ]);
+        // Keccak-256 of "abc".
+        assert_eq!(keccak256(b"abc").as_slice(), &[
+            0x4e, 0x03, 0x65, 0x7a, 0xea, 0x45, 0xa9, 0x4f, 0xc7, 0xd4, 0x7b, 0xa8, 0x26, 0xc8, 0xd6, 0x67, 0xc0, 0xd1,
+            0xe6, 0xe3, 0x3a, 0x64, 0xa0, 0x36, 0xec, 0x44, 0xf5, 0x8f, 0xa1, 0x2d, 0x6c, 0x45
+        ]);
+    }
+
+    #[test]
+    fn test_keccak256_multiple_blocks() {
+        // Keccak-256 of 200 bytes of `0xa3`.
+        assert_eq!(keccak256(&[0xa3u8; 200]).as_slice(), &[
+            0x3a, 0x57, 0x66, 0x6b, 0x04, 0x87, 0x77, 0xf2, 0xc9, 0x53, 0xdc, 0x44, 0x56, 0xf4, 0x5a, 0x25, 0x88, 0xe1,
+            0xcb, 0x6f, 0x2d, 0xa7, 0x60, 0x12, 0x2d, 0x53, 0x0a, 0xc2, 0xce, 0x60, 0x7d, 0x4a
+        ]);
+
+        // Ensure inputs around the block boundary are handled.
+        for num_bytes in [135, 136, 137, 272, 300] {
+            let input = vec![0xa3u8; num_bytes];
+            assert_eq!(keccak256(&input).len(), 32);
+            assert_ne!(keccak256(&input), keccak256(&input[1..]));
+        }
+    }
+}
@@ -22,16 +22,37 @@
 pub use snarkvm_console_types::prelude::*;
 
 pub mod bhp;
-pub use bhp::{BHP, BHP1024, BHP256, BHP512, BHP768};
+pub use bhp::{BHPSponge, BHP, BHP1024, BHP256, BHP512, BHP768};
 
 mod blake2xs;
 pub use blake2xs::Blake2Xs;
 
+mod blake3;
+pub use blake3::Blake3;
+
+mod ecdsa;
+pub use ecdsa::ECDSA;
+
+mod ed25519;
+pub use ed25519::Ed25519;
+
 mod elligator2;
 pub use elligator2::Elligator2;
 
+mod keccak;
+pub use keccak::Keccak256;
+
 mod pedersen;
-pub use pedersen::{Pedersen, Pedersen128, Pedersen64};
+pub use pedersen::{Pedersen, Pedersen128, Pedersen64, PedersenSponge};
 
 mod poseidon;
 pub use poseidon::{Poseidon, Poseidon2, Poseidon4, Poseidon8};
+
+mod poseidon_v2;
+pub use poseidon_v2::{Poseidon2V2, PoseidonV2};
+
+mod sha256;
+pub use sha256::Sha256;
+
+mod sha512;
+pub use sha512::Sha512;
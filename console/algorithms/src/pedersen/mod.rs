@@ -18,6 +18,9 @@ mod commit;
 mod commit_uncompressed;
 mod hash;
 mod hash_uncompressed;
+mod sponge;
+
+pub use sponge::PedersenSponge;
 
 use crate::Blake2Xs;
 use snarkvm_console_types::prelude::*;
@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A streaming interface to the Pedersen hash function, for inputs whose length is not known up front.
+///
+/// As the Pedersen hash is a sum over the set bits of the input, the streaming hash
+/// of an input is equal to `Pedersen::hash` of the same input.
+#[derive(Clone)]
+pub struct PedersenSponge<'a, E: Environment, const NUM_BITS: u8> {
+    /// The Pedersen hash function.
+    pedersen: &'a Pedersen<E, NUM_BITS>,
+    /// The digest of the absorbed bits.
+    digest: Group<E>,
+    /// The total number of absorbed bits.
+    num_absorbed: usize,
+}
+
+impl<E: Environment, const NUM_BITS: u8> Pedersen<E, NUM_BITS> {
+    /// Returns a new streaming instance of the Pedersen hash function.
+    pub fn sponge(&self) -> PedersenSponge<'_, E, NUM_BITS> {
+        PedersenSponge { pedersen: self, digest: Group::zero(), num_absorbed: 0 }
+    }
+}
+
+impl<E: Environment, const NUM_BITS: u8> PedersenSponge<'_, E, NUM_BITS> {
+    /// Absorbs the given input bits.
+    pub fn absorb(&mut self, input: &[bool]) -> Result<()> {
+        // Ensure the input size is within the parameter size.
+        let num_absorbed = self.num_absorbed.saturating_add(input.len());
+        ensure!(
+            num_absorbed <= NUM_BITS as usize,
+            "Invalid input size for Pedersen: expected <= {NUM_BITS}, found {num_absorbed}"
+        );
+
+        // Add base_i^{input_i} to the digest, for all i.
+        for (bit, base) in input.iter().zip_eq(&self.pedersen.base_window[self.num_absorbed..num_absorbed]) {
+            if *bit {
+                self.digest += base;
+            }
+        }
+        self.num_absorbed = num_absorbed;
+        Ok(())
+    }
+
+    /// Returns the Pedersen hash of the absorbed input as a group element.
+    pub fn squeeze_uncompressed(&self) -> Group<E> {
+        self.digest
+    }
+
+    /// Returns the Pedersen hash of the absorbed input as a field element.
+    pub fn squeeze(&self) -> Field<E> {
+        self.digest.to_x_coordinate()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_sponge<const NUM_BITS: u8>(rng: &mut TestRng) -> Result<()> {
+        let pedersen = Pedersen::<CurrentEnvironment, NUM_BITS>::setup("PedersenSpongeTest");
+
+        let input = (0..rng.gen_range(0..=NUM_BITS)).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+
+        // Absorb the input in random-sized pieces.
+        let mut sponge = pedersen.sponge();
+        let mut remaining = &input[..];
+        while !remaining.is_empty() {
+            let (piece, rest) = remaining.split_at(rng.gen_range(1..=remaining.len()));
+            sponge.absorb(piece)?;
+            remaining = rest;
+        }
+        // Ensure the streaming hash matches the one-shot hash.
+        assert_eq!(pedersen.hash(&input)?, sponge.squeeze());
+        assert_eq!(pedersen.hash_uncompressed(&input)?, sponge.squeeze_uncompressed());
+
+        // Ensure the sponge rejects inputs beyond the parameter size.
+        let num_remaining = NUM_BITS as usize - input.len();
+        assert!(sponge.clone().absorb(&vec![false; num_remaining + 1]).is_err());
+        assert!(sponge.absorb(&vec![false; num_remaining]).is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_pedersen_sponge() -> Result<()> {
+        let mut rng = TestRng::default();
+        for _ in 0..ITERATIONS {
+            check_sponge::<64>(&mut rng)?;
+            check_sponge::<128>(&mut rng)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const RATE: usize> Hash for PoseidonV2<E, RATE> {
+    type Input = Field<E>;
+    type Output = Field<E>;
+
+    /// Returns the cryptographic hash for a list of field elements as input.
+    #[inline]
+    fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        Ok(self.hash_many(input, 1)[0])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Poseidon;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_hash<const RATE: usize>(rng: &mut TestRng) -> Result<()> {
+        let poseidon = PoseidonV2::<CurrentEnvironment, RATE>::setup("PoseidonV2Test")?;
+        let other = PoseidonV2::<CurrentEnvironment, RATE>::setup("PoseidonV2Other")?;
+        let poseidon_v1 = Poseidon::<CurrentEnvironment, RATE>::setup("PoseidonV2Test")?;
+
+        for num_inputs in 0..(3 * RATE) {
+            let input = (0..num_inputs).map(|_| Uniform::rand(rng)).collect::<Vec<Field<_>>>();
+            let expected = poseidon.hash(&input)?;
+            // Ensure the hash is deterministic.
+            assert_eq!(expected, poseidon.hash(&input)?);
+            // Ensure the hash depends on the domain separator.
+            assert_ne!(expected, other.hash(&input)?);
+            // Ensure the hash differs from Poseidon.
+            assert_ne!(expected, poseidon_v1.hash(&input)?);
+            // Ensure the hash depends on the input length.
+            let mut extended = input.clone();
+            extended.push(Field::zero());
+            assert_ne!(expected, poseidon.hash(&extended)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash() -> Result<()> {
+        let mut rng = TestRng::default();
+        for _ in 0..ITERATIONS {
+            check_hash::<2>(&mut rng)?;
+            check_hash::<3>(&mut rng)?;
+            check_hash::<7>(&mut rng)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const RATE: usize> HashMany for PoseidonV2<E, RATE> {
+    type Input = Field<E>;
+    type Output = Field<E>;
+
+    /// Returns the cryptographic hash for a list of field elements as input,
+    /// and returns the specified number of field elements as output.
+    #[inline]
+    fn hash_many(&self, input: &[Self::Input], num_outputs: u16) -> Vec<Self::Output> {
+        // Construct the preimage: [ DOMAIN || LENGTH(INPUT) || [0; RATE-2] || INPUT ].
+        let mut preimage = Vec::with_capacity(RATE + input.len());
+        preimage.push(self.domain);
+        preimage.push(Field::<E>::from_u128(input.len() as u128));
+        preimage.resize(RATE, Field::<E>::zero()); // Pad up to RATE.
+        preimage.extend_from_slice(input);
+
+        // Absorb the preimage, `RATE` elements at a time, permuting between each chunk.
+        let mut state = vec![E::Field::zero(); RATE + CAPACITY];
+        for (i, chunk) in preimage.chunks(RATE).enumerate() {
+            if i > 0 {
+                self.permute(&mut state);
+            }
+            for (element, input) in state[CAPACITY..].iter_mut().zip(chunk) {
+                *element += **input;
+            }
+        }
+
+        // Squeeze the output, `RATE` elements at a time, permuting before each chunk.
+        let mut output = Vec::with_capacity(num_outputs as usize);
+        while output.len() < num_outputs as usize {
+            self.permute(&mut state);
+            let num_remaining = num_outputs as usize - output.len();
+            output.extend(state[CAPACITY..].iter().take(num_remaining).map(|element| Field::new(*element)));
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    #[test]
+    fn test_hash_many() -> Result<()> {
+        let mut rng = TestRng::default();
+        let poseidon = PoseidonV2::<CurrentEnvironment, 2>::setup("PoseidonV2Test")?;
+
+        for num_inputs in 0..6 {
+            let input = (0..num_inputs).map(|_| Uniform::rand(&mut rng)).collect::<Vec<Field<_>>>();
+            let outputs = poseidon.hash_many(&input, 7);
+            assert_eq!(7, outputs.len());
+            // Ensure the first output is the hash.
+            assert_eq!(poseidon.hash(&input)?, outputs[0]);
+            // Ensure fewer outputs are a prefix of more outputs.
+            for num_outputs in 0..7 {
+                assert_eq!(outputs[..num_outputs], poseidon.hash_many(&input, num_outputs as u16)[..]);
+            }
+        }
+        Ok(())
+    }
+}
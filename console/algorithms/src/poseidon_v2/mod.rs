@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod hash;
+mod hash_many;
+mod permute;
+
+use snarkvm_console_types::prelude::*;
+use snarkvm_fields::{Poseidon2Parameters, PoseidonDefaultField};
+
+use std::sync::Arc;
+
+const CAPACITY: usize = 1;
+
+/// Poseidon2V2 is the Poseidon2 hash function of input rate 2.
+pub type Poseidon2V2<E> = PoseidonV2<E, 2>;
+
+/// The Poseidon2 hash function, which is distinguished from the rate-2 Poseidon hash function (`Poseidon2`)
+/// by the `V2` suffix. Poseidon2 replaces the dense MDS matrix of Poseidon with cheaper linear layers.
+#[derive(Clone)]
+pub struct PoseidonV2<E: Environment, const RATE: usize> {
+    /// The domain separator for the Poseidon2 hash function.
+    domain: Field<E>,
+    /// The Poseidon2 parameters for hashing.
+    parameters: Arc<Poseidon2Parameters<E::Field, RATE, CAPACITY>>,
+}
+
+impl<E: Environment, const RATE: usize> PoseidonV2<E, RATE> {
+    /// Initializes a new instance of Poseidon2.
+    pub fn setup(domain: &str) -> Result<Self> {
+        // Ensure the given domain is within the allowed size in bits.
+        let num_bits = domain.len().saturating_mul(8);
+        let max_bits = Field::<E>::size_in_data_bits();
+        ensure!(num_bits <= max_bits, "Domain cannot exceed {max_bits} bits, found {num_bits} bits");
+
+        Ok(Self {
+            domain: Field::<E>::new_domain_separator(domain),
+            parameters: Arc::new(E::Field::default_poseidon2_parameters::<RATE>()?),
+        })
+    }
+
+    /// Returns the domain separator for the hash function.
+    pub fn domain(&self) -> Field<E> {
+        self.domain
+    }
+
+    /// Returns the Poseidon2 parameters for hashing.
+    pub fn parameters(&self) -> &Arc<Poseidon2Parameters<E::Field, RATE, CAPACITY>> {
+        &self.parameters
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::edwards_bls12::Fq;
+
+    #[test]
+    fn test_parameters() {
+        fn check_parameters<const RATE: usize>() {
+            let parameters = Fq::default_poseidon2_parameters::<RATE>().unwrap();
+            // Ensure the round constants match the number of rounds.
+            assert_eq!(parameters.full_rounds, parameters.external_ark.len());
+            assert_eq!(parameters.partial_rounds, parameters.internal_ark.len());
+            assert!(parameters.external_ark.iter().all(|round| round.len() == RATE + CAPACITY));
+            // Ensure the linear layers match the state width.
+            assert_eq!(RATE + CAPACITY, parameters.external_matrix.len());
+            assert!(parameters.external_matrix.iter().all(|row| row.len() == RATE + CAPACITY));
+            assert_eq!(RATE + CAPACITY, parameters.internal_diagonal.len());
+            // Ensure the parameters are deterministic.
+            assert_eq!(parameters, Fq::default_poseidon2_parameters::<RATE>().unwrap());
+        }
+        check_parameters::<2>();
+        check_parameters::<3>();
+        check_parameters::<7>();
+
+        // Ensure the state widths without an external matrix are rejected.
+        assert!(Fq::default_poseidon2_parameters::<4>().is_err());
+        assert!(Fq::default_poseidon2_parameters::<8>().is_err());
+    }
+
+    #[test]
+    fn test_external_matrix() {
+        let (one, two) = (Fq::from(1u64), Fq::from(2u64));
+        // A state width of 3 uses `circ(2, 1, 1)`.
+        let parameters = Fq::default_poseidon2_parameters::<2>().unwrap();
+        assert_eq!(vec![vec![two, one, one], vec![one, two, one], vec![one, one, two]], parameters.external_matrix);
+        // A state width of 8 uses `circ(2 * M4, M4)`.
+        let parameters = Fq::default_poseidon2_parameters::<7>().unwrap();
+        assert_eq!(Fq::from(10u64), parameters.external_matrix[0][0]);
+        assert_eq!(Fq::from(5u64), parameters.external_matrix[0][4]);
+        assert_eq!(Fq::from(6u64), parameters.external_matrix[7][3]);
+    }
+}
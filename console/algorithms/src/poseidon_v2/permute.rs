@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const RATE: usize> PoseidonV2<E, RATE> {
+    /// Applies the Poseidon2 permutation to the given state in-place.
+    pub(super) fn permute(&self, state: &mut [E::Field]) {
+        let full_rounds_over_2 = self.parameters.full_rounds / 2;
+
+        // Apply the external matrix, before the first round.
+        self.apply_external_matrix(state);
+        // Apply the first half of the full rounds.
+        for ark in &self.parameters.external_ark[..full_rounds_over_2] {
+            self.apply_full_round(state, ark);
+        }
+        // Apply the partial rounds.
+        for ark in &self.parameters.internal_ark {
+            state[0] += ark;
+            state[0] = state[0].pow([self.parameters.alpha]);
+            self.apply_internal_matrix(state);
+        }
+        // Apply the second half of the full rounds.
+        for ark in &self.parameters.external_ark[full_rounds_over_2..] {
+            self.apply_full_round(state, ark);
+        }
+    }
+
+    /// Applies a full round, with the given additive round keys, to the state in-place.
+    fn apply_full_round(&self, state: &mut [E::Field], ark: &[E::Field]) {
+        for (element, ark) in state.iter_mut().zip_eq(ark) {
+            *element = (*element + ark).pow([self.parameters.alpha]);
+        }
+        self.apply_external_matrix(state);
+    }
+
+    /// Applies the external matrix to the state in-place.
+    fn apply_external_matrix(&self, state: &mut [E::Field]) {
+        let new_state = self
+            .parameters
+            .external_matrix
+            .iter()
+            .map(|row| E::Field::sum_of_products(row.iter(), state.iter()))
+            .collect::<Vec<_>>();
+        state.copy_from_slice(&new_state);
+    }
+
+    /// Applies the internal matrix `J + diag(d)` to the state in-place, as `x_i := sum(x) + d_i * x_i`.
+    fn apply_internal_matrix(&self, state: &mut [E::Field]) {
+        let sum = state.iter().sum::<E::Field>();
+        for (element, diagonal) in state.iter_mut().zip_eq(&self.parameters.internal_diagonal) {
+            *element = sum + *element * diagonal;
+        }
+    }
+}
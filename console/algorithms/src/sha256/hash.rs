@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl Hash for Sha256 {
+    type Input = bool;
+    type Output = Vec<bool>;
+
+    /// Returns the SHA-256 hash of the given input as a list of bits.
+    /// A byte string is hashed by passing each byte as **little-endian** bits, in order,
+    /// and the digest is returned in the same form. The input length must be a multiple of 8.
+    fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        // Ensure the input is a byte string.
+        ensure!(input.len() % 8 == 0, "SHA-256 expects a multiple of 8 input bits, found {}", input.len());
+
+        // Pad the input with `0x80`, then zeros, then the input length as a big-endian `u64`.
+        let num_padded_bits = (input.len() + 8 + 64 + BLOCK_SIZE_IN_BITS - 1) / BLOCK_SIZE_IN_BITS * BLOCK_SIZE_IN_BITS;
+        let mut padded = Vec::with_capacity(num_padded_bits);
+        padded.extend_from_slice(input);
+        padded.extend((0..8).map(|i| i == 7));
+        padded.resize(num_padded_bits - 64, false);
+        let length = input.len() as u64;
+        padded.extend(length.to_be_bytes().iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)));
+
+        // Compress each block into the state.
+        let mut state = INITIAL_STATE;
+        for block in padded.chunks(BLOCK_SIZE_IN_BITS) {
+            let mut words = [0u32; 16];
+            for (word, bits) in words.iter_mut().zip(block.chunks(32)) {
+                // Each word is composed of 4 big-endian bytes, of little-endian bits.
+                *word = bits.chunks(8).fold(0u32, |word, byte| {
+                    (word << 8) | byte.iter().rev().fold(0u32, |byte, bit| (byte << 1) | (*bit as u32))
+                });
+            }
+            compress(&mut state, &words);
+        }
+
+        // Output the state as big-endian bytes, of little-endian bits.
+        Ok(state
+            .iter()
+            .flat_map(|word| word.to_be_bytes())
+            .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the SHA-256 hash of the given bytes, as bytes.
+    fn sha256(input: &[u8]) -> Vec<u8> {
+        let bits = input.iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)).collect::<Vec<_>>();
+        let hash = Sha256::new().hash(&bits).unwrap();
+        hash.chunks(8).map(|bits| bits.iter().rev().fold(0u8, |byte, bit| (byte << 1) | (*bit as u8))).collect()
+    }
+
+    #[test]
+    fn test_sha256() {
+        // SHA-256 of the empty string.
+        assert_eq!(sha256(b"").as_slice(), &[
+            0xe3, 0xb0, 0xc4, 0x42, 0x98, 0xfc, 0x1c, 0x14, 0x9a, 0xfb, 0xf4, 0xc8, 0x99, 0x6f, 0xb9, 0x24, 0x27, 0xae,
+            0x41, 0xe4, 0x64, 0x9b, 0x93, 0x4c, 0xa4, 0x95, 0x99, 0x1b, 0x78, 0x52, 0xb8, 0x55
+        ]);
+        // SHA-256 of "abc".
+        assert_eq!(sha256(b"abc").as_slice(), &[
+            0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea, 0x41, 0x41, 0x40, 0xde, 0x5d, 0xae, 0x22, 0x23, 0xb0, 0x03,
+            0x61, 0xa3, 0x96, 0x17, 0x7a, 0x9c, 0xb4, 0x10, 0xff, 0x61, 0xf2, 0x00, 0x15, 0xad
+        ]);
+    }
+
+    #[test]
+    fn test_sha256_multiple_blocks() {
+        // SHA-256 of "abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq".
+        assert_eq!(sha256(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq").as_slice(), &[
+            0x24, 0x8d, 0x6a, 0x61, 0xd2, 0x06, 0x38, 0xb8, 0xe5, 0xc0, 0x26, 0x93, 0x0c, 0x3e, 0x60, 0x39, 0xa3, 0x3c,
+            0xe4, 0x59, 0x64, 0xff, 0x21, 0x67, 0xf6, 0xec, 0xed, 0xd4, 0x19, 0xdb, 0x06, 0xc1
+        ]);
+
+        // Ensure inputs around the block boundary are handled.
+        for num_bytes in [55, 56, 63, 64, 65, 200] {
+            let input = vec![0xa3u8; num_bytes];
+            assert_eq!(sha256(&input).len(), 32);
+            assert_ne!(sha256(&input), sha256(&input[1..]));
+        }
+    }
+
+    #[test]
+    fn test_sha256_rejects_partial_bytes() {
+        assert!(Sha256::new().hash(&[true; 7]).is_err());
+    }
+}
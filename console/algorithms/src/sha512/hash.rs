@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl Hash for Sha512 {
+    type Input = bool;
+    type Output = Vec<bool>;
+
+    /// Returns the SHA-512 hash of the given input as a list of bits.
+    /// A byte string is hashed by passing each byte as **little-endian** bits, in order,
+    /// and the digest is returned in the same form. The input length must be a multiple of 8.
+    fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        // Ensure the input is a byte string.
+        ensure!(input.len() % 8 == 0, "SHA-512 expects a multiple of 8 input bits, found {}", input.len());
+
+        // Pad the input with `0x80`, then zeros, then the input length as a big-endian `u128`.
+        let num_padded_bits =
+            (input.len() + 8 + 128 + BLOCK_SIZE_IN_BITS - 1) / BLOCK_SIZE_IN_BITS * BLOCK_SIZE_IN_BITS;
+        let mut padded = Vec::with_capacity(num_padded_bits);
+        padded.extend_from_slice(input);
+        padded.extend((0..8).map(|i| i == 7));
+        padded.resize(num_padded_bits - 128, false);
+        let length = input.len() as u128;
+        padded.extend(length.to_be_bytes().iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)));
+
+        // Compress each block into the state.
+        let mut state = INITIAL_STATE;
+        for block in padded.chunks(BLOCK_SIZE_IN_BITS) {
+            let mut words = [0u64; 16];
+            for (word, bits) in words.iter_mut().zip(block.chunks(64)) {
+                // Each word is composed of 8 big-endian bytes, of little-endian bits.
+                *word = bits.chunks(8).fold(0u64, |word, byte| {
+                    (word << 8) | byte.iter().rev().fold(0u64, |byte, bit| (byte << 1) | (*bit as u64))
+                });
+            }
+            compress(&mut state, &words);
+        }
+
+        // Output the state as big-endian bytes, of little-endian bits.
+        Ok(state
+            .iter()
+            .flat_map(|word| word.to_be_bytes())
+            .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the SHA-512 hash of the given bytes, as bytes.
+    fn sha512(input: &[u8]) -> Vec<u8> {
+        let bits = input.iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)).collect::<Vec<_>>();
+        let hash = Sha512::new().hash(&bits).unwrap();
+        hash.chunks(8).map(|bits| bits.iter().rev().fold(0u8, |byte, bit| (byte << 1) | (*bit as u8))).collect()
+    }
+
+    #[test]
+    fn test_sha512() {
+        // SHA-512 of the empty string.
+        assert_eq!(sha512(b"").as_slice(), &[
+            0xcf, 0x83, 0xe1, 0x35, 0x7e, 0xef, 0xb8, 0xbd, 0xf1, 0x54, 0x28, 0x50, 0xd6, 0x6d, 0x80, 0x07, 0xd6, 0x20,
+            0xe4, 0x05, 0x0b, 0x57, 0x15, 0xdc, 0x83, 0xf4, 0xa9, 0x21, 0xd3, 0x6c, 0xe9, 0xce, 0x47, 0xd0, 0xd1, 0x3c,
+            0x5d, 0x85, 0xf2, 0xb0, 0xff, 0x83, 0x18, 0xd2, 0x87, 0x7e, 0xec, 0x2f, 0x63, 0xb9, 0x31, 0xbd, 0x47, 0x41,
+            0x7a, 0x81, 0xa5, 0x38, 0x32, 0x7a, 0xf9, 0x27, 0xda, 0x3e
+        ]);
+        // SHA-512 of "abc".
+        assert_eq!(sha512(b"abc").as_slice(), &[
+            0xdd, 0xaf, 0x35, 0xa1, 0x93, 0x61, 0x7a, 0xba, 0xcc, 0x41, 0x73, 0x49, 0xae, 0x20, 0x41, 0x31, 0x12, 0xe6,
+            0xfa, 0x4e, 0x89, 0xa9, 0x7e, 0xa2, 0x0a, 0x9e, 0xee, 0xe6, 0x4b, 0x55, 0xd3, 0x9a, 0x21, 0x92, 0x99, 0x2a,
+            0x27, 0x4f, 0xc1, 0xa8, 0x36, 0xba, 0x3c, 0x23, 0xa3, 0xfe, 0xeb, 0xbd, 0x45, 0x4d, 0x44, 0x23, 0x64, 0x3c,
+            0xe8, 0x0e, 0x2a, 0x9a, 0xc9, 0x4f, 0xa5, 0x4c, 0xa4, 0x9f
+        ]);
+    }
+
+    #[test]
+    fn test_sha512_multiple_blocks() {
+        // SHA-512 of the 896-bit message from FIPS 180-4.
+        assert_eq!(
+            sha512(b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu").as_slice(),
+            &[
+                0x8e, 0x95, 0x9b, 0x75, 0xda, 0xe3, 0x13, 0xda, 0x8c, 0xf4, 0xf7, 0x28, 0x14, 0xfc, 0x14, 0x3f, 0x8f,
+                0x77, 0x79, 0xc6, 0xeb, 0x9f, 0x7f, 0xa1, 0x72, 0x99, 0xae, 0xad, 0xb6, 0x88, 0x90, 0x18, 0x50, 0x1d,
+                0x28, 0x9e, 0x49, 0x00, 0xf7, 0xe4, 0x33, 0x1b, 0x99, 0xde, 0xc4, 0xb5, 0x43, 0x3a, 0xc7, 0xd3, 0x29,
+                0xee, 0xb6, 0xdd, 0x26, 0x54, 0x5e, 0x96, 0xe5, 0x5b, 0x87, 0x4b, 0xe9, 0x09
+            ]
+        );
+
+        // Ensure inputs around the block boundary are handled.
+        for num_bytes in [111, 112, 127, 128, 129, 300] {
+            let input = vec![0xa3u8; num_bytes];
+            assert_eq!(sha512(&input).len(), 64);
+            assert_ne!(sha512(&input), sha512(&input[1..]));
+        }
+    }
+
+    #[test]
+    fn test_sha512_rejects_partial_bytes() {
+        assert!(Sha512::new().hash(&[true; 7]).is_err());
+    }
+}
@@ -365,6 +365,11 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         &self.tree
     }
 
+    /// Returns the leaf hasher of the tree.
+    pub const fn leaf_hasher(&self) -> &LH {
+        &self.leaf_hasher
+    }
+
     /// Returns the empty hash.
     pub const fn empty_hash(&self) -> &PH::Hash {
         &self.empty_hash
@@ -25,6 +25,7 @@ pub use snarkvm_utilities::{
     error,
     has_duplicates,
     io::{Read, Result as IoResult, Write},
+    Compress,
     FromBits as _,
     FromBytes,
     FromBytesDeserializer,
@@ -95,3 +96,4 @@ pub use serde::{
     Serialize,
     Serializer,
 };
+pub use subtle::{Choice, ConstantTimeEq};
@@ -68,3 +68,15 @@ pub trait Ternary {
     where
         Self: Sized;
 }
+
+impl<C: Ternary<Output = C>> Ternary for Vec<C> {
+    type Boolean = C::Boolean;
+    type Output = Vec<C>;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`, element-wise.
+    /// Note: `first` and `second` must be of the same length.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        assert_eq!(first.len(), second.len(), "Ternary requires vectors of the same length");
+        first.iter().zip(second).map(|(first, second)| C::ternary(condition, first, second)).collect()
+    }
+}
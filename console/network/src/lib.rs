@@ -112,6 +112,10 @@ pub trait Network:
     const MAX_DATA_DEPTH: usize = 32;
     /// The maximum number of values and/or entries in data.
     const MAX_DATA_ENTRIES: usize = 32;
+    /// The minimum number of elements in an array.
+    const MIN_ARRAY_ELEMENTS: u32 = 1;
+    /// The maximum number of elements in an array.
+    const MAX_ARRAY_ELEMENTS: u32 = 32;
     /// The maximum number of fields in data (must not exceed u16::MAX).
     #[allow(clippy::cast_possible_truncation)]
     const MAX_DATA_SIZE_IN_FIELDS: u32 = ((128 * 1024 * 8) / Field::<Self>::SIZE_IN_DATA_BITS) as u32;
@@ -226,6 +230,12 @@ pub trait Network:
     /// Returns the Poseidon hash with an input rate of 8.
     fn hash_psd8(input: &[Field<Self>]) -> Result<Field<Self>>;
 
+    /// Returns the Poseidon2 hash with an input rate of 2.
+    fn hash_psd2v2(input: &[Field<Self>]) -> Result<Field<Self>>;
+
+    /// Returns the BLAKE3 hash of the given byte string, truncated to the data bits of a field element.
+    fn hash_blake3(input: &[bool]) -> Result<Field<Self>>;
+
     /// Returns the extended Poseidon hash with an input rate of 2.
     fn hash_many_psd2(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>>;
 
@@ -17,9 +17,11 @@
 use super::*;
 use snarkvm_console_algorithms::{
     Blake2Xs,
+    Blake3,
     Pedersen128,
     Pedersen64,
     Poseidon2,
+    Poseidon2V2,
     Poseidon4,
     Poseidon8,
     BHP1024,
@@ -68,6 +70,8 @@ lazy_static! {
     pub static ref POSEIDON_4: Poseidon4<Testnet3> = Poseidon4::<Testnet3>::setup("AleoPoseidon4").expect("Failed to setup Poseidon4");
     /// The Poseidon hash function, using a rate of 8.
     pub static ref POSEIDON_8: Poseidon8<Testnet3> = Poseidon8::<Testnet3>::setup("AleoPoseidon8").expect("Failed to setup Poseidon8");
+    /// The Poseidon2 hash function, using a rate of 2.
+    pub static ref POSEIDON_2_V2: Poseidon2V2<Testnet3> = Poseidon2V2::<Testnet3>::setup("AleoPoseidon2V2").expect("Failed to setup Poseidon2V2");
 
     pub static ref CREDITS_PROVING_KEYS: IndexMap<String, Arc<MarlinProvingKey<Console>>> = {
         let mut map = IndexMap::new();
@@ -308,6 +312,17 @@ impl Network for Testnet3 {
         POSEIDON_8.hash(input)
     }
 
+    /// Returns the Poseidon2 hash with an input rate of 2.
+    fn hash_psd2v2(input: &[Field<Self>]) -> Result<Field<Self>> {
+        POSEIDON_2_V2.hash(input)
+    }
+
+    /// Returns the BLAKE3 hash of the given byte string, truncated to the data bits of a field element.
+    fn hash_blake3(input: &[bool]) -> Result<Field<Self>> {
+        let digest = Blake3::new().hash(input)?;
+        Field::from_bits_le(&digest[..Field::<Self>::size_in_data_bits()])
+    }
+
     /// Returns the extended Poseidon hash with an input rate of 2.
     fn hash_many_psd2(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
         POSEIDON_2.hash_many(input, num_outputs)
@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A case-preserving identifier, holding the canonical lowercase identifier
+/// for case-insensitive lookup, along with the original casing for display.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CasedIdentifier<N: Network> {
+    /// The canonical lowercase identifier.
+    canonical: Identifier<N>,
+    /// The identifier in its original casing.
+    display: String,
+}
+
+impl<N: Network> CasedIdentifier<N> {
+    /// Returns the canonical lowercase identifier.
+    pub const fn canonical(&self) -> &Identifier<N> {
+        &self.canonical
+    }
+
+    /// Returns the identifier in its original casing.
+    pub fn display(&self) -> &str {
+        &self.display
+    }
+}
+
+impl<N: Network> FromStr for CasedIdentifier<N> {
+    type Err = Error;
+
+    /// Reads in a case-preserving identifier from a string.
+    fn from_str(identifier: &str) -> Result<Self, Self::Err> {
+        // Ensure the identifier is valid in its original casing.
+        Identifier::<N>::from_str(identifier)?;
+        // Construct the canonical lowercase identifier.
+        let canonical = Identifier::from_str(&identifier.to_ascii_lowercase())?;
+        Ok(Self { canonical, display: identifier.to_string() })
+    }
+}
+
+impl<N: Network> Debug for CasedIdentifier<N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for CasedIdentifier<N> {
+    /// Prints the identifier in its original casing.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.display)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_cased_identifier() -> Result<()> {
+        let upper = CasedIdentifier::<CurrentNetwork>::from_str("Foo")?;
+        let lower = CasedIdentifier::<CurrentNetwork>::from_str("foo")?;
+
+        // Ensure the canonical identifiers are shared.
+        assert_eq!(upper.canonical(), lower.canonical());
+        assert_eq!(&Identifier::from_str("foo")?, upper.canonical());
+
+        // Ensure the display casing is preserved.
+        assert_eq!("Foo", upper.display());
+        assert_eq!("foo", lower.display());
+        assert_eq!("Foo", upper.to_string());
+        assert_ne!(upper, lower);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cased_identifier_fails() {
+        assert!(CasedIdentifier::<CurrentNetwork>::from_str("").is_err());
+        assert!(CasedIdentifier::<CurrentNetwork>::from_str("1Foo").is_err());
+        assert!(CasedIdentifier::<CurrentNetwork>::from_str("Foo-Bar").is_err());
+    }
+}
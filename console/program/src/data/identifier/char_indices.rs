@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Identifier<N> {
+    /// Returns the byte offset and character pairs of the identifier, mirroring `str::char_indices`.
+    pub fn char_indices(&self) -> Result<Vec<(usize, char)>> {
+        // Return the byte offset and character pairs of the decoded string.
+        Ok(self.try_to_string()?.char_indices().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::identifier::tests::sample_identifier_as_string;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_char_indices() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random fixed-length alphanumeric string, that always starts with an alphabetic character.
+            let expected_string = sample_identifier_as_string::<CurrentNetwork>(&mut rng)?;
+
+            let candidate = Identifier::<CurrentNetwork>::from_str(&expected_string)?;
+            assert_eq!(expected_string.char_indices().collect::<Vec<_>>(), candidate.char_indices()?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_char_indices_multibyte() -> Result<()> {
+        // Construct an identifier from a field containing multibyte characters.
+        let string = "a\u{e9}b\u{03b1}c";
+        let identifier = Identifier::<CurrentNetwork>(
+            Field::from_bits_le(&string.as_bytes().to_bits_le())?,
+            u8::try_from(string.len())?,
+        );
+        assert_eq!(vec![(0, 'a'), (1, '\u{e9}'), (3, 'b'), (4, '\u{03b1}'), (6, 'c')], identifier.char_indices()?);
+
+        // Ensure an identifier that is not valid UTF-8 fails.
+        let bytes = b"a\xffb";
+        let identifier =
+            Identifier::<CurrentNetwork>(Field::from_bits_le(&bytes.to_bits_le())?, u8::try_from(bytes.len())?);
+        assert!(identifier.char_indices().is_err());
+        Ok(())
+    }
+}
@@ -31,6 +31,13 @@ impl<N: Network> core::hash::Hash for Identifier<N> {
     }
 }
 
+impl<N: Network> ConstantTimeEq for Identifier<N> {
+    /// Returns `1` if `self` and `other` are equal, without branching on their values.
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
 impl<N: Network> Equal<Self> for Identifier<N> {
     type Output = Boolean<N>;
 
@@ -44,3 +51,27 @@ impl<N: Network> Equal<Self> for Identifier<N> {
         Boolean::new(self.0 != other.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::identifier::tests::sample_identifier;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_ct_eq() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let a = sample_identifier::<CurrentNetwork>(&mut rng)?;
+            let b = sample_identifier::<CurrentNetwork>(&mut rng)?;
+            assert!(bool::from(a.ct_eq(&a)));
+            assert_eq!(a == b, bool::from(a.ct_eq(&b)));
+        }
+        Ok(())
+    }
+}
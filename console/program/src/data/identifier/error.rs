@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The reason a string is not a valid identifier.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum IdentifierError {
+    #[error("Identifier cannot be empty")]
+    Empty,
+
+    #[error("Identifier must start with a letter, found '{0}'")]
+    InvalidStart(char),
+
+    #[error("Identifier must consist of letters, digits, and underscores, found '{0}'")]
+    InvalidCharacter(char),
+
+    #[error("Identifier '{0}' is a reserved keyword")]
+    Reserved(String),
+
+    #[error("Identifier is too large. Identifiers must be <= {max_bytes} bytes long, found {num_bytes} bytes")]
+    TooLarge { num_bytes: usize, max_bytes: usize },
+
+    #[error("{0}")]
+    Message(String),
+}
+
+impl<N: Network> Identifier<N> {
+    /// Checks that the given string is a valid identifier, returning the reason if it is not.
+    pub fn check_str(identifier: &str) -> Result<(), IdentifierError> {
+        // Ensure the identifier is not an empty string, and starts with an ASCII letter.
+        match identifier.chars().next() {
+            Some(character) if !character.is_ascii_alphabetic() => {
+                return Err(IdentifierError::InvalidStart(character))
+            }
+            Some(_) => (),
+            None => return Err(IdentifierError::Empty),
+        }
+
+        // Ensure the identifier consists of ASCII letters, ASCII digits, and underscores.
+        if let Some(character) = identifier.chars().find(|c| !c.is_ascii_alphanumeric() && *c != '_') {
+            return Err(IdentifierError::InvalidCharacter(character));
+        }
+
+        // Ensure identifier fits within the data capacity of the base field.
+        let max_bytes = Field::<N>::size_in_data_bits() / 8; // Note: This intentionally rounds down.
+        if identifier.len() > max_bytes {
+            return Err(IdentifierError::TooLarge { num_bytes: identifier.len(), max_bytes });
+        }
+        Ok(())
+    }
+}
+
+/// Validates each of the given names as an identifier, returning a result for every name, in order.
+/// This allows callers to report every invalid name in one pass, rather than stopping at the first.
+pub fn validate_all<N: Network>(names: &[&str]) -> Vec<Result<Identifier<N>, IdentifierError>> {
+    names
+        .iter()
+        .map(|name| {
+            Identifier::<N>::check_str(name)?;
+            Identifier::from_str(name).map_err(|error| IdentifierError::Message(error.to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_check_str() {
+        assert_eq!(Ok(()), Identifier::<CurrentNetwork>::check_str("foo_bar1"));
+        assert_eq!(Err(IdentifierError::Empty), Identifier::<CurrentNetwork>::check_str(""));
+        assert_eq!(Err(IdentifierError::InvalidStart('1')), Identifier::<CurrentNetwork>::check_str("1foo"));
+        assert_eq!(Err(IdentifierError::InvalidStart('_')), Identifier::<CurrentNetwork>::check_str("_foo"));
+        assert_eq!(Err(IdentifierError::InvalidCharacter('-')), Identifier::<CurrentNetwork>::check_str("foo-bar"));
+
+        let max_bytes = Field::<CurrentNetwork>::size_in_data_bits() / 8;
+        assert_eq!(
+            Err(IdentifierError::TooLarge { num_bytes: max_bytes + 1, max_bytes }),
+            Identifier::<CurrentNetwork>::check_str(&"a".repeat(max_bytes + 1))
+        );
+    }
+
+    #[test]
+    fn test_validate_all() -> Result<()> {
+        let names = ["foo", "", "bar_1", "1baz", "qux~", "quux"];
+        let results = validate_all::<CurrentNetwork>(&names);
+
+        // Ensure the results align index-for-index with the names.
+        assert_eq!(names.len(), results.len());
+        assert_eq!(Identifier::from_str("foo")?, results[0].clone()?);
+        assert_eq!(Err(IdentifierError::Empty), results[1]);
+        assert_eq!(Identifier::from_str("bar_1")?, results[2].clone()?);
+        assert_eq!(Err(IdentifierError::InvalidStart('1')), results[3]);
+        assert_eq!(Err(IdentifierError::InvalidCharacter('~')), results[4]);
+        assert_eq!(Identifier::from_str("quux")?, results[5].clone()?);
+
+        // Ensure an empty list returns an empty result.
+        assert!(validate_all::<CurrentNetwork>(&[]).is_empty());
+        Ok(())
+    }
+}
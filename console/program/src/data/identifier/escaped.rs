@@ -0,0 +1,144 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Identifier<N> {
+    /// Returns the identifier as a string, rendering backslashes, control characters,
+    /// and non-printable bytes as `\xNN` escapes. Unlike `Display`, this method never fails,
+    /// and is safe for printing potentially-malformed identifiers to a terminal.
+    pub fn to_display_escaped(&self) -> String {
+        // Convert the identifier to bytes, without failing on a partial trailing byte.
+        let bytes = self
+            .0
+            .to_bits_le()
+            .chunks(8)
+            .map(|byte| byte.iter().rev().fold(0u8, |acc, bit| (acc << 1) | *bit as u8))
+            .take(self.1 as usize)
+            .collect::<Vec<u8>>();
+
+        // Render each byte, escaping any byte that is not printable ASCII.
+        bytes.iter().fold(String::with_capacity(bytes.len()), |mut string, byte| {
+            match *byte {
+                b'\\' => string.push_str("\\x5c"),
+                0x20..=0x7e => string.push(*byte as char),
+                _ => string.push_str(&format!("\\x{byte:02x}")),
+            }
+            string
+        })
+    }
+
+    /// Reads in an identifier from a string containing `\xNN` and `\uNNNN` escapes (e.g. `\x66oo` for `foo`).
+    /// The escapes are decoded into characters, and the result is validated as an identifier.
+    pub fn from_escaped_str(identifier: &str) -> Result<Self> {
+        let mut decoded = String::with_capacity(identifier.len());
+        let mut characters = identifier.chars();
+        while let Some(character) = characters.next() {
+            // Copy any unescaped character.
+            if character != '\\' {
+                decoded.push(character);
+                continue;
+            }
+            // Determine the number of hex digits in the escape.
+            let num_digits = match characters.next() {
+                Some('x') => 2,
+                Some('u') => 4,
+                Some(other) => bail!("Invalid escape '\\{other}' in identifier '{identifier}'"),
+                None => bail!("Incomplete escape at the end of identifier '{identifier}'"),
+            };
+            // Decode the hex digits.
+            let digits = characters.by_ref().take(num_digits).collect::<String>();
+            if digits.len() != num_digits || !digits.chars().all(|digit| digit.is_ascii_hexdigit()) {
+                bail!("Malformed escape with digits '{digits}' in identifier '{identifier}'")
+            }
+            match char::from_u32(u32::from_str_radix(&digits, 16)?) {
+                Some(character) => decoded.push(character),
+                None => bail!("Invalid character escape '{digits}' in identifier '{identifier}'"),
+            }
+        }
+        // Parse the decoded identifier.
+        Self::from_str(&decoded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::identifier::tests::sample_identifier;
+    use snarkvm_console_network::Testnet3;
+
+    use core::fmt::Write;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_to_display_escaped() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Ensure a valid identifier renders the same as `Display`.
+            let identifier = sample_identifier::<CurrentNetwork>(&mut rng)?;
+            assert_eq!(identifier.to_string(), identifier.to_display_escaped());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_display_escaped_malformed() -> Result<()> {
+        // Construct an identifier from a field containing a control byte.
+        let bytes = b"ab\x07c";
+        let identifier =
+            Identifier::<CurrentNetwork>(Field::from_bits_le(&bytes.to_bits_le())?, u8::try_from(bytes.len())?);
+        assert_eq!("ab\\x07c", identifier.to_display_escaped());
+
+        // Construct an identifier from a field containing invalid UTF-8 and a backslash.
+        let bytes = b"\xff\\a";
+        let identifier =
+            Identifier::<CurrentNetwork>(Field::from_bits_le(&bytes.to_bits_le())?, u8::try_from(bytes.len())?);
+        assert!(write!(String::new(), "{identifier}").is_err());
+        assert_eq!("\\xff\\x5ca", identifier.to_display_escaped());
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_escaped_str() -> Result<()> {
+        assert_eq!("foo", Identifier::<CurrentNetwork>::from_escaped_str("\\x66oo")?.to_string());
+        assert_eq!("foo", Identifier::<CurrentNetwork>::from_escaped_str("f\\u006fo")?.to_string());
+        assert_eq!("foo_bar", Identifier::<CurrentNetwork>::from_escaped_str("foo\\x5Fbar")?.to_string());
+        assert_eq!("foo", Identifier::<CurrentNetwork>::from_escaped_str("foo")?.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_escaped_str_fails() {
+        // Must be a well-formed escape.
+        assert!(Identifier::<CurrentNetwork>::from_escaped_str("foo\\").is_err());
+        assert!(Identifier::<CurrentNetwork>::from_escaped_str("foo\\x").is_err());
+        assert!(Identifier::<CurrentNetwork>::from_escaped_str("foo\\x6").is_err());
+        assert!(Identifier::<CurrentNetwork>::from_escaped_str("foo\\xzz").is_err());
+        assert!(Identifier::<CurrentNetwork>::from_escaped_str("foo\\x+6").is_err());
+        assert!(Identifier::<CurrentNetwork>::from_escaped_str("foo\\u006").is_err());
+        assert!(Identifier::<CurrentNetwork>::from_escaped_str("foo\\n").is_err());
+        assert!(Identifier::<CurrentNetwork>::from_escaped_str("foo\\ud800").is_err());
+
+        // Must decode to a valid identifier.
+        assert!(Identifier::<CurrentNetwork>::from_escaped_str("\\x31foo").is_err());
+        assert!(Identifier::<CurrentNetwork>::from_escaped_str("foo\\x2dbar").is_err());
+        assert!(Identifier::<CurrentNetwork>::from_escaped_str("foo\\x5cx5c").is_err());
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Identifier<N> {
+    /// Reads in an identifier from a string, with a tighter byte budget than the field capacity.
+    ///
+    /// The effective limit is `min(max_bytes, field_capacity)`, allowing storage layers to enforce
+    /// shorter identifiers than the data capacity of a base field element.
+    pub fn from_str_max_bytes(identifier: &str, max_bytes: usize) -> Result<Self> {
+        // Ensure identifier fits within the given byte budget.
+        let capacity = Field::<N>::size_in_data_bits() / 8; // Note: This intentionally rounds down.
+        let max_bytes = max_bytes.min(capacity);
+        if identifier.len() > max_bytes {
+            bail!("Identifier exceeds the byte budget. Identifiers must be <= {max_bytes} bytes long")
+        }
+        // Parse the identifier.
+        Self::from_str(identifier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_from_str_max_bytes() -> Result<()> {
+        // Check identifiers within an 8-byte budget.
+        assert_eq!("foo", Identifier::<CurrentNetwork>::from_str_max_bytes("foo", 8)?.to_string());
+        assert_eq!("foo_bar1", Identifier::<CurrentNetwork>::from_str_max_bytes("foo_bar1", 8)?.to_string());
+
+        // Check a budget exceeding the field capacity is clamped.
+        let max_bytes = Field::<CurrentNetwork>::size_in_data_bits() / 8;
+        let identifier = "a".repeat(max_bytes);
+        assert_eq!(identifier, Identifier::<CurrentNetwork>::from_str_max_bytes(&identifier, usize::MAX)?.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str_max_bytes_fails() {
+        // Must fit within the byte budget.
+        let error = Identifier::<CurrentNetwork>::from_str_max_bytes("foo_bar12", 8).unwrap_err();
+        assert!(error.to_string().contains("byte budget"));
+        assert!(Identifier::<CurrentNetwork>::from_str_max_bytes("foo", 2).is_err());
+        assert!(Identifier::<CurrentNetwork>::from_str_max_bytes("a", 0).is_err());
+
+        // Must fit within the data capacity of a base field element.
+        let max_bytes = Field::<CurrentNetwork>::size_in_data_bits() / 8;
+        assert!(Identifier::<CurrentNetwork>::from_str_max_bytes(&"a".repeat(max_bytes + 1), usize::MAX).is_err());
+
+        // Must be a valid identifier.
+        assert!(Identifier::<CurrentNetwork>::from_str_max_bytes("1foo", 8).is_err());
+        assert!(Identifier::<CurrentNetwork>::from_str_max_bytes("", 8).is_err());
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Identifier<N> {
+    /// Initializes a numbered identifier of the form `{base}_{index}` (e.g. `field_0`, `field_1`, ...).
+    pub fn indexed(base: &str, index: usize) -> Result<Self> {
+        // Construct the numbered identifier.
+        let identifier = format!("{base}_{index}");
+        // Ensure the identifier fits within the data capacity of the base field.
+        let max_bytes = Field::<N>::size_in_data_bits() / 8; // Note: This intentionally rounds down.
+        if identifier.len() > max_bytes {
+            bail!("Identifier '{identifier}' is too large. Identifiers must be <= {max_bytes} bytes long")
+        }
+        // Parse the identifier.
+        Self::from_str(&identifier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_indexed() -> Result<()> {
+        for index in 0..10 {
+            let candidate = Identifier::<CurrentNetwork>::indexed("field", index)?;
+            assert_eq!(format!("field_{index}"), candidate.to_string());
+        }
+
+        let candidate = Identifier::<CurrentNetwork>::indexed("foo", 1234567890)?;
+        assert_eq!("foo_1234567890", candidate.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_indexed_fails() {
+        // Must fit within the data capacity of a base field element.
+        let max_bytes = Field::<CurrentNetwork>::size_in_data_bits() / 8;
+        let base = "a".repeat(max_bytes - 2);
+        assert!(Identifier::<CurrentNetwork>::indexed(&base, 0).is_ok());
+        assert!(Identifier::<CurrentNetwork>::indexed(&base, 10).is_err());
+        assert!(Identifier::<CurrentNetwork>::indexed(&"a".repeat(max_bytes), 0).is_err());
+
+        // Must be a valid identifier.
+        assert!(Identifier::<CurrentNetwork>::indexed("", 0).is_err());
+        assert!(Identifier::<CurrentNetwork>::indexed("1foo", 0).is_err());
+        assert!(Identifier::<CurrentNetwork>::indexed("foo-bar", 0).is_err());
+    }
+}
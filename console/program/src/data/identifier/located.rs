@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Identifier<N> {
+    /// Parses an identifier from a string, returning the byte offset of the failure if it is invalid.
+    ///
+    /// The offset is that of the offending character for `InvalidStart` and `InvalidCharacter`,
+    /// the first byte beyond the capacity for `TooLarge`, and `0` otherwise.
+    pub fn from_str_located(identifier: &str) -> Result<Self, (IdentifierError, usize)> {
+        if let Err(error) = Self::check_str(identifier) {
+            let offset = match &error {
+                IdentifierError::InvalidCharacter(_) => identifier
+                    .char_indices()
+                    .find(|(_, c)| !c.is_ascii_alphanumeric() && *c != '_')
+                    .map_or(0, |(offset, _)| offset),
+                IdentifierError::TooLarge { max_bytes, .. } => *max_bytes,
+                IdentifierError::Empty
+                | IdentifierError::InvalidStart(_)
+                | IdentifierError::Reserved(_)
+                | IdentifierError::Message(_) => 0,
+            };
+            return Err((error, offset));
+        }
+        Self::from_str(identifier).map_err(|error| (IdentifierError::Message(error.to_string()), 0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_from_str_located() {
+        assert_eq!(
+            Identifier::from_str("foo_bar").ok(),
+            Identifier::<CurrentNetwork>::from_str_located("foo_bar").ok()
+        );
+
+        // Ensure the offset points at the offending character.
+        assert_eq!(
+            Err((IdentifierError::InvalidCharacter('-'), 3)),
+            Identifier::<CurrentNetwork>::from_str_located("foo-bar")
+        );
+        assert_eq!(
+            Err((IdentifierError::InvalidCharacter('é'), 4)),
+            Identifier::<CurrentNetwork>::from_str_located("abcdé")
+        );
+        assert_eq!(
+            Err((IdentifierError::InvalidStart('1'), 0)),
+            Identifier::<CurrentNetwork>::from_str_located("1foo")
+        );
+        assert_eq!(Err((IdentifierError::Empty, 0)), Identifier::<CurrentNetwork>::from_str_located(""));
+
+        // Ensure the offset for an oversized identifier is the first byte beyond the capacity.
+        let max_bytes = Field::<CurrentNetwork>::size_in_data_bits() / 8;
+        assert_eq!(
+            Err((IdentifierError::TooLarge { num_bytes: max_bytes + 1, max_bytes }, max_bytes)),
+            Identifier::<CurrentNetwork>::from_str_located(&"a".repeat(max_bytes + 1))
+        );
+    }
+}
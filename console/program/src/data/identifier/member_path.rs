@@ -0,0 +1,54 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// Returns the qualified member path `{program}.{member}`, for display in diagnostics.
+///
+/// The program portion must itself be a valid identifier.
+pub fn format_member_path<N: Network>(program: &str, member: &Identifier<N>) -> Result<String> {
+    // Ensure the program portion is a valid identifier.
+    let program = Identifier::<N>::from_str(program)
+        .map_err(|error| anyhow!("Invalid program '{program}' in member path: {error}"))?;
+    // Join the program and member.
+    Ok(format!("{program}.{member}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_format_member_path() -> Result<()> {
+        let member = Identifier::<CurrentNetwork>::from_str("transfer")?;
+        assert_eq!("token.transfer", format_member_path("token", &member)?);
+        assert_eq!("credits_v2.transfer", format_member_path("credits_v2", &member)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_member_path_fails() -> Result<()> {
+        let member = Identifier::<CurrentNetwork>::from_str("transfer")?;
+        assert!(format_member_path("", &member).is_err());
+        assert!(format_member_path("1token", &member).is_err());
+        assert!(format_member_path("token.aleo", &member).is_err());
+        assert!(format_member_path("to-ken", &member).is_err());
+        Ok(())
+    }
+}
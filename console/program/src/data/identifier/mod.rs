@@ -15,14 +15,36 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod bytes;
+mod cased;
+mod char_indices;
 mod equal;
+mod error;
+mod escaped;
 mod from_bits;
 mod from_field;
+mod from_str_max_bytes;
+mod indexed;
+mod located;
+mod member_path;
+mod namespace;
+mod packing;
 mod parse;
+mod reserved;
 mod serialize;
+mod short_alias;
+mod sigil;
 mod size_in_bits;
 mod to_bits;
+mod to_color;
 mod to_field;
+mod validate_as;
+mod with_suffix;
+
+pub use cased::CasedIdentifier;
+pub use error::{validate_all, IdentifierError};
+pub use member_path::format_member_path;
+pub use namespace::RESERVED_PREFIXES;
+pub use reserved::RESERVED_KEYWORDS;
 
 use snarkvm_console_network::Network;
 use snarkvm_console_types::{prelude::*, Field};
@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The identifier prefixes reserved for system programs.
+pub const RESERVED_PREFIXES: &[&str] = &["aleo_", "credits_"];
+
+impl<N: Network> Identifier<N> {
+    /// Returns `true` if the identifier starts with a prefix reserved for system programs.
+    pub fn in_reserved_namespace(&self) -> Result<bool> {
+        // Decode the identifier as a string.
+        let identifier = self.try_to_string()?;
+        // Check the identifier against the reserved prefixes.
+        Ok(RESERVED_PREFIXES.iter().any(|prefix| identifier.starts_with(prefix)))
+    }
+
+    /// Reads in a user-defined identifier from a string, which must not be in a reserved namespace.
+    pub fn from_str_user(identifier: &str) -> Result<Self> {
+        // Parse the identifier.
+        let candidate = Self::from_str(identifier)?;
+        // Ensure the identifier is not in a reserved namespace.
+        if candidate.in_reserved_namespace()? {
+            bail!("Identifier '{identifier}' is in a reserved namespace ({})", RESERVED_PREFIXES.join(", "))
+        }
+        Ok(candidate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_in_reserved_namespace() -> Result<()> {
+        assert!(Identifier::<CurrentNetwork>::from_str("aleo_foo")?.in_reserved_namespace()?);
+        assert!(Identifier::<CurrentNetwork>::from_str("credits_foo")?.in_reserved_namespace()?);
+        assert!(!Identifier::<CurrentNetwork>::from_str("myfoo")?.in_reserved_namespace()?);
+        assert!(!Identifier::<CurrentNetwork>::from_str("aleo")?.in_reserved_namespace()?);
+        assert!(!Identifier::<CurrentNetwork>::from_str("my_aleo_foo")?.in_reserved_namespace()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str_user() -> Result<()> {
+        assert_eq!("myfoo", Identifier::<CurrentNetwork>::from_str_user("myfoo")?.to_string());
+        assert!(Identifier::<CurrentNetwork>::from_str_user("aleo_foo").is_err());
+        assert!(Identifier::<CurrentNetwork>::from_str_user("credits_foo").is_err());
+        assert!(Identifier::<CurrentNetwork>::from_str_user("1foo").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Identifier<N> {
+    /// Returns `true` if the field element of the given identifier follows the documented packing.
+    ///
+    /// The identifier bytes are packed in order, starting from the least-significant byte of the field
+    /// element. Thus, the little-endian encoding of the field element is the string bytes followed by
+    /// zero padding, and the big-endian encoding is zero padding followed by the reversed string bytes.
+    pub fn packing_check(identifier: &str) -> Result<bool> {
+        // Parse the identifier, and serialize its field element.
+        let field_bytes_le = Self::from_str(identifier)?.0.to_bytes_le()?;
+        let (data_le, padding_le) = field_bytes_le.split_at(identifier.len());
+
+        // Ensure the little-endian encoding is the string bytes, followed by zeros.
+        let is_valid_le = data_le == identifier.as_bytes() && padding_le.iter().all(|byte| *byte == 0);
+
+        // Ensure the big-endian encoding is zeros, followed by the reversed string bytes.
+        let field_bytes_be = field_bytes_le.iter().rev().copied().collect::<Vec<_>>();
+        let (padding_be, data_be) = field_bytes_be.split_at(field_bytes_be.len() - identifier.len());
+        let is_valid_be =
+            padding_be.iter().all(|byte| *byte == 0) && data_be.iter().rev().eq(identifier.as_bytes().iter());
+
+        Ok(is_valid_le && is_valid_be)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::identifier::tests::sample_identifier_as_string;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_packing_check() -> Result<()> {
+        // Ensure a 1-byte identifier follows the packing.
+        assert!(Identifier::<CurrentNetwork>::packing_check("a")?);
+        // Ensure multi-byte identifiers follow the packing.
+        assert!(Identifier::<CurrentNetwork>::packing_check("foo")?);
+        assert!(Identifier::<CurrentNetwork>::packing_check("transfer_public")?);
+        // Ensure a maximum-length identifier follows the packing.
+        let max_bytes = Field::<CurrentNetwork>::size_in_data_bits() / 8;
+        assert!(Identifier::<CurrentNetwork>::packing_check(&"z".repeat(max_bytes))?);
+
+        let mut rng = TestRng::default();
+        for _ in 0..ITERATIONS {
+            let identifier = sample_identifier_as_string::<CurrentNetwork>(&mut rng)?;
+            assert!(Identifier::<CurrentNetwork>::packing_check(&identifier)?);
+        }
+
+        // Ensure an invalid identifier fails to parse.
+        assert!(Identifier::<CurrentNetwork>::packing_check("1foo").is_err());
+        Ok(())
+    }
+}
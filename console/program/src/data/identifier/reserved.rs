@@ -0,0 +1,142 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The reserved keywords, which may not be used as names in a program.
+#[rustfmt::skip]
+pub const RESERVED_KEYWORDS: &[&str] = &[
+    // Mode
+    "const",
+    "constant",
+    "public",
+    "private",
+    // Literals
+    "address",
+    "boolean",
+    "field",
+    "group",
+    "i8",
+    "i16",
+    "i32",
+    "i64",
+    "i128",
+    "u8",
+    "u16",
+    "u32",
+    "u64",
+    "u128",
+    "scalar",
+    "string",
+    // Boolean
+    "true",
+    "false",
+    // Statements
+    "input",
+    "output",
+    "as",
+    "into",
+    // Record
+    "record",
+    "gates",
+    // Program
+    "function",
+    "struct",
+    "closure",
+    "program",
+    "aleo",
+    "self",
+    "storage",
+    "mapping",
+    "key",
+    "value",
+    // Reserved (catch all)
+    "global",
+    "return",
+    "break",
+    "assert",
+    "continue",
+    "let",
+    "if",
+    "else",
+    "while",
+    "for",
+    "switch",
+    "case",
+    "default",
+    "match",
+    "enum",
+    "struct",
+    "union",
+    "trait",
+    "impl",
+    "type",
+];
+
+impl<N: Network> Identifier<N> {
+    /// Returns `true` if the identifier is a reserved keyword.
+    pub fn is_reserved(&self) -> bool {
+        // Note: A malformed identifier cannot be decoded, and thus cannot be a keyword.
+        match self.try_to_string() {
+            Ok(identifier) => RESERVED_KEYWORDS.contains(&identifier.as_str()),
+            Err(_) => false,
+        }
+    }
+
+    /// Reads in an identifier from a string, which must not be a reserved keyword.
+    ///
+    /// Note: `from_str` accepts reserved keywords, as some (e.g. `aleo` and `gates`) are valid identifiers
+    /// outside of user-defined names. Use this method to parse a user-defined name.
+    pub fn from_str_unreserved(identifier: &str) -> Result<Self, IdentifierError> {
+        // Ensure the identifier is valid.
+        Self::check_str(identifier)?;
+        // Ensure the identifier is not a reserved keyword.
+        if RESERVED_KEYWORDS.contains(&identifier) {
+            return Err(IdentifierError::Reserved(identifier.to_string()));
+        }
+        Self::from_str(identifier).map_err(|error| IdentifierError::Message(error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_is_reserved() -> Result<()> {
+        for keyword in ["record", "function", "self", "u64", "aleo"] {
+            assert!(Identifier::<CurrentNetwork>::from_str(keyword)?.is_reserved());
+        }
+        for name in ["token", "records", "my_function", "self_1"] {
+            assert!(!Identifier::<CurrentNetwork>::from_str(name)?.is_reserved());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str_unreserved() -> Result<()> {
+        assert_eq!(Identifier::from_str("token")?, Identifier::<CurrentNetwork>::from_str_unreserved("token")?);
+        assert_eq!(
+            Err(IdentifierError::Reserved("record".to_string())),
+            Identifier::<CurrentNetwork>::from_str_unreserved("record")
+        );
+        assert_eq!(Err(IdentifierError::InvalidStart('1')), Identifier::<CurrentNetwork>::from_str_unreserved("1foo"));
+        Ok(())
+    }
+}
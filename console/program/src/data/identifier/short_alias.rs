@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Identifier<N> {
+    /// The number of hash bytes appended to a truncated alias.
+    const ALIAS_HASH_BYTES: usize = 2;
+
+    /// Returns a short alias for the identifier, for compact display.
+    ///
+    /// If the identifier is at most `max_len` characters, it is returned unchanged. Otherwise, it is
+    /// truncated to `max_len` characters, followed by an ellipsis and a short hash of the full identifier,
+    /// so that distinct identifiers sharing a prefix are given distinct aliases (e.g. `verylongna…a1b2`).
+    pub fn short_alias(&self, max_len: usize) -> Result<String> {
+        let identifier = self.try_to_string()?;
+        if identifier.len() <= max_len {
+            return Ok(identifier);
+        }
+        // Compute the hash suffix of the full identifier.
+        let hash = N::hash_psd2(&[self.0])?.to_bytes_le()?;
+        let suffix = hash.iter().take(Self::ALIAS_HASH_BYTES).map(|byte| format!("{byte:02x}")).collect::<String>();
+        // Note: Identifiers are ASCII, so slicing on a byte boundary is safe.
+        Ok(format!("{}…{suffix}", &identifier[..max_len]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_short_alias() -> Result<()> {
+        // Ensure a short identifier is returned unchanged.
+        let identifier = Identifier::<CurrentNetwork>::from_str("transfer")?;
+        assert_eq!("transfer", identifier.short_alias(8)?);
+        assert_eq!("transfer", identifier.short_alias(10)?);
+
+        // Ensure a long identifier is truncated, with a hash suffix.
+        let first = Identifier::<CurrentNetwork>::from_str("verylongname_first")?;
+        let second = Identifier::<CurrentNetwork>::from_str("verylongname_second")?;
+        let first_alias = first.short_alias(10)?;
+        let second_alias = second.short_alias(10)?;
+        assert!(first_alias.starts_with("verylongna…"));
+        assert!(second_alias.starts_with("verylongna…"));
+        assert_eq!("verylongna…".len() + 4, first_alias.len());
+
+        // Ensure the aliases are deterministic, and distinct for identifiers sharing a prefix.
+        assert_eq!(first_alias, first.short_alias(10)?);
+        assert_ne!(first_alias, second_alias);
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Identifier<N> {
+    /// Parses an identifier from a string prefixed with the given sigil (e.g. `$foo` or `@foo`).
+    /// The sigil is stripped, and the remainder is parsed as an identifier.
+    pub fn from_sigil_str(identifier: &str, sigil: char) -> Result<Self> {
+        match identifier.strip_prefix(sigil) {
+            Some(identifier) => Self::from_str(identifier),
+            None => bail!("Identifier '{identifier}' must start with the sigil '{sigil}'"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_from_sigil_str() -> Result<()> {
+        assert_eq!(Identifier::from_str("foo")?, Identifier::<CurrentNetwork>::from_sigil_str("$foo", '$')?);
+        assert_eq!(Identifier::from_str("foo_1")?, Identifier::<CurrentNetwork>::from_sigil_str("@foo_1", '@')?);
+
+        // Ensure a missing or mismatched sigil fails.
+        assert!(Identifier::<CurrentNetwork>::from_sigil_str("foo", '$').is_err());
+        assert!(Identifier::<CurrentNetwork>::from_sigil_str("@foo", '$').is_err());
+        assert!(Identifier::<CurrentNetwork>::from_sigil_str("$", '$').is_err());
+        // Ensure the remainder must be a valid identifier.
+        assert!(Identifier::<CurrentNetwork>::from_sigil_str("$1bad", '$').is_err());
+        assert!(Identifier::<CurrentNetwork>::from_sigil_str("$$foo", '$').is_err());
+        Ok(())
+    }
+}
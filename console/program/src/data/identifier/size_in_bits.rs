@@ -24,6 +24,12 @@ impl<N: Network> Identifier<N> {
         // Convert the size to bits (as a byte-aligned multiple).
         8 * self.1
     }
+
+    /// Returns the maximum number of bits of an identifier, for preallocating witness slots.
+    pub fn max_size_in_bits() -> usize {
+        // Convert the maximum number of bytes to bits (as a byte-aligned multiple).
+        8 * (Field::<N>::size_in_data_bits() / 8) // Note: This intentionally rounds down.
+    }
 }
 
 #[cfg(test)]
@@ -46,7 +52,23 @@ mod tests {
 
             let candidate = Identifier::<CurrentNetwork>::from_str(&expected_string)?;
             assert_eq!(expected_string.len() * 8, candidate.size_in_bits() as usize);
+            assert!(candidate.size_in_bits() as usize <= Identifier::<CurrentNetwork>::max_size_in_bits());
         }
         Ok(())
     }
+
+    #[test]
+    fn test_max_size_in_bits() -> Result<()> {
+        let max_size_in_bits = Identifier::<CurrentNetwork>::max_size_in_bits();
+        assert_eq!(0, max_size_in_bits % 8);
+        assert!(max_size_in_bits <= Field::<CurrentNetwork>::size_in_data_bits());
+
+        // Ensure an identifier at the maximum size is accepted.
+        let candidate = Identifier::<CurrentNetwork>::from_str(&"a".repeat(max_size_in_bits / 8))?;
+        assert_eq!(max_size_in_bits, candidate.size_in_bits() as usize);
+
+        // Ensure an identifier exceeding the maximum size is rejected.
+        assert!(Identifier::<CurrentNetwork>::from_str(&"a".repeat(max_size_in_bits / 8 + 1)).is_err());
+        Ok(())
+    }
 }
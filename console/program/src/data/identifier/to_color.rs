@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Identifier<N> {
+    /// The maximum value of each color channel, to avoid colors that are too bright.
+    const MAX_CHANNEL: u8 = 207;
+    /// The minimum value of each color channel, to avoid colors that are too dark.
+    const MIN_CHANNEL: u8 = 48;
+
+    /// Returns a deterministic RGB color for the identifier, for visual grouping in UIs.
+    /// The color is derived from the hash of the identifier, with each channel within brightness bounds.
+    pub fn to_color(&self) -> (u8, u8, u8) {
+        // Hash the identifier.
+        // Note: Hashing a single field element and serializing the output are infallible.
+        let bytes =
+            N::hash_psd2(&[self.0]).and_then(|hash| hash.to_bytes_le()).expect("Failed to hash a single field element");
+        // Map the first bytes of the hash into the channel bounds.
+        let range = u16::from(Self::MAX_CHANNEL - Self::MIN_CHANNEL) + 1;
+        let channel = |byte: u8| Self::MIN_CHANNEL + (u16::from(byte) % range) as u8;
+        (channel(bytes[0]), channel(bytes[1]), channel(bytes[2]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::identifier::tests::sample_identifier;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_to_color() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let mut colors = std::collections::HashSet::new();
+        for _ in 0..ITERATIONS {
+            let identifier = sample_identifier::<CurrentNetwork>(&mut rng)?;
+
+            // Ensure the color is deterministic.
+            let color = identifier.to_color();
+            assert_eq!(color, identifier.to_color());
+            assert_eq!(color, Identifier::<CurrentNetwork>::from_str(&identifier.to_string())?.to_color());
+
+            // Ensure the color is within the brightness bounds.
+            for channel in [color.0, color.1, color.2] {
+                assert!((48..=207).contains(&channel));
+            }
+            colors.insert(color);
+        }
+        // Ensure different identifiers usually produce different colors.
+        assert!(colors.len() > ITERATIONS * 9 / 10);
+        Ok(())
+    }
+}
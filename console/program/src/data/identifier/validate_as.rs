@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Identifier<N> {
+    /// Ensures the identifier is valid as a program name (e.g. `token` in `token.aleo`).
+    ///
+    /// # Requirements
+    /// The program name must not be a reserved keyword, which includes the network-level domain `aleo`.
+    pub fn validate_as_program_name(&self) -> Result<()> {
+        // Note: This mirrors the check on the program name in `Program::new`.
+        if self.is_reserved() {
+            bail!("Program name '{self}' is a reserved keyword")
+        }
+        Ok(())
+    }
+
+    /// Ensures the identifier is valid as a member name (e.g. a struct member or record entry).
+    ///
+    /// # Requirements
+    /// The member name must not be a reserved keyword.
+    pub fn validate_as_member_name(&self) -> Result<()> {
+        // Note: This mirrors the check on the member names in `Program::add_struct` and `Program::add_record`.
+        if self.is_reserved() {
+            bail!("Member name '{self}' is a reserved keyword")
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn check(name: &str, is_program_name: bool, is_member_name: bool) -> Result<()> {
+        let identifier = Identifier::<CurrentNetwork>::from_str(name)?;
+        assert_eq!(is_program_name, identifier.validate_as_program_name().is_ok(), "{name}");
+        assert_eq!(is_member_name, identifier.validate_as_member_name().is_ok(), "{name}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_as() -> Result<()> {
+        // Ensure names may be valid in both contexts.
+        check("token", true, true)?;
+        check("transfer_1", true, true)?;
+        check("Token", true, true)?;
+        check("my_aleo_token", true, true)?;
+        check("aleo1", true, true)?;
+        // Ensure names in a reserved namespace are valid in both contexts, as a program accepts them.
+        check("credits_pool", true, true)?;
+        check("aleo_token", true, true)?;
+        // Ensure reserved keywords are invalid in both contexts.
+        for keyword in ["aleo", "record", "function", "program", "self", "u64", "gates", "mapping"] {
+            check(keyword, false, false)?;
+        }
+        Ok(())
+    }
+}
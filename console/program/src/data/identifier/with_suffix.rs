@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Identifier<N> {
+    /// Returns a new identifier with the given suffix appended (e.g. `foo` with `_old` becomes `foo_old`).
+    pub fn with_suffix(&self, suffix: &str) -> Result<Self> {
+        // Ensure the suffix consists of ASCII letters, ASCII digits, and underscores.
+        if suffix.chars().any(|character| !character.is_ascii_alphanumeric() && character != '_') {
+            bail!("Identifier suffix '{suffix}' must consist of letters, digits, and underscores")
+        }
+        // Construct the suffixed identifier.
+        let identifier = format!("{self}{suffix}");
+        // Ensure the identifier fits within the data capacity of the base field.
+        let max_bytes = Field::<N>::size_in_data_bits() / 8; // Note: This intentionally rounds down.
+        if identifier.len() > max_bytes {
+            bail!("Identifier '{identifier}' is too large. Identifiers must be <= {max_bytes} bytes long")
+        }
+        // Parse the identifier.
+        Self::from_str(&identifier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_with_suffix() -> Result<()> {
+        let identifier = Identifier::<CurrentNetwork>::from_str("foo")?;
+        assert_eq!("foo_old", identifier.with_suffix("_old")?.to_string());
+        assert_eq!("foo2", identifier.with_suffix("2")?.to_string());
+        assert_eq!(identifier, identifier.with_suffix("")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_suffix_fails() -> Result<()> {
+        let identifier = Identifier::<CurrentNetwork>::from_str("foo")?;
+
+        // Must consist of letters, digits, and underscores.
+        assert!(identifier.with_suffix("-old").is_err());
+        assert!(identifier.with_suffix(".old").is_err());
+        assert!(identifier.with_suffix("\u{03b1}").is_err());
+
+        // Must fit within the data capacity of a base field element.
+        let max_bytes = Field::<CurrentNetwork>::size_in_data_bits() / 8;
+        assert!(identifier.with_suffix(&"_".repeat(max_bytes - 3)).is_ok());
+        assert!(identifier.with_suffix(&"_".repeat(max_bytes - 2)).is_err());
+        Ok(())
+    }
+}
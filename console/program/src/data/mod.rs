@@ -18,16 +18,32 @@ mod ciphertext;
 pub use ciphertext::Ciphertext;
 
 pub(super) mod identifier;
-pub use identifier::Identifier;
+pub use identifier::{
+    format_member_path,
+    validate_all,
+    CasedIdentifier,
+    Identifier,
+    IdentifierError,
+    RESERVED_KEYWORDS,
+};
 
 mod literal;
 pub use literal::Literal;
 
 mod plaintext;
-pub use plaintext::Plaintext;
+pub use plaintext::{PathedChange, Plaintext};
 
 mod record;
-pub use record::{Balance, Entry, Owner, Record};
+pub use record::{
+    Balance,
+    Entry,
+    EntryDisclosure,
+    Owner,
+    Record,
+    RecordEntriesPath,
+    RecordEntriesTree,
+    RECORD_ENTRIES_DEPTH,
+};
 
 mod register;
 pub use register::Register;
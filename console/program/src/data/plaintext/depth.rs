@@ -0,0 +1,51 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Plaintext<N> {
+    /// Returns the nesting depth of the plaintext, where a literal has a depth of 0,
+    /// and a struct or array has a depth of one more than its deepest member.
+    pub fn depth(&self) -> usize {
+        match self {
+            Self::Literal(..) => 0,
+            Self::Struct(members, ..) => 1 + members.values().map(Self::depth).max().unwrap_or(0),
+            Self::Array(elements, ..) => 1 + elements.iter().map(Self::depth).max().unwrap_or(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_depth() -> Result<()> {
+        for (expected, plaintext) in [
+            (0, "5u8"),
+            (1, "{ a: 5u8, b: true }"),
+            (1, "[1u8, 2u8]"),
+            (2, "{ a: 5u8, b: { c: true } }"),
+            (3, "{ a: [{ b: 1field }], c: 2field }"),
+        ] {
+            assert_eq!(expected, Plaintext::<CurrentNetwork>::from_str(plaintext)?.depth());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,186 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A difference between two plaintexts at a given path.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PathedChange<N: Network> {
+    /// The path to the differing plaintext, i.e. `token.owner` or `ids[1]`.
+    /// The path is empty if the plaintexts differ at the root.
+    path: String,
+    /// The plaintext at the path before the change, if it exists.
+    before: Option<Plaintext<N>>,
+    /// The plaintext at the path after the change, if it exists.
+    after: Option<Plaintext<N>>,
+}
+
+impl<N: Network> PathedChange<N> {
+    /// Returns the path to the differing plaintext.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the plaintext at the path before the change, if it exists.
+    pub const fn before(&self) -> Option<&Plaintext<N>> {
+        self.before.as_ref()
+    }
+
+    /// Returns the plaintext at the path after the change, if it exists.
+    pub const fn after(&self) -> Option<&Plaintext<N>> {
+        self.after.as_ref()
+    }
+}
+
+impl<N: Network> Debug for PathedChange<N> {
+    /// Prints the change as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for PathedChange<N> {
+    /// Prints the change as a string, i.e. `token.amount: 5u64 -> 6u64`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let format = |plaintext: &Option<Plaintext<N>>| match plaintext {
+            Some(plaintext) => plaintext.to_string(),
+            None => "(none)".to_string(),
+        };
+        write!(f, "{}: {} -> {}", self.path, format(&self.before), format(&self.after))
+    }
+}
+
+impl<N: Network> Plaintext<N> {
+    /// Returns the differences between `self` and `other`, as a list of changes at the most specific path.
+    /// Members and elements that exist on only one side are reported with `None` on the other side.
+    pub fn diff(&self, other: &Self) -> Vec<PathedChange<N>> {
+        let mut changes = Vec::new();
+        self.diff_internal(other, String::new(), &mut changes);
+        changes
+    }
+
+    /// Appends the differences between `self` and `other` at the given path to `changes`.
+    fn diff_internal(&self, other: &Self, path: String, changes: &mut Vec<PathedChange<N>>) {
+        // Returns the path to the given member.
+        let member_path = |identifier: &Identifier<N>| match path.is_empty() {
+            true => identifier.to_string(),
+            false => format!("{path}.{identifier}"),
+        };
+
+        match (self, other) {
+            (Self::Literal(a, ..), Self::Literal(b, ..)) => {
+                if a != b {
+                    changes.push(PathedChange { path, before: Some(self.clone()), after: Some(other.clone()) });
+                }
+            }
+            (Self::Struct(a, ..), Self::Struct(b, ..)) => {
+                // Compare the members in `self`, in order.
+                for (identifier, plaintext) in a {
+                    match b.get(identifier) {
+                        Some(candidate) => plaintext.diff_internal(candidate, member_path(identifier), changes),
+                        None => changes.push(PathedChange {
+                            path: member_path(identifier),
+                            before: Some(plaintext.clone()),
+                            after: None,
+                        }),
+                    }
+                }
+                // Report the members that only exist in `other`.
+                for (identifier, candidate) in b.iter().filter(|(identifier, _)| !a.contains_key(*identifier)) {
+                    changes.push(PathedChange {
+                        path: member_path(identifier),
+                        before: None,
+                        after: Some(candidate.clone()),
+                    });
+                }
+            }
+            (Self::Array(a, ..), Self::Array(b, ..)) => {
+                for index in 0..a.len().max(b.len()) {
+                    let element_path = format!("{path}[{index}]");
+                    match (a.get(index), b.get(index)) {
+                        (Some(element), Some(candidate)) => element.diff_internal(candidate, element_path, changes),
+                        (before, after) => changes.push(PathedChange {
+                            path: element_path,
+                            before: before.cloned(),
+                            after: after.cloned(),
+                        }),
+                    }
+                }
+            }
+            // The plaintexts are of different kinds.
+            _ => changes.push(PathedChange { path, before: Some(self.clone()), after: Some(other.clone()) }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_diff_equal() -> Result<()> {
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("{ a: 1u8, b: { c: [true, false] } }")?;
+        assert!(plaintext.diff(&plaintext).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_diff_literal() -> Result<()> {
+        let a = Plaintext::<CurrentNetwork>::from_str("1u8")?;
+        let b = Plaintext::<CurrentNetwork>::from_str("2u8")?;
+
+        let changes = a.diff(&b);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path(), "");
+        assert_eq!(changes[0].before(), Some(&a));
+        assert_eq!(changes[0].after(), Some(&b));
+        Ok(())
+    }
+
+    #[test]
+    fn test_diff_nested() -> Result<()> {
+        let a = Plaintext::<CurrentNetwork>::from_str(
+            "{ token: { owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah, amount: 5u64 }, ids: [1u8, 2u8], memo: 0field }",
+        )?;
+        let b = Plaintext::<CurrentNetwork>::from_str(
+            "{ token: { owner: aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add, amount: 5u64 }, ids: [1u8, 3u8, 4u8], flag: true }",
+        )?;
+
+        let changes = a.diff(&b).iter().map(|change| change.to_string()).collect::<Vec<_>>();
+        assert_eq!(changes, [
+            "token.owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah -> aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add",
+            "ids[1]: 2u8 -> 3u8",
+            "ids[2]: (none) -> 4u8",
+            "memo: 0field -> (none)",
+            "flag: (none) -> true",
+        ]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_diff_mismatched_kinds() -> Result<()> {
+        let a = Plaintext::<CurrentNetwork>::from_str("{ a: 1u8 }")?;
+        let b = Plaintext::<CurrentNetwork>::from_str("{ a: { b: 1u8 } }")?;
+
+        let changes = a.diff(&b);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path(), "a");
+        Ok(())
+    }
+}
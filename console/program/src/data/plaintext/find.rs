@@ -24,7 +24,7 @@ impl<N: Network> Plaintext<N> {
 
         match self {
             // Halts if the value is not a struct.
-            Self::Literal(..) => bail!("'{self}' is not a struct"),
+            Self::Literal(..) | Self::Array(..) => bail!("'{self}' is not a struct"),
             // Retrieve the value of the member (from the value).
             Self::Struct(members, ..) => {
                 // Initialize the members starting from the top-level.
@@ -39,7 +39,9 @@ impl<N: Network> Plaintext<N> {
                     if i != path.len() - 1 {
                         match submembers.get(identifier) {
                             // Halts if the member is not a struct.
-                            Some(Self::Literal(..)) => bail!("'{identifier}' must be a struct"),
+                            Some(Self::Literal(..)) | Some(Self::Array(..)) => {
+                                bail!("'{identifier}' must be a struct")
+                            }
                             // Retrieve the member and update `submembers` for the next iteration.
                             Some(Self::Struct(members, ..)) => submembers = members,
                             // Halts if the member does not exist.
@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Plaintext<N> {
+    /// Returns the canonical JSON encoding of the plaintext.
+    ///
+    /// The encoding is defined as follows:
+    ///   - A literal is a JSON string containing the literal, including its type suffix (e.g. `"5u64"`).
+    ///   - A struct is a JSON object, with its members in declaration order.
+    ///   - An array is a JSON array, with its elements in order.
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Self::Literal(literal, ..) => serde_json::Value::String(literal.to_string()),
+            Self::Struct(members, ..) => serde_json::Value::Object(
+                members.iter().map(|(identifier, plaintext)| (identifier.to_string(), plaintext.to_json())).collect(),
+            ),
+            Self::Array(elements, ..) => serde_json::Value::Array(elements.iter().map(|e| e.to_json()).collect()),
+        }
+    }
+
+    /// Returns the plaintext from its canonical JSON encoding.
+    pub fn from_json(json: &serde_json::Value) -> Result<Self> {
+        match json {
+            serde_json::Value::String(literal) => Ok(Self::from(Literal::from_str(literal)?)),
+            serde_json::Value::Object(members) => {
+                // Ensure the struct has at least one member.
+                ensure!(!members.is_empty(), "Found a plaintext struct with no members");
+                // Ensure the number of members is within `N::MAX_DATA_ENTRIES`.
+                ensure!(
+                    members.len() <= N::MAX_DATA_ENTRIES,
+                    "Found a plaintext that exceeds size ({})",
+                    members.len()
+                );
+                // Parse the members.
+                let members = members
+                    .iter()
+                    .map(|(identifier, plaintext)| Ok((Identifier::from_str(identifier)?, Self::from_json(plaintext)?)))
+                    .collect::<Result<IndexMap<_, _>>>()?;
+                // Return the struct.
+                Ok(Self::Struct(members, Default::default()))
+            }
+            serde_json::Value::Array(elements) => {
+                // Ensure the array has at least one element.
+                ensure!(!elements.is_empty(), "Found a plaintext array with no elements");
+                // Ensure the number of elements is within `N::MAX_ARRAY_ELEMENTS`.
+                ensure!(
+                    elements.len() <= N::MAX_ARRAY_ELEMENTS as usize,
+                    "Found a plaintext array that exceeds size ({})",
+                    elements.len()
+                );
+                // Parse the elements.
+                let elements = elements.iter().map(Self::from_json).collect::<Result<Vec<_>>>()?;
+                // Return the array.
+                Ok(Self::Array(elements, Default::default()))
+            }
+            _ => bail!("Invalid JSON for a plaintext: expected a string, object, or array"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_json() -> Result<()> {
+        let expected = Plaintext::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah, token: { amount: 100u64, ids: [1u8, 2u8] }, memo: \"hello\" }",
+        )?;
+
+        // Check the encoding.
+        let json = expected.to_json();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "owner": "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah",
+                "token": { "amount": "100u64", "ids": ["1u8", "2u8"] },
+                "memo": "\"hello\"",
+            })
+        );
+        // Ensure the member order is preserved.
+        assert_eq!(
+            json.to_string(),
+            r#"{"owner":"aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah","token":{"amount":"100u64","ids":["1u8","2u8"]},"memo":"\"hello\""}"#
+        );
+
+        // Check the round trip.
+        assert_eq!(expected, Plaintext::from_json(&json)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_fails() {
+        assert!(Plaintext::<CurrentNetwork>::from_json(&serde_json::json!(5)).is_err());
+        assert!(Plaintext::<CurrentNetwork>::from_json(&serde_json::json!({})).is_err());
+        assert!(Plaintext::<CurrentNetwork>::from_json(&serde_json::json!([])).is_err());
+        assert!(Plaintext::<CurrentNetwork>::from_json(&serde_json::json!(null)).is_err());
+        assert!(Plaintext::<CurrentNetwork>::from_json(&serde_json::json!("5")).is_err());
+        assert!(Plaintext::<CurrentNetwork>::from_json(&serde_json::json!({ "1a": "5u8" })).is_err());
+    }
+}
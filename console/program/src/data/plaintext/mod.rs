@@ -14,12 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod diff;
+pub use diff::PathedChange;
+
 mod bytes;
+mod depth;
 mod encrypt;
 mod equal;
 mod find;
 mod from_bits;
 mod from_fields;
+mod json;
 mod num_randomizers;
 mod parse;
 mod serialize;
@@ -40,6 +45,8 @@ pub enum Plaintext<N: Network> {
     Literal(Literal<N>, OnceCell<Vec<bool>>),
     /// A struct.
     Struct(IndexMap<Identifier<N>, Plaintext<N>>, OnceCell<Vec<bool>>),
+    /// An array.
+    Array(Vec<Plaintext<N>>, OnceCell<Vec<bool>>),
 }
 
 impl<N: Network> From<Literal<N>> for Plaintext<N> {
@@ -153,6 +160,15 @@ mod tests {
             OnceCell::new(),
         );
         assert_eq!(value.to_bits_le(), Plaintext::<CurrentNetwork>::from_bits_le(&value.to_bits_le())?.to_bits_le());
+
+        let value = Plaintext::<CurrentNetwork>::Array(
+            vec![
+                Plaintext::<CurrentNetwork>::from_str("{ a: true, b: [1u8, 2u8] }")?,
+                Plaintext::<CurrentNetwork>::from_str("{ a: false, b: [3u8, 4u8] }")?,
+            ],
+            OnceCell::new(),
+        );
+        assert_eq!(value.to_bits_le(), Plaintext::<CurrentNetwork>::from_bits_le(&value.to_bits_le())?.to_bits_le());
         Ok(())
     }
 }
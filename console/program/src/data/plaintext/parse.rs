@@ -62,6 +62,28 @@ impl<N: Network> Parser for Plaintext<N> {
             Ok((string, Plaintext::Struct(IndexMap::from_iter(members.into_iter()), Default::default())))
         }
 
+        /// Parses a plaintext as an array: `[plaintext_0, ..., plaintext_n]`.
+        fn parse_array<N: Network>(string: &str) -> ParserResult<Plaintext<N>> {
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the "[" from the string.
+            let (string, _) = tag("[")(string)?;
+            // Parse the elements.
+            let (string, elements) = map_res(separated_list1(tag(","), Plaintext::parse), |elements: Vec<_>| {
+                // Ensure the number of elements is within `N::MAX_ARRAY_ELEMENTS`.
+                match elements.len() <= N::MAX_ARRAY_ELEMENTS as usize {
+                    true => Ok(elements),
+                    false => Err(error(format!("Found a plaintext array that exceeds size ({})", elements.len()))),
+                }
+            })(string)?;
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the ']' from the string.
+            let (string, _) = tag("]")(string)?;
+            // Output the plaintext.
+            Ok((string, Plaintext::Array(elements, Default::default())))
+        }
+
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse to determine the plaintext (order matters).
@@ -70,6 +92,8 @@ impl<N: Network> Parser for Plaintext<N> {
             map(Literal::parse, |literal| Self::Literal(literal, Default::default())),
             // Parse a plaintext struct.
             parse_struct,
+            // Parse a plaintext array.
+            parse_array,
         ))(string)
     }
 }
@@ -131,7 +155,7 @@ impl<N: Network> Plaintext<N> {
                             // Print the member with a comma.
                             false => write!(f, "\n{:indent$}{name}: {literal},", "", indent = (depth + 1) * INDENT),
                         },
-                        Self::Struct(..) => {
+                        Self::Struct(..) | Self::Array(..) => {
                             // Print the member name.
                             write!(f, "\n{:indent$}{name}: ", "", indent = (depth + 1) * INDENT)?;
                             // Print the member.
@@ -147,6 +171,32 @@ impl<N: Network> Plaintext<N> {
                     }
                 })
             }
+            // Prints the array, i.e. [ 10i64, 198u64 ]
+            Self::Array(array, ..) => {
+                // Print the opening bracket.
+                write!(f, "[")?;
+                // Print the elements.
+                array.iter().enumerate().try_for_each(|(i, plaintext)| {
+                    match plaintext {
+                        // Print the literal.
+                        Self::Literal(literal, ..) => {
+                            write!(f, "\n{:indent$}{literal}", "", indent = (depth + 1) * INDENT)?
+                        }
+                        Self::Struct(..) | Self::Array(..) => {
+                            // Print the indentation.
+                            write!(f, "\n{:indent$}", "", indent = (depth + 1) * INDENT)?;
+                            // Print the element.
+                            plaintext.fmt_internal(f, depth + 1)?;
+                        }
+                    }
+                    match i == array.len() - 1 {
+                        // Print the closing bracket after the last element.
+                        true => write!(f, "\n{:indent$}]", "", indent = depth * INDENT),
+                        // Print the element with a comma.
+                        false => write!(f, ","),
+                    }
+                })
+            }
         }
     }
 }
@@ -213,6 +263,46 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_array() -> Result<()> {
+        // Sanity check.
+        let expected = r"[
+  1u8,
+  2u8
+]";
+        let (remainder, candidate) = Plaintext::<CurrentNetwork>::parse("[1u8, 2u8]")?;
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+
+        let expected = r"{
+  foo: [
+    {
+      bar: 1u8
+    },
+    {
+      bar: 2u8
+    }
+  ],
+  baz: [
+    true,
+    false
+  ]
+}";
+        let (remainder, candidate) =
+            Plaintext::<CurrentNetwork>::parse("{ foo: [{ bar: 1u8 }, { bar: 2u8 }], baz: [true, false] }")?;
+        println!("\nExpected: {expected}\n\nFound: {candidate}\n");
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+
+        // Must be non-empty.
+        assert!(Plaintext::<CurrentNetwork>::parse("[]").is_err());
+        // Must not exceed the maximum number of elements.
+        let elements = vec!["1u8"; CurrentNetwork::MAX_ARRAY_ELEMENTS as usize + 1].join(", ");
+        assert!(Plaintext::<CurrentNetwork>::parse(&format!("[{elements}]")).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_parse_fails() {
         // Must be non-empty.
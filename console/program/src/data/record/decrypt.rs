@@ -70,20 +70,7 @@ impl<N: Network> Record<N, Ciphertext<N>> {
             // Retrieve the randomizers for this entry.
             let randomizers = &randomizers[index..index + num_randomizers];
             // Decrypt the entry.
-            let entry = match entry {
-                // Constant entries do not need to be decrypted.
-                Entry::Constant(plaintext) => Entry::Constant(plaintext.clone()),
-                // Public entries do not need to be decrypted.
-                Entry::Public(plaintext) => Entry::Public(plaintext.clone()),
-                // Private entries are decrypted with the given randomizers.
-                Entry::Private(private) => Entry::Private(Plaintext::from_fields(
-                    &private
-                        .iter()
-                        .zip_eq(randomizers)
-                        .map(|(ciphertext, randomizer)| *ciphertext - randomizer)
-                        .collect::<Vec<_>>(),
-                )?),
-            };
+            let entry = Self::decrypt_entry_with_randomizers(entry, randomizers)?;
             // Insert the decrypted entry.
             if decrypted_data.insert(*id, entry).is_some() {
                 bail!("Duplicate identifier in record: {}", id);
@@ -95,6 +82,71 @@ impl<N: Network> Record<N, Ciphertext<N>> {
         // Return the decrypted record.
         Self::from_plaintext(owner, gates, decrypted_data, self.nonce)
     }
+
+    /// Decrypts the given entry into plaintext using the given randomizers.
+    fn decrypt_entry_with_randomizers(
+        entry: &Entry<N, Ciphertext<N>>,
+        randomizers: &[Field<N>],
+    ) -> Result<Entry<N, Plaintext<N>>> {
+        match entry {
+            // Constant entries do not need to be decrypted.
+            Entry::Constant(plaintext) => Ok(Entry::Constant(plaintext.clone())),
+            // Public entries do not need to be decrypted.
+            Entry::Public(plaintext) => Ok(Entry::Public(plaintext.clone())),
+            // Private entries are decrypted with the given randomizers.
+            Entry::Private(private) => Ok(Entry::Private(Plaintext::from_fields(
+                &private
+                    .iter()
+                    .zip_eq(randomizers)
+                    .map(|(ciphertext, randomizer)| *ciphertext - randomizer)
+                    .collect::<Vec<_>>(),
+            )?)),
+        }
+    }
+}
+
+impl<N: Network> Record<N, Ciphertext<N>> {
+    /// Decrypts the data entries of `self` into plaintext one at a time, using the given view key.
+    /// Unlike `decrypt`, the decrypted record is never materialized; each entry is decrypted
+    /// only when the iterator is advanced, which suits records with large data payloads.
+    pub fn decrypt_entries<'a>(
+        &'a self,
+        view_key: &ViewKey<N>,
+    ) -> Result<impl 'a + Iterator<Item = Result<(Identifier<N>, Entry<N, Plaintext<N>>)>>> {
+        // Compute the record view key.
+        let record_view_key = (self.nonce * **view_key).to_x_coordinate();
+        // Decrypt the entries.
+        self.decrypt_entries_symmetric(&record_view_key)
+    }
+
+    /// Decrypts the data entries of `self` into plaintext one at a time, using the given record view key.
+    pub fn decrypt_entries_symmetric<'a>(
+        &'a self,
+        record_view_key: &Field<N>,
+    ) -> Result<impl 'a + Iterator<Item = Result<(Identifier<N>, Entry<N, Plaintext<N>>)>>> {
+        // Determine the number of randomizers needed to encrypt the record.
+        let num_randomizers = self.num_randomizers()?;
+        // Prepare a randomizer for each field element.
+        let randomizers = N::hash_many_psd8(&[N::encryption_domain(), *record_view_key], num_randomizers);
+
+        // Skip the randomizers for the owner and gates, if they are private.
+        let mut index = usize::from(self.owner.is_private()) + usize::from(self.gates.is_private());
+
+        // Decrypt each entry on demand.
+        Ok(self.data.iter().map(move |(id, entry)| {
+            // Retrieve the number of randomizers for this entry.
+            let num_randomizers = entry.num_randomizers()? as usize;
+            // Retrieve the randomizers for this entry.
+            let entry_randomizers = match randomizers.get(index..index + num_randomizers) {
+                Some(entry_randomizers) => entry_randomizers,
+                None => bail!("Missing randomizers to decrypt the record entry '{id}'"),
+            };
+            // Increment the index.
+            index += num_randomizers;
+            // Decrypt the entry.
+            Ok((*id, Self::decrypt_entry_with_randomizers(entry, entry_randomizers)?))
+        }))
+    }
 }
 
 #[cfg(test)]
@@ -124,6 +176,8 @@ mod tests {
                 vec![
                     (Identifier::from_str("a")?, Entry::Private(Plaintext::from(Literal::Field(Field::rand(rng))))),
                     (Identifier::from_str("b")?, Entry::Private(Plaintext::from(Literal::Scalar(Scalar::rand(rng))))),
+                    (Identifier::from_str("c")?, Entry::Public(Plaintext::from(Literal::Boolean(Boolean::rand(rng))))),
+                    (Identifier::from_str("d")?, Entry::Private(Plaintext::from(Literal::Group(Group::rand(rng))))),
                 ]
                 .into_iter(),
             ),
@@ -133,6 +187,9 @@ mod tests {
         let ciphertext = record.encrypt(randomizer)?;
         // Decrypt the record.
         assert_eq!(record, ciphertext.decrypt(&view_key)?);
+        // Decrypt the record entries one at a time.
+        let entries = ciphertext.decrypt_entries(&view_key)?.collect::<Result<IndexMap<_, _>>>()?;
+        assert_eq!(record.data(), &entries);
         Ok(())
     }
 
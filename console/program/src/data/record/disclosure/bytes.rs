@@ -0,0 +1,47 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> FromBytes for EntryDisclosure<N> {
+    /// Reads the entry disclosure from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the identifier.
+        let identifier = FromBytes::read_le(&mut reader)?;
+        // Read the entry.
+        let entry = FromBytes::read_le(&mut reader)?;
+        // Read the leaf salt.
+        let leaf_salt = FromBytes::read_le(&mut reader)?;
+        // Read the path.
+        let path = FromBytes::read_le(&mut reader)?;
+        // Return the entry disclosure.
+        Ok(Self::from(identifier, entry, leaf_salt, path))
+    }
+}
+
+impl<N: Network> ToBytes for EntryDisclosure<N> {
+    /// Writes the entry disclosure to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the identifier.
+        self.identifier.write_le(&mut writer)?;
+        // Write the entry.
+        self.entry.write_le(&mut writer)?;
+        // Write the leaf salt.
+        self.leaf_salt.write_le(&mut writer)?;
+        // Write the path.
+        self.path.write_le(&mut writer)
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod bytes;
+mod verify;
+
+use crate::{Entry, Identifier, Plaintext};
+use snarkvm_console_collections::merkle_tree::MerklePath;
+use snarkvm_console_network::{prelude::*, BHPMerkleTree};
+use snarkvm_console_types::Field;
+
+/// The depth of the Merkle tree for the entries of a record.
+pub const RECORD_ENTRIES_DEPTH: u8 = 6;
+
+/// The Merkle tree for the entries of a record.
+pub type RecordEntriesTree<N> = BHPMerkleTree<N, RECORD_ENTRIES_DEPTH>;
+/// The Merkle path for an entry in a record.
+pub type RecordEntriesPath<N> = MerklePath<N, RECORD_ENTRIES_DEPTH>;
+
+/// The entry disclosure proves the membership of a single record entry in the entries root of a record,
+/// without revealing the other entries of the record.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EntryDisclosure<N: Network> {
+    /// The name of the entry.
+    identifier: Identifier<N>,
+    /// The entry.
+    entry: Entry<N, Plaintext<N>>,
+    /// The salt blinding the Merkle leaf of the entry.
+    leaf_salt: Field<N>,
+    /// The Merkle path for the entry.
+    path: RecordEntriesPath<N>,
+}
+
+impl<N: Network> EntryDisclosure<N> {
+    /// Initializes a new entry disclosure.
+    pub const fn from(
+        identifier: Identifier<N>,
+        entry: Entry<N, Plaintext<N>>,
+        leaf_salt: Field<N>,
+        path: RecordEntriesPath<N>,
+    ) -> Self {
+        Self { identifier, entry, leaf_salt, path }
+    }
+
+    /// Returns the name of the entry.
+    pub const fn identifier(&self) -> &Identifier<N> {
+        &self.identifier
+    }
+
+    /// Returns the entry.
+    pub const fn entry(&self) -> &Entry<N, Plaintext<N>> {
+        &self.entry
+    }
+
+    /// Returns the salt blinding the Merkle leaf of the entry.
+    pub const fn leaf_salt(&self) -> &Field<N> {
+        &self.leaf_salt
+    }
+
+    /// Returns the Merkle path for the entry.
+    pub const fn path(&self) -> &RecordEntriesPath<N> {
+        &self.path
+    }
+
+    /// Returns the Merkle leaf for the given entry, as `(leaf_salt || identifier || entry)`.
+    /// The identifier is encoded as a fixed-width field element, so the leaf is unambiguous.
+    pub fn to_leaf(
+        leaf_salt: &Field<N>,
+        identifier: &Identifier<N>,
+        entry: &Entry<N, Plaintext<N>>,
+    ) -> Result<Vec<bool>> {
+        let mut leaf = leaf_salt.to_bits_le();
+        leaf.extend(identifier.to_field()?.to_bits_le().into_iter().take(Field::<N>::size_in_data_bits()));
+        leaf.extend(entry.to_bits_le());
+        Ok(leaf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_record_entries_depth() {
+        // Ensure the tree fits the owner, the gates, and the maximum number of data entries.
+        assert!(2 + CurrentNetwork::MAX_DATA_ENTRIES <= 2usize.pow(RECORD_ENTRIES_DEPTH as u32));
+    }
+
+    #[test]
+    fn test_to_leaf_is_unambiguous() -> Result<()> {
+        let leaf_salt = Field::<CurrentNetwork>::from_u8(1);
+        let entry = Entry::from_str("1u8.private")?;
+
+        // Ensure the identifier is encoded with a fixed width.
+        let short = EntryDisclosure::to_leaf(&leaf_salt, &Identifier::from_str("a")?, &entry)?;
+        let long = EntryDisclosure::to_leaf(&leaf_salt, &Identifier::from_str("amount")?, &entry)?;
+        assert_eq!(short.len(), long.len());
+        assert_ne!(short, long);
+        Ok(())
+    }
+}
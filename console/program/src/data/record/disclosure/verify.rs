@@ -0,0 +1,33 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> EntryDisclosure<N> {
+    /// Returns `true` if the disclosed entry is in the record with the given entries root.
+    ///
+    /// Note: The entries root is not bound to the record commitment. To bind the disclosure to the
+    /// record commitment, prove the circuit `EntryDisclosure::verify_with_commitment` instead.
+    pub fn verify(&self, entries_root: &Field<N>) -> bool {
+        // Compute the Merkle leaf of the entry.
+        let leaf = match Self::to_leaf(&self.leaf_salt, &self.identifier, &self.entry) {
+            Ok(leaf) => leaf,
+            Err(_) => return false,
+        };
+        // Verify the Merkle path to the entries root.
+        N::verify_merkle_path_bhp(&self.path, entries_root, &leaf)
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Entry<N, Plaintext<N>> {
+    /// Returns the plaintext of the entry as a constant entry.
+    pub fn into_constant(self) -> Self {
+        Self::Constant(self.into_plaintext())
+    }
+
+    /// Returns the plaintext of the entry as a public entry.
+    pub fn into_public(self) -> Self {
+        Self::Public(self.into_plaintext())
+    }
+
+    /// Returns the plaintext of the entry as a private entry.
+    /// The entry is encrypted along with the rest of the record, when the record is encrypted.
+    ///
+    /// Note: To change the visibility of an entry in an encrypted record, use `Record::into_private_entry`.
+    pub fn into_private(self) -> Self {
+        Self::Private(self.into_plaintext())
+    }
+
+    /// Returns the plaintext of the entry.
+    pub fn into_plaintext(self) -> Plaintext<N> {
+        match self {
+            Self::Constant(plaintext) | Self::Public(plaintext) | Self::Private(plaintext) => plaintext,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_into_visibility() -> Result<()> {
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("{ a: 1u8, b: 2field }")?;
+
+        for entry in
+            [Entry::Constant(plaintext.clone()), Entry::Public(plaintext.clone()), Entry::Private(plaintext.clone())]
+        {
+            assert_eq!(Entry::Constant(plaintext.clone()), entry.clone().into_constant());
+            assert_eq!(Entry::Public(plaintext.clone()), entry.clone().into_public());
+            assert_eq!(Entry::Private(plaintext.clone()), entry.clone().into_private());
+            assert_eq!(plaintext, entry.into_plaintext());
+        }
+        Ok(())
+    }
+}
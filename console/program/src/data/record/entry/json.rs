@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Entry<N, Plaintext<N>> {
+    /// Returns the canonical JSON encoding of the entry, as `{ "visibility": ..., "value": ... }`,
+    /// where the visibility is one of `constant`, `public`, or `private`.
+    pub fn to_json(&self) -> serde_json::Value {
+        let (visibility, plaintext) = match self {
+            Self::Constant(plaintext) => ("constant", plaintext),
+            Self::Public(plaintext) => ("public", plaintext),
+            Self::Private(plaintext) => ("private", plaintext),
+        };
+        serde_json::json!({ "visibility": visibility, "value": plaintext.to_json() })
+    }
+
+    /// Returns the entry from its canonical JSON encoding.
+    pub fn from_json(json: &serde_json::Value) -> Result<Self> {
+        // Retrieve the visibility and value.
+        let (visibility, value) = match json.as_object() {
+            Some(object) if object.len() == 2 => match (object.get("visibility"), object.get("value")) {
+                (Some(serde_json::Value::String(visibility)), Some(value)) => (visibility, value),
+                _ => bail!("Invalid JSON for an entry: expected a 'visibility' string and a 'value'"),
+            },
+            _ => bail!("Invalid JSON for an entry: expected an object with 'visibility' and 'value'"),
+        };
+        // Parse the plaintext.
+        let plaintext = Plaintext::from_json(value)?;
+        // Return the entry.
+        match visibility.as_str() {
+            "constant" => Ok(Self::Constant(plaintext)),
+            "public" => Ok(Self::Public(plaintext)),
+            "private" => Ok(Self::Private(plaintext)),
+            _ => bail!("Invalid JSON for an entry: unknown visibility '{visibility}'"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_json() -> Result<()> {
+        for (expected, json) in [
+            ("5u8.constant", serde_json::json!({ "visibility": "constant", "value": "5u8" })),
+            ("true.public", serde_json::json!({ "visibility": "public", "value": "true" })),
+            ("{ a: 1field.private }", serde_json::json!({ "visibility": "private", "value": { "a": "1field" } })),
+        ] {
+            let expected = Entry::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(expected)?;
+            assert_eq!(json, expected.to_json());
+            assert_eq!(expected, Entry::from_json(&json)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_fails() {
+        type CurrentEntry = Entry<CurrentNetwork, Plaintext<CurrentNetwork>>;
+
+        assert!(CurrentEntry::from_json(&serde_json::json!("5u8")).is_err());
+        assert!(CurrentEntry::from_json(&serde_json::json!({ "value": "5u8" })).is_err());
+        assert!(CurrentEntry::from_json(&serde_json::json!({ "visibility": "secret", "value": "5u8" })).is_err());
+        assert!(CurrentEntry::from_json(&serde_json::json!({ "visibility": "public", "value": "5u8", "extra": 1 }))
+            .is_err());
+    }
+}
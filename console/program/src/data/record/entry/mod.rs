@@ -17,6 +17,8 @@
 mod bytes;
 mod equal;
 mod find;
+mod into_visibility;
+mod json;
 mod num_randomizers;
 mod parse;
 mod to_bits;
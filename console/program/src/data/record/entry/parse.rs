@@ -55,6 +55,8 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
                 parse_literal,
                 // Parse a struct.
                 parse_struct,
+                // Parse an array.
+                parse_array,
             ))(string)?;
             // Return the identifier, plaintext, and visibility.
             Ok((string, (identifier, plaintext, mode)))
@@ -96,6 +98,51 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
             Ok((string, (Plaintext::Struct(IndexMap::from_iter(members.into_iter()), Default::default()), mode)))
         }
 
+        /// Parses an entry as an array: `[plaintext_0.visibility, ..., plaintext_n.visibility]`.
+        /// Observe the `visibility` is the same for all elements of the plaintext value.
+        fn parse_array<N: Network>(string: &str) -> ParserResult<(Plaintext<N>, Mode)> {
+            /// Parses a sanitized element: `plaintext.visibility`.
+            fn parse_element<N: Network>(string: &str) -> ParserResult<(Plaintext<N>, Mode)> {
+                // Parse the whitespace and comments from the string.
+                let (string, _) = Sanitizer::parse(string)?;
+                // Parse the plaintext and visibility from the string.
+                alt((
+                    // Parse a literal.
+                    parse_literal,
+                    // Parse a struct.
+                    parse_struct,
+                    // Parse an array.
+                    parse_array,
+                ))(string)
+            }
+
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the "[" from the string.
+            let (string, _) = tag("[")(string)?;
+            // Parse the elements.
+            let (string, (elements, mode)) = map_res(separated_list1(tag(","), parse_element), |elements: Vec<_>| {
+                // Ensure the elements all have the same visibility.
+                let mode = elements.iter().map(|(_, mode)| mode).dedup().collect::<Vec<_>>();
+                let mode = match mode.len() == 1 {
+                    true => *mode[0],
+                    false => return Err(error("Elements of array in entry have different visibilities")),
+                };
+                // Ensure the number of elements is within `N::MAX_ARRAY_ELEMENTS`.
+                match elements.len() <= N::MAX_ARRAY_ELEMENTS as usize {
+                    // Return the elements and the visibility.
+                    true => Ok((elements.into_iter().map(|(p, _)| p).collect::<Vec<_>>(), mode)),
+                    false => Err(error(format!("Found a plaintext array that exceeds size ({})", elements.len()))),
+                }
+            })(string)?;
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the ']' from the string.
+            let (string, _) = tag("]")(string)?;
+            // Output the plaintext and visibility.
+            Ok((string, (Plaintext::Array(elements, Default::default()), mode)))
+        }
+
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse to determine the entry (order matters).
@@ -104,6 +151,8 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
             parse_literal,
             // Parse a struct.
             parse_struct,
+            // Parse an array.
+            parse_array,
         ))(string)?;
 
         // Return the entry.
@@ -198,11 +247,59 @@ impl<N: Network> Entry<N, Plaintext<N>> {
                                 false => write!(f, "\n{:indent$}}},", "", indent = depth * INDENT),
                             }
                         }
+                        Plaintext::Array(..) => {
+                            // Print the member name.
+                            write!(f, "\n{:indent$}{name}: ", "", indent = (depth + 1) * INDENT)?;
+                            // Print the member.
+                            self.fmt_nested(plaintext, f, depth + 1)?;
+                            // Print the closing brace.
+                            match i == struct_.len() - 1 {
+                                // Print the last member without a comma.
+                                true => write!(f, "\n{:indent$}}}", "", indent = depth * INDENT),
+                                // Print the member with a comma.
+                                false => write!(f, ","),
+                            }
+                        }
+                    }
+                })
+            }
+            // Prints the array, i.e. [ 10i64.private, 198i64.private ]
+            Plaintext::Array(array, ..) => {
+                // Print the opening bracket.
+                write!(f, "[")?;
+                // Print the elements.
+                array.iter().enumerate().try_for_each(|(i, plaintext)| {
+                    match plaintext {
+                        // Print the literal.
+                        Plaintext::Literal(literal, ..) => {
+                            write!(f, "\n{:indent$}{literal}.{visibility}", "", indent = (depth + 1) * INDENT)?
+                        }
+                        Plaintext::Struct(..) | Plaintext::Array(..) => {
+                            // Print the indentation.
+                            write!(f, "\n{:indent$}", "", indent = (depth + 1) * INDENT)?;
+                            // Print the element.
+                            self.fmt_nested(plaintext, f, depth + 1)?;
+                        }
+                    }
+                    match i == array.len() - 1 {
+                        // Print the closing bracket after the last element.
+                        true => write!(f, "\n{:indent$}]", "", indent = depth * INDENT),
+                        // Print the element with a comma.
+                        false => write!(f, ","),
                     }
                 })
             }
         }
     }
+
+    /// Prints the given plaintext with the visibility of `self` at the given indentation depth.
+    fn fmt_nested(&self, plaintext: &Plaintext<N>, f: &mut Formatter, depth: usize) -> fmt::Result {
+        match self {
+            Self::Constant(..) => Self::Constant(plaintext.clone()).fmt_internal(f, depth),
+            Self::Public(..) => Self::Public(plaintext.clone()).fmt_internal(f, depth),
+            Self::Private(..) => Self::Private(plaintext.clone()).fmt_internal(f, depth),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -256,4 +353,40 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_array() -> Result<()> {
+        // Sanity check.
+        let expected = r"[
+  1u8.private,
+  2u8.private
+]";
+        let (remainder, candidate) =
+            Entry::<CurrentNetwork, Plaintext<CurrentNetwork>>::parse("[1u8.private, 2u8.private]")?;
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+
+        let expected = r"{
+  foo: [
+    {
+      bar: 1u8.public
+    },
+    {
+      bar: 2u8.public
+    }
+  ],
+  baz: 5u8.public
+}";
+        let (remainder, candidate) = Entry::<CurrentNetwork, Plaintext<CurrentNetwork>>::parse(
+            "{ foo: [{ bar: 1u8.public }, { bar: 2u8.public }], baz: 5u8.public }",
+        )?;
+        println!("\nExpected: {expected}\n\nFound: {candidate}\n");
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+
+        // Elements must have the same visibility.
+        assert!(Entry::<CurrentNetwork, Plaintext<CurrentNetwork>>::parse("[1u8.private, 2u8.public]").is_err());
+
+        Ok(())
+    }
 }
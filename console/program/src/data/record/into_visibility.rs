@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Returns the record, with the given entry as a constant entry.
+    pub fn into_constant_entry(self, entry_name: &Identifier<N>) -> Result<Self> {
+        self.map_entry(entry_name, Entry::into_constant)
+    }
+
+    /// Returns the record, with the given entry as a public entry.
+    pub fn into_public_entry(self, entry_name: &Identifier<N>) -> Result<Self> {
+        self.map_entry(entry_name, Entry::into_public)
+    }
+
+    /// Returns the record, with the given entry as a private entry.
+    pub fn into_private_entry(self, entry_name: &Identifier<N>) -> Result<Self> {
+        self.map_entry(entry_name, Entry::into_private)
+    }
+
+    /// Returns the record, with the given entry converted by the given function.
+    ///
+    /// Note: The visibility of an entry determines the randomizers of every later entry, and the record commitment.
+    /// As such, the record is converted in plaintext, and must be re-encrypted as a whole with `Record::encrypt`,
+    /// which derives the randomizers of every private entry from the (unchanged) record nonce.
+    fn map_entry(
+        mut self,
+        entry_name: &Identifier<N>,
+        convert: impl FnOnce(Entry<N, Plaintext<N>>) -> Entry<N, Plaintext<N>>,
+    ) -> Result<Self> {
+        match self.data.get_mut(entry_name) {
+            Some(entry) => *entry = convert(entry.clone()),
+            None => bail!("Entry '{entry_name}' does not exist in the record"),
+        }
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_into_entry_visibility() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a view key and an owner.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let owner = Owner::Private(Plaintext::from(Literal::Address(Address::try_from(&private_key)?)));
+
+        // Prepare the record, with a public entry followed by a private entry.
+        let randomizer = Scalar::rand(&mut rng);
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_plaintext(
+            owner,
+            Balance::Private(Plaintext::from(Literal::U64(U64::new(1)))),
+            IndexMap::from_iter([
+                (Identifier::from_str("a")?, Entry::Public(Plaintext::from_str("{ x: 1u8, y: 2field }")?)),
+                (Identifier::from_str("b")?, Entry::Private(Plaintext::from(Literal::Field(Field::rand(&mut rng))))),
+            ]),
+            CurrentNetwork::g_scalar_multiply(&randomizer),
+        )?;
+        let a = Identifier::from_str("a")?;
+
+        // Ensure the record round-trips through encryption, for each visibility of the first entry.
+        for record in [
+            record.clone().into_private_entry(&a)?,
+            record.clone().into_constant_entry(&a)?,
+            record.clone().into_private_entry(&a)?.into_public_entry(&a)?,
+        ] {
+            let ciphertext = record.encrypt(randomizer)?;
+            assert_eq!(record, ciphertext.decrypt(&view_key)?);
+            // Ensure only the private entries are encrypted.
+            let is_private = matches!(record.data()[&a], Entry::Private(..));
+            assert_eq!(is_private, matches!(ciphertext.data()[&a], Entry::Private(..)));
+            assert!(matches!(ciphertext.data()[&Identifier::from_str("b")?], Entry::Private(..)));
+        }
+
+        // Ensure the visibility is changed.
+        assert!(matches!(record.clone().into_private_entry(&a)?.data()[&a], Entry::Private(..)));
+        assert!(matches!(record.clone().into_constant_entry(&a)?.data()[&a], Entry::Constant(..)));
+
+        // Ensure a missing entry fails.
+        assert!(record.into_public_entry(&Identifier::from_str("c")?).is_err());
+        Ok(())
+    }
+}
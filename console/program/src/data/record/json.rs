@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Returns the canonical JSON encoding of the record.
+    ///
+    /// The record is encoded as a JSON object with the keys `owner`, `gates`, followed by
+    /// each data entry in declaration order, and lastly `_nonce`. The owner, gates, and data
+    /// entries are encoded as entries (see `Entry::to_json`), and the nonce as a string.
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut object = serde_json::Map::with_capacity(self.data.len() + 3);
+        object.insert("owner".to_string(), self.owner.to_entry().to_json());
+        object.insert("gates".to_string(), self.gates.to_entry().to_json());
+        for (identifier, entry) in &self.data {
+            object.insert(identifier.to_string(), entry.to_json());
+        }
+        object.insert("_nonce".to_string(), serde_json::Value::String(self.nonce.to_string()));
+        serde_json::Value::Object(object)
+    }
+
+    /// Returns the record from its canonical JSON encoding.
+    pub fn from_json(json: &serde_json::Value) -> Result<Self> {
+        // Retrieve the object.
+        let object = match json.as_object() {
+            Some(object) => object,
+            None => bail!("Invalid JSON for a record: expected an object"),
+        };
+
+        // Parse the owner.
+        let owner = match object.get("owner").map(Entry::from_json).transpose()? {
+            Some(Entry::Public(Plaintext::Literal(Literal::Address(owner), ..))) => Owner::Public(owner),
+            Some(Entry::Private(Plaintext::Literal(Literal::Address(owner), ..))) => {
+                Owner::Private(Plaintext::from(Literal::Address(owner)))
+            }
+            _ => bail!("Invalid JSON for a record: expected a public or private address 'owner'"),
+        };
+        // Parse the gates.
+        let gates = match object.get("gates").map(Entry::from_json).transpose()? {
+            Some(Entry::Public(Plaintext::Literal(Literal::U64(gates), ..))) => Balance::Public(gates),
+            Some(Entry::Private(Plaintext::Literal(Literal::U64(gates), ..))) => {
+                Balance::Private(Plaintext::from(Literal::U64(gates)))
+            }
+            _ => bail!("Invalid JSON for a record: expected a public or private u64 'gates'"),
+        };
+        // Parse the nonce.
+        let nonce = match object.get("_nonce") {
+            Some(serde_json::Value::String(nonce)) => Group::from_str(nonce)?,
+            _ => bail!("Invalid JSON for a record: expected a '_nonce' string"),
+        };
+        // Parse the data entries.
+        let data = object
+            .iter()
+            .filter(|(key, _)| !matches!(key.as_str(), "owner" | "gates" | "_nonce"))
+            .map(|(identifier, entry)| Ok((Identifier::from_str(identifier)?, Entry::from_json(entry)?)))
+            .collect::<Result<IndexMap<_, _>>>()?;
+
+        // Return the record.
+        Self::from_plaintext(owner, gates, data, nonce)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_json() -> Result<()> {
+        let expected = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add.private, gates: 5u64.public, a: true.private, b: { c: 1field.public }, _nonce: 0group.public }",
+        )?;
+
+        // Check the encoding.
+        let json = expected.to_json();
+        assert_eq!(
+            json.to_string(),
+            r#"{"owner":{"visibility":"private","value":"aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add"},"gates":{"visibility":"public","value":"5u64"},"a":{"visibility":"private","value":"true"},"b":{"visibility":"public","value":{"c":"1field"}},"_nonce":"0group"}"#
+        );
+
+        // Check the round trip.
+        assert_eq!(expected, Record::from_json(&json)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_fails() {
+        type CurrentRecord = Record<CurrentNetwork, Plaintext<CurrentNetwork>>;
+
+        let owner = serde_json::json!({ "visibility": "private", "value": "aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add" });
+        let gates = serde_json::json!({ "visibility": "private", "value": "5u64" });
+
+        // Missing nonce.
+        assert!(CurrentRecord::from_json(&serde_json::json!({ "owner": owner, "gates": gates })).is_err());
+        // Constant owner.
+        let constant = serde_json::json!({ "visibility": "constant", "value": "aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add" });
+        assert!(CurrentRecord::from_json(
+            &serde_json::json!({ "owner": constant, "gates": gates, "_nonce": "0group" })
+        )
+        .is_err());
+        // Gates with the wrong type.
+        let gates = serde_json::json!({ "visibility": "private", "value": "5u32" });
+        assert!(CurrentRecord::from_json(&serde_json::json!({ "owner": owner, "gates": gates, "_nonce": "0group" }))
+            .is_err());
+    }
+}
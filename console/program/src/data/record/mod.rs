@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod disclosure;
+pub use disclosure::{EntryDisclosure, RecordEntriesPath, RecordEntriesTree, RECORD_ENTRIES_DEPTH};
+
 mod entry;
 pub use entry::Entry;
 
@@ -25,15 +28,19 @@ mod decrypt;
 mod encrypt;
 mod equal;
 mod find;
+mod into_visibility;
 mod is_owner;
+mod json;
 mod num_randomizers;
 mod parse_ciphertext;
 mod parse_plaintext;
+mod scan;
 mod serial_number;
 mod serialize;
 mod tag;
 mod to_bits;
 mod to_commitment;
+mod to_entries_tree;
 mod to_fields;
 
 use crate::{Ciphertext, Identifier, Literal, Plaintext, ProgramID};
@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+impl<N: Network> Record<N, Ciphertext<N>> {
+    /// Returns `true` for each of the given records that is owned by the given view key.
+    ///
+    /// This is equivalent to calling `is_owner` on each record, but amortizes the per-record cost
+    /// by deriving the address once, computing the record view keys in parallel, and converting
+    /// them to affine coordinates with a single batch inversion.
+    pub fn scan<'a>(view_key: &ViewKey<N>, records: impl IntoIterator<Item = &'a Self>) -> Vec<bool>
+    where
+        N: 'a,
+    {
+        // Compute the x-coordinate of the address.
+        let address_x_coordinate = view_key.to_address().to_x_coordinate();
+
+        // Collect the records.
+        let records = records.into_iter().collect::<Vec<_>>();
+
+        // Compute the (unnormalized) record view keys for the records with a private owner.
+        let products = cfg_iter!(records)
+            .filter(|record| record.owner.is_private())
+            .map(|record| record.nonce * **view_key)
+            .collect::<Vec<_>>();
+        // Compute the record view keys with a single batch inversion.
+        let record_view_keys = Group::to_x_coordinates(&products);
+        // Compute the 0th randomizer for each record view key.
+        let randomizers = cfg_into_iter!(record_view_keys)
+            .map(|record_view_key| N::hash_many_psd8(&[N::encryption_domain(), record_view_key], 1)[0])
+            .collect::<Vec<_>>();
+
+        // Check the owner of each record.
+        let mut randomizers = randomizers.into_iter();
+        records
+            .into_iter()
+            .map(|record| match &record.owner {
+                // If the owner is public, check if the address is the owner.
+                Owner::Public(owner) => owner.to_x_coordinate() == address_x_coordinate,
+                // If the owner is private, decrypt the owner to check if it matches the address.
+                // See `is_owner_with_address_x_coordinate` for why comparing the x-coordinates is sufficient.
+                Owner::Private(ciphertext) => match randomizers.next() {
+                    Some(randomizer) => ciphertext[0] - randomizer == address_x_coordinate,
+                    None => N::halt("Internal error: missing randomizer in record scan"),
+                },
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 50;
+
+    #[test]
+    fn test_scan() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a view key and address.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let address = Address::try_from(&private_key)?;
+
+        // Sample another address.
+        let other_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+
+        // Sample records with public and private owners, that belong to either address.
+        let mut records = Vec::with_capacity(ITERATIONS);
+        for i in 0..ITERATIONS {
+            let owner_address = if i % 3 == 0 { other_address } else { address };
+            let owner = match i % 2 == 0 {
+                true => Owner::Public(owner_address),
+                false => Owner::Private(Plaintext::from(Literal::Address(owner_address))),
+            };
+            let randomizer = Uniform::rand(&mut rng);
+            let record = Record {
+                owner,
+                gates: Balance::Private(Plaintext::from(Literal::U64(U64::new(u64::rand(&mut rng) >> 12)))),
+                data: IndexMap::new(),
+                nonce: CurrentNetwork::g_scalar_multiply(&randomizer),
+            };
+            records.push(record.encrypt(randomizer)?);
+        }
+
+        // Ensure the scan matches `is_owner` on each record.
+        let expected = records.iter().map(|record| record.is_owner(&view_key)).collect::<Vec<_>>();
+        assert_eq!(expected, Record::scan(&view_key, &records));
+        assert!(expected.iter().any(|is_owner| *is_owner));
+        assert!(expected.iter().any(|is_owner| !*is_owner));
+
+        // Ensure the empty case is handled.
+        assert!(Record::scan(&view_key, &[]).is_empty());
+        Ok(())
+    }
+}
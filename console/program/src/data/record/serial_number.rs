@@ -16,6 +16,9 @@
 
 use super::*;
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 impl<N: Network, Private: Visibility> Record<N, Private> {
     /// A helper method to derive the serial number from the private key and commitment.
     pub fn serial_number(private_key: PrivateKey<N>, commitment: Field<N>) -> Result<Field<N>> {
@@ -27,6 +30,11 @@ impl<N: Network, Private: Visibility> Record<N, Private> {
         Self::serial_number_from_gamma(&gamma, commitment)
     }
 
+    /// A helper method to derive the serial numbers from the private key and each of the given commitments, in parallel.
+    pub fn serial_numbers(private_key: PrivateKey<N>, commitments: &[Field<N>]) -> Result<Vec<Field<N>>> {
+        cfg_iter!(commitments).map(|commitment| Self::serial_number(private_key, *commitment)).collect()
+    }
+
     /// A helper method to derive the serial number from the gamma and commitment.
     pub fn serial_number_from_gamma(gamma: &Group<N>, commitment: Field<N>) -> Result<Field<N>> {
         // Compute `sn_nonce` as `Hash(COFACTOR * gamma)`.
@@ -35,3 +43,33 @@ impl<N: Network, Private: Visibility> Record<N, Private> {
         N::commit_bhp512(&(N::serial_number_domain(), commitment).to_bits_le(), &sn_nonce)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 25;
+
+    #[test]
+    fn test_serial_numbers() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample the private key and commitments.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let commitments = (0..ITERATIONS).map(|_| Field::rand(&mut rng)).collect::<Vec<_>>();
+
+        // Ensure the batched serial numbers match the individual serial numbers.
+        let candidates =
+            Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::serial_numbers(private_key, &commitments)?;
+        assert_eq!(commitments.len(), candidates.len());
+        for (commitment, candidate) in commitments.iter().zip_eq(candidates) {
+            let expected =
+                Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::serial_number(private_key, *commitment)?;
+            assert_eq!(expected, candidate);
+        }
+        Ok(())
+    }
+}
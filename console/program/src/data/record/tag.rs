@@ -16,10 +16,48 @@
 
 use super::*;
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 impl<N: Network, Private: Visibility> Record<N, Private> {
     /// A helper method to derive the tag from the `sk_tag` and commitment.
     pub fn tag(sk_tag: Field<N>, commitment: Field<N>) -> Result<Field<N>> {
         // Compute the tag as `Hash(sk_tag, commitment)`.
         N::hash_psd2(&[sk_tag, commitment])
     }
+
+    /// A helper method to derive the tags from the `sk_tag` and each of the given commitments, in parallel.
+    pub fn tags(sk_tag: Field<N>, commitments: &[Field<N>]) -> Result<Vec<Field<N>>> {
+        cfg_iter!(commitments).map(|commitment| Self::tag(sk_tag, *commitment)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_tags() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample the `sk_tag` and commitments.
+        let sk_tag = Field::rand(&mut rng);
+        let commitments = (0..ITERATIONS).map(|_| Field::rand(&mut rng)).collect::<Vec<_>>();
+
+        // Ensure the batched tags match the individual tags.
+        let candidates = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::tags(sk_tag, &commitments)?;
+        assert_eq!(commitments.len(), candidates.len());
+        for (commitment, candidate) in commitments.iter().zip_eq(candidates) {
+            assert_eq!(Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::tag(sk_tag, *commitment)?, candidate);
+        }
+
+        // Ensure the empty case is handled.
+        assert!(Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::tags(sk_tag, &[])?.is_empty());
+        Ok(())
+    }
 }
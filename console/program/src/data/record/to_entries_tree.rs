@@ -0,0 +1,158 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Returns the Merkle tree for the entries of the record, given the record owner's secret salt.
+    /// The leaves are the `owner`, the `gates`, and then the data entries, in order,
+    /// where each leaf is blinded by a leaf salt derived from the salt and the index of the entry.
+    pub fn to_entries_tree(&self, salt: &Field<N>) -> Result<RecordEntriesTree<N>> {
+        // Compute the Merkle leaves.
+        let leaves = self
+            .to_salted_entries(salt)?
+            .iter()
+            .map(|(leaf_salt, identifier, entry)| EntryDisclosure::to_leaf(leaf_salt, identifier, entry))
+            .collect::<Result<Vec<_>>>()?;
+        // Compute the Merkle tree.
+        N::merkle_tree_bhp::<RECORD_ENTRIES_DEPTH>(&leaves)
+    }
+
+    /// Returns the Merkle root for the entries of the record, given the record owner's secret salt.
+    pub fn to_entries_root(&self, salt: &Field<N>) -> Result<Field<N>> {
+        Ok(*self.to_entries_tree(salt)?.root())
+    }
+
+    /// Returns a disclosure of the entry with the given name, given the record owner's secret salt.
+    /// The disclosure reveals only the given entry, and may be verified against the entries root.
+    pub fn disclose(&self, salt: &Field<N>, identifier: &Identifier<N>) -> Result<EntryDisclosure<N>> {
+        // Retrieve the salted entries.
+        let entries = self.to_salted_entries(salt)?;
+        // Retrieve the index of the entry.
+        let index = match entries.iter().position(|(_, candidate, _)| candidate == identifier) {
+            Some(index) => index,
+            None => bail!("Entry '{identifier}' does not exist in the record"),
+        };
+        // Compute the Merkle leaves.
+        let leaves = entries
+            .iter()
+            .map(|(leaf_salt, identifier, entry)| EntryDisclosure::to_leaf(leaf_salt, identifier, entry))
+            .collect::<Result<Vec<_>>>()?;
+        // Compute the Merkle path for the entry.
+        let path = N::merkle_tree_bhp::<RECORD_ENTRIES_DEPTH>(&leaves)?.prove(index, &leaves[index])?;
+        // Return the entry disclosure.
+        let (leaf_salt, identifier, entry) = entries[index].clone();
+        Ok(EntryDisclosure::from(identifier, entry, leaf_salt, path))
+    }
+
+    /// Returns the entries of the record as a list of `(leaf_salt, identifier, entry)`.
+    fn to_salted_entries(&self, salt: &Field<N>) -> Result<Vec<(Field<N>, Identifier<N>, Entry<N, Plaintext<N>>)>> {
+        // Prepare the owner and gates entries.
+        let reserved = [
+            (Identifier::from_str("owner")?, self.owner.to_entry()),
+            (Identifier::from_str("gates")?, self.gates.to_entry()),
+        ];
+        // Derive the leaf salt for each entry.
+        reserved
+            .into_iter()
+            .chain(self.data.iter().map(|(identifier, entry)| (*identifier, entry.clone())))
+            .enumerate()
+            .map(|(index, (identifier, entry))| {
+                let leaf_salt = N::hash_psd2(&[*salt, Field::from_u16(u16::try_from(index)?)])?;
+                Ok((leaf_salt, identifier, entry))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn sample_record() -> Record<CurrentNetwork, Plaintext<CurrentNetwork>> {
+        Record::from_str(
+            "{ owner: aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add.private, gates: 5u64.private, amount: 100u64.private, memo: { a: 1field.public }, _nonce: 0group.public }",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_disclose() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample the record and salt.
+        let record = sample_record();
+        let salt = Uniform::rand(&mut rng);
+        let root = record.to_entries_root(&salt)?;
+
+        for name in ["owner", "gates", "amount", "memo"] {
+            let identifier = Identifier::from_str(name)?;
+
+            // Disclose the entry.
+            let disclosure = record.disclose(&salt, &identifier)?;
+            assert_eq!(disclosure.identifier(), &identifier);
+            assert!(disclosure.verify(&root));
+
+            // Ensure the disclosure does not verify against a different root.
+            assert!(!disclosure.verify(&record.to_entries_root(&Uniform::rand(&mut rng))?));
+
+            // Check the byte representation.
+            let bytes = disclosure.to_bytes_le()?;
+            assert_eq!(disclosure, EntryDisclosure::read_le(&bytes[..])?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_disclose_tampered_entry() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample the record and salt.
+        let record = sample_record();
+        let salt = Uniform::rand(&mut rng);
+        let root = record.to_entries_root(&salt)?;
+
+        // Disclose the entry, and replace its value.
+        let disclosure = record.disclose(&salt, &Identifier::from_str("amount")?)?;
+        let tampered = EntryDisclosure::from(
+            *disclosure.identifier(),
+            Entry::from_str("101u64.private")?,
+            *disclosure.leaf_salt(),
+            disclosure.path().clone(),
+        );
+        assert!(!tampered.verify(&root));
+
+        // Disclose the entry, and replace its name.
+        let tampered = EntryDisclosure::from(
+            Identifier::from_str("gates")?,
+            disclosure.entry().clone(),
+            *disclosure.leaf_salt(),
+            disclosure.path().clone(),
+        );
+        assert!(!tampered.verify(&root));
+        Ok(())
+    }
+
+    #[test]
+    fn test_disclose_fails() -> Result<()> {
+        let record = sample_record();
+        assert!(record.disclose(&Field::from_u8(1), &Identifier::from_str("missing")?).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Value<N> {
+    /// Returns the nesting depth of the value, as checked against `N::MAX_DATA_DEPTH`.
+    /// A literal has a depth of 0, and the entries of a record are at a depth of 1.
+    pub fn depth(&self) -> usize {
+        match self {
+            Self::Plaintext(plaintext) => plaintext.depth(),
+            Self::Record(record) => {
+                1 + record
+                    .data()
+                    .values()
+                    .map(|entry| match entry {
+                        Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext) => {
+                            plaintext.depth()
+                        }
+                    })
+                    .max()
+                    .unwrap_or(0)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_depth() -> Result<()> {
+        for (expected, value) in [
+            (0, "5u8"),
+            (2, "{ a: 5u8, b: { c: true } }"),
+            (
+                1,
+                "{ owner: aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add.private, gates: 5u64.private, _nonce: 0group.public }",
+            ),
+            (
+                2,
+                "{ owner: aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add.private, gates: 5u64.private, a: { b: 1field.public }, _nonce: 0group.public }",
+            ),
+        ] {
+            assert_eq!(expected, Value::<CurrentNetwork>::from_str(value)?.depth());
+        }
+        Ok(())
+    }
+}
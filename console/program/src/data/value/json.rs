@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Value<N> {
+    /// Returns the canonical JSON encoding of the value, as either `{ "plaintext": ... }` or `{ "record": ... }`.
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Self::Plaintext(plaintext) => serde_json::json!({ "plaintext": plaintext.to_json() }),
+            Self::Record(record) => serde_json::json!({ "record": record.to_json() }),
+        }
+    }
+
+    /// Returns the value from its canonical JSON encoding.
+    pub fn from_json(json: &serde_json::Value) -> Result<Self> {
+        match json.as_object() {
+            Some(object) if object.len() == 1 => match object.iter().next() {
+                Some((variant, value)) if variant == "plaintext" => Ok(Self::Plaintext(Plaintext::from_json(value)?)),
+                Some((variant, value)) if variant == "record" => Ok(Self::Record(Record::from_json(value)?)),
+                _ => bail!("Invalid JSON for a value: expected a 'plaintext' or 'record'"),
+            },
+            _ => bail!("Invalid JSON for a value: expected an object with a single 'plaintext' or 'record'"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_json() -> Result<()> {
+        // Check a plaintext value.
+        let expected = Value::<CurrentNetwork>::from_str("{ a: 1u8, b: [true, false] }")?;
+        let json = expected.to_json();
+        assert_eq!(json, serde_json::json!({ "plaintext": { "a": "1u8", "b": ["true", "false"] } }));
+        assert_eq!(expected, Value::from_json(&json)?);
+
+        // Check a record value.
+        let expected = Value::<CurrentNetwork>::from_str(
+            "{ owner: aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add.public, gates: 5u64.private, _nonce: 0group.public }",
+        )?;
+        let json = expected.to_json();
+        assert!(json.get("record").is_some());
+        assert_eq!(expected, Value::from_json(&json)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_fails() {
+        assert!(Value::<CurrentNetwork>::from_json(&serde_json::json!("1u8")).is_err());
+        assert!(Value::<CurrentNetwork>::from_json(&serde_json::json!({ "literal": "1u8" })).is_err());
+        assert!(Value::<CurrentNetwork>::from_json(&serde_json::json!({ "plaintext": "1u8", "record": {} })).is_err());
+    }
+}
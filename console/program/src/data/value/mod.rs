@@ -15,10 +15,13 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod bytes;
+mod depth;
 mod equal;
 mod find;
+mod json;
 mod parse;
 mod serialize;
+mod size;
 mod to_bits;
 mod to_fields;
 
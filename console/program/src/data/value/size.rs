@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Value<N> {
+    /// Returns the number of field elements to encode the value, as checked against `N::MAX_DATA_SIZE_IN_FIELDS`.
+    /// Unlike `to_fields`, this does not fail if the value exceeds the maximum size.
+    pub fn num_field_elements(&self) -> usize {
+        // Compute the number of bits.
+        let num_bits = match self {
+            Self::Plaintext(plaintext) => plaintext.to_bits_le().len(),
+            Self::Record(record) => record.to_bits_le().len(),
+        } + 1; // 1 extra bit for the terminus indicator.
+               // Compute the ceiling division of the number of bits by the number of bits in a field element.
+        (num_bits + Field::<N>::size_in_data_bits() - 1) / Field::<N>::size_in_data_bits()
+    }
+
+    /// Returns the number of bytes to serialize the value.
+    pub fn serialized_size(&self) -> Result<usize> {
+        Ok(self.to_bytes_le()?.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_num_field_elements() -> Result<()> {
+        for value in [
+            "5u8",
+            "{ a: 5u8, b: [1field, 2field, 3field] }",
+            "{ owner: aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add.private, gates: 5u64.private, a: { b: 1field.public }, _nonce: 0group.public }",
+        ] {
+            let value = Value::<CurrentNetwork>::from_str(value)?;
+            assert_eq!(value.to_fields()?.len(), value.num_field_elements());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_serialized_size() -> Result<()> {
+        for value in [
+            "5u8",
+            "{ a: 5u8, b: [1field, 2field, 3field] }",
+            "{ owner: aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add.private, gates: 5u64.private, a: { b: 1field.public }, _nonce: 0group.public }",
+        ] {
+            let value = Value::<CurrentNetwork>::from_str(value)?;
+            assert_eq!(bincode::serialize(&value)?.len(), 8 + value.serialized_size()?);
+        }
+        Ok(())
+    }
+}
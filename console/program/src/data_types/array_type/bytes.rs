@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> FromBytes for ArrayType<N> {
+    /// Reads an array type from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the element type.
+        let element_type = match u8::read_le(&mut reader)? {
+            0 => PlaintextType::Literal(LiteralType::read_le(&mut reader)?),
+            1 => PlaintextType::Struct(Identifier::read_le(&mut reader)?),
+            variant => return Err(error(format!("Failed to deserialize array element type variant {variant}"))),
+        };
+        // Read the length.
+        let length = u32::read_le(&mut reader)?;
+        // Return the array type.
+        Self::new(element_type, length).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for ArrayType<N> {
+    /// Writes an array type to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the element type.
+        match self.element_type {
+            ElementType::Literal(literal_type) => {
+                0u8.write_le(&mut writer)?;
+                literal_type.write_le(&mut writer)?;
+            }
+            ElementType::Struct(identifier) => {
+                1u8.write_le(&mut writer)?;
+                identifier.write_le(&mut writer)?;
+            }
+        }
+        // Write the length.
+        self.length.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        for case in ["[u64; 32]", "[field; 1]", "[point; 4]"] {
+            let expected = ArrayType::<CurrentNetwork>::from_str(case)?;
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, ArrayType::read_le(&expected_bytes[..])?);
+            assert!(ArrayType::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        }
+        Ok(())
+    }
+}
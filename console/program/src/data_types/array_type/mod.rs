@@ -0,0 +1,77 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod bytes;
+mod parse;
+mod serialize;
+
+use crate::{Identifier, LiteralType, PlaintextType};
+use snarkvm_console_network::prelude::*;
+
+/// An `ArrayType` defines a fixed-length array of homogeneous plaintext elements.
+/// The format of the type is `[<element_type>; <length>]`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ArrayType<N: Network> {
+    /// The element type of the array.
+    element_type: ElementType<N>,
+    /// The number of elements in the array.
+    length: u32,
+}
+
+/// The element type of an array, which is either a literal type or a struct type.
+/// Note: Nested arrays are not supported; wrap the inner array in a struct instead.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+enum ElementType<N: Network> {
+    /// A literal type, i.e. `u64`.
+    Literal(LiteralType),
+    /// A struct type, i.e. `point`.
+    Struct(Identifier<N>),
+}
+
+impl<N: Network> ArrayType<N> {
+    /// Initializes a new array type from the given element type and length.
+    pub fn new(element_type: PlaintextType<N>, length: u32) -> Result<Self> {
+        // Ensure the length is within bounds.
+        ensure!(
+            (N::MIN_ARRAY_ELEMENTS..=N::MAX_ARRAY_ELEMENTS).contains(&length),
+            "An array must have between {} and {} elements, found {length}",
+            N::MIN_ARRAY_ELEMENTS,
+            N::MAX_ARRAY_ELEMENTS
+        );
+        // Ensure the element type is not an array.
+        let element_type = match element_type {
+            PlaintextType::Literal(literal_type) => ElementType::Literal(literal_type),
+            PlaintextType::Struct(identifier) => ElementType::Struct(identifier),
+            PlaintextType::Array(..) => bail!("An array cannot contain an array, use a struct instead"),
+        };
+        Ok(Self { element_type, length })
+    }
+
+    /// Returns the element type of the array.
+    #[inline]
+    pub const fn element_type(&self) -> PlaintextType<N> {
+        match self.element_type {
+            ElementType::Literal(literal_type) => PlaintextType::Literal(literal_type),
+            ElementType::Struct(identifier) => PlaintextType::Struct(identifier),
+        }
+    }
+
+    /// Returns the number of elements in the array.
+    #[inline]
+    pub const fn length(&self) -> u32 {
+        self.length
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Parser for ArrayType<N> {
+    /// Parses a string into an array type.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses a sanitized array type: `[element_type; length]`.
+        fn parse_array<N: Network>(string: &str) -> ParserResult<(PlaintextType<N>, u32)> {
+            // Parse the "[" from the string.
+            let (string, _) = tag("[")(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the element type from the string (order matters).
+            let (string, element_type) = alt((
+                map(LiteralType::parse, PlaintextType::Literal),
+                map(Identifier::parse, PlaintextType::Struct),
+            ))(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the ";" from the string.
+            let (string, _) = tag(";")(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the length from the string.
+            let (string, length) =
+                map_res(recognize(many1(one_of("0123456789"))), |digits: &str| digits.parse::<u32>())(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the "]" from the string.
+            let (string, _) = tag("]")(string)?;
+            // Return the element type and length.
+            Ok((string, (element_type, length)))
+        }
+
+        // Parse the array type, and ensure it is well-formed.
+        map_res(parse_array, |(element_type, length)| Self::new(element_type, length))(string)
+    }
+}
+
+impl<N: Network> FromStr for ArrayType<N> {
+    type Err = Error;
+
+    /// Returns an array type from a string literal.
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for ArrayType<N> {
+    /// Prints the array type as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for ArrayType<N> {
+    /// Prints the array type as a string, i.e. `[u64; 32]`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "[{}; {}]", self.element_type(), self.length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let (remainder, candidate) = ArrayType::<CurrentNetwork>::parse("[u64; 32]")?;
+        assert_eq!("", remainder);
+        assert_eq!(candidate.element_type(), PlaintextType::Literal(LiteralType::U64));
+        assert_eq!(candidate.length(), 32);
+
+        let (remainder, candidate) = ArrayType::<CurrentNetwork>::parse("[ point;4 ]")?;
+        assert_eq!("", remainder);
+        assert_eq!(candidate.element_type(), PlaintextType::Struct(Identifier::from_str("point")?));
+        assert_eq!(candidate.length(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_fails() {
+        // Must have a length.
+        assert!(ArrayType::<CurrentNetwork>::parse("[u64]").is_err());
+        assert!(ArrayType::<CurrentNetwork>::parse("[u64; ]").is_err());
+        // Must have a length within bounds.
+        assert!(ArrayType::<CurrentNetwork>::parse("[u64; 0]").is_err());
+        assert!(ArrayType::<CurrentNetwork>::parse("[u64; 33]").is_err());
+        // Must not be nested.
+        assert!(ArrayType::<CurrentNetwork>::parse("[[u64; 2]; 2]").is_err());
+        // Must not contain visibility.
+        assert!(ArrayType::<CurrentNetwork>::parse("[u64.private; 2]").is_err());
+    }
+
+    #[test]
+    fn test_display() -> Result<()> {
+        assert_eq!(ArrayType::<CurrentNetwork>::from_str("[u64;32]")?.to_string(), "[u64; 32]");
+        assert_eq!(ArrayType::<CurrentNetwork>::from_str("[ point ; 4 ]")?.to_string(), "[point; 4]");
+        Ok(())
+    }
+}
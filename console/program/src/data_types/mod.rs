@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod array_type;
+pub use array_type::ArrayType;
+
 mod finalize_type;
 pub use finalize_type::FinalizeType;
 
@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> PlaintextType<N> {
+    /// Returns the JSON schema for the canonical JSON encoding of a plaintext of this type.
+    /// A struct type is referenced as `#/definitions/<identifier>`.
+    pub fn to_json_schema(&self) -> serde_json::Value {
+        match self {
+            Self::Literal(literal_type) => {
+                serde_json::json!({ "type": "string", "pattern": literal_pattern(literal_type) })
+            }
+            Self::Struct(identifier) => serde_json::json!({ "$ref": format!("#/definitions/{identifier}") }),
+            Self::Array(array_type) => serde_json::json!({
+                "type": "array",
+                "items": array_type.element_type().to_json_schema(),
+                "minItems": array_type.length(),
+                "maxItems": array_type.length(),
+            }),
+        }
+    }
+}
+
+/// Returns the pattern matching the string encoding of a literal of the given type.
+fn literal_pattern(literal_type: &LiteralType) -> String {
+    match literal_type {
+        LiteralType::Address => "^aleo1[a-z0-9]{58}$".to_string(),
+        LiteralType::Boolean => "^(true|false)$".to_string(),
+        LiteralType::String => "^\".*\"$".to_string(),
+        LiteralType::I8 | LiteralType::I16 | LiteralType::I32 | LiteralType::I64 | LiteralType::I128 => {
+            format!("^-?[0-9]+{}$", literal_type.type_name())
+        }
+        _ => format!("^[0-9]+{}$", literal_type.type_name()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_json_schema() -> Result<()> {
+        assert_eq!(
+            PlaintextType::<CurrentNetwork>::from_str("u64")?.to_json_schema(),
+            serde_json::json!({ "type": "string", "pattern": "^[0-9]+u64$" })
+        );
+        assert_eq!(
+            PlaintextType::<CurrentNetwork>::from_str("i8")?.to_json_schema(),
+            serde_json::json!({ "type": "string", "pattern": "^-?[0-9]+i8$" })
+        );
+        assert_eq!(
+            PlaintextType::<CurrentNetwork>::from_str("token")?.to_json_schema(),
+            serde_json::json!({ "$ref": "#/definitions/token" })
+        );
+        assert_eq!(
+            PlaintextType::<CurrentNetwork>::from_str("[boolean; 4]")?.to_json_schema(),
+            serde_json::json!({
+                "type": "array",
+                "items": { "type": "string", "pattern": "^(true|false)$" },
+                "minItems": 4,
+                "maxItems": 4,
+            })
+        );
+        Ok(())
+    }
+}
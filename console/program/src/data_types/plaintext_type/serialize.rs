@@ -69,6 +69,10 @@ mod tests {
         "passport",
         "object",
         "array",
+        // Array
+        "[u64; 32]",
+        "[field; 1]",
+        "[signature; 4]",
     ];
 
     fn check_serde_json<
@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Struct<N> {
+    /// Returns the JSON schema for the canonical JSON encoding of a plaintext of this struct type.
+    /// Members of a struct type are referenced as `#/definitions/<identifier>`, and the caller
+    /// is expected to provide the schemas of those structs under `definitions`.
+    pub fn to_json_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "title": self.name.to_string(),
+            "type": "object",
+            "properties": self
+                .members
+                .iter()
+                .map(|(identifier, plaintext_type)| (identifier.to_string(), plaintext_type.to_json_schema()))
+                .collect::<serde_json::Map<_, _>>(),
+            "required": self.members.keys().map(|identifier| identifier.to_string()).collect::<Vec<_>>(),
+            "additionalProperties": false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_json_schema() -> Result<()> {
+        let struct_ = Struct::<CurrentNetwork>::from_str(
+            "struct message:\n    owner as address;\n    data as [u8; 2];\n    meta as metadata;",
+        )?;
+        assert_eq!(
+            struct_.to_json_schema().to_string(),
+            serde_json::json!({
+                "title": "message",
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string", "pattern": "^aleo1[a-z0-9]{58}$" },
+                    "data": {
+                        "type": "array",
+                        "items": { "type": "string", "pattern": "^[0-9]+u8$" },
+                        "minItems": 2,
+                        "maxItems": 2,
+                    },
+                    "meta": { "$ref": "#/definitions/metadata" },
+                },
+                "required": ["owner", "data", "meta"],
+                "additionalProperties": false,
+            })
+            .to_string()
+        );
+        Ok(())
+    }
+}
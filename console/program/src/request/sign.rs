@@ -27,6 +27,79 @@ impl<N: Network> Request<N> {
         inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
         input_types: &[ValueType<N>],
         rng: &mut R,
+    ) -> Result<Self> {
+        // Sample a random nonce.
+        let nonce = Field::<N>::rand(rng);
+        // Compute the request.
+        Self::sign_with_nonce(private_key, program_id, function_name, inputs, input_types, nonce)
+    }
+
+    /// Returns the request for a given private key, program ID, function name, inputs, input types, and seed.
+    ///
+    /// The nonce is derived from the seed and the full signed message, instead of sampled from an RNG:
+    ///     provisional := Sign(seed, Hash(seed || function ID))
+    ///     nonce := Hash(seed || provisional.challenge || Hash(input types))
+    /// The provisional challenge commits to `tvk`, `tcm`, the function ID, and the input IDs,
+    /// so any change to the signed message (including the input visibilities) yields a different nonce.
+    /// As the transition view key, and thus the output record randomizers, are derived from the nonce,
+    /// signing the same call with the same seed reproduces the same request and output ciphertexts.
+    /// This allows the results of a delegated prover to be audited against the expected ciphertexts.
+    pub fn sign_deterministic(
+        private_key: &PrivateKey<N>,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        input_types: &[ValueType<N>],
+        seed: Field<N>,
+    ) -> Result<Self> {
+        // Prepare the inputs.
+        let inputs = inputs
+            .enumerate()
+            .map(|(index, input)| {
+                input
+                    .try_into()
+                    .map_err(|_| anyhow!("Failed to parse input #{index} for '{program_id}/{function_name}'"))
+            })
+            .collect::<Result<Vec<Value<N>>>>()?;
+
+        // Compute the function ID as `Hash(network_id, program_id, function_name)`.
+        let function_id = N::hash_bhp1024(
+            &(U16::<N>::new(N::ID), program_id.name(), program_id.network(), function_name).to_bits_le(),
+        )?;
+        // Compute the provisional request, using the nonce `Hash(seed || function ID)`.
+        // Note: The provisional request is never returned; its challenge is a commitment to the full signed message.
+        let provisional_nonce = N::hash_psd2(&[seed, function_id])?;
+        let provisional = Self::sign_with_nonce(
+            private_key,
+            program_id,
+            function_name,
+            inputs.iter(),
+            input_types,
+            provisional_nonce,
+        )?;
+
+        // Compute the input types hash as `Hash(input types)`.
+        // Note: Constant and public inputs have the same input ID, so the visibilities are bound explicitly.
+        let mut input_types_bits = Vec::new();
+        for input_type in input_types {
+            input_types_bits.extend(input_type.to_bytes_le()?.to_bits_le());
+        }
+        let input_types_hash = N::hash_bhp1024(&input_types_bits)?;
+        // Compute the nonce as `Hash(seed || provisional challenge || input types hash)`.
+        let nonce = N::hash_psd4(&[seed, provisional.signature().challenge().to_field()?, input_types_hash])?;
+
+        // Compute the request.
+        Self::sign_with_nonce(private_key, program_id, function_name, inputs.into_iter(), input_types, nonce)
+    }
+
+    /// Returns the request for a given private key, program ID, function name, inputs, input types, and nonce.
+    fn sign_with_nonce(
+        private_key: &PrivateKey<N>,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        input_types: &[ValueType<N>],
+        nonce: Field<N>,
     ) -> Result<Self> {
         // Ensure the number of inputs matches the number of input types.
         if input_types.len() != inputs.len() {
@@ -54,8 +127,6 @@ impl<N: Network> Request<N> {
         // Retrieve `pr_sig`.
         let pr_sig = compute_key.pr_sig();
 
-        // Sample a random nonce.
-        let nonce = Field::<N>::rand(rng);
         // Compute a `r` as `HashToScalar(sk_sig || nonce)`. Note: This is the transition secret key `tsk`.
         let r = N::hash_to_scalar_psd4(&[N::serial_number_domain(), sk_sig.to_field()?, nonce])?;
         // Compute `g_r` as `r * G`. Note: This is the transition public key `tpk`.
@@ -273,4 +273,71 @@ mod tests {
             assert!(request.verify(&input_types));
         }
     }
+
+    #[test]
+    fn test_sign_deterministic() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS / 100 {
+            // Sample a random private key and address.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+            let address = Address::try_from(&private_key).unwrap();
+
+            // Construct a program ID and function name.
+            let program_id = ProgramID::from_str("token.aleo").unwrap();
+            let function_name = Identifier::from_str("transfer").unwrap();
+
+            // Construct the inputs and input types.
+            let inputs = [Value::from_str(&address.to_string()).unwrap(), Value::from_str("100u64").unwrap()];
+            let input_types =
+                vec![ValueType::from_str("address.private").unwrap(), ValueType::from_str("u64.public").unwrap()];
+
+            // Compute the signed request, twice with the same seed.
+            let seed = Field::rand(rng);
+            let sign = |seed, inputs: &[Value<CurrentNetwork>]| {
+                Request::sign_deterministic(&private_key, program_id, function_name, inputs.iter(), &input_types, seed)
+                    .unwrap()
+            };
+            let request = sign(seed, &inputs);
+            assert!(request.verify(&input_types));
+            assert_eq!(request, sign(seed, &inputs));
+
+            // Ensure a different seed yields a different request.
+            let candidate = sign(Field::rand(rng), &inputs);
+            assert!(candidate.verify(&input_types));
+            assert_ne!(request.tvk(), candidate.tvk());
+
+            // Ensure different inputs with the same seed yield a different nonce.
+            let other_inputs = [inputs[0].clone(), Value::from_str("101u64").unwrap()];
+            let candidate = sign(seed, &other_inputs);
+            assert!(candidate.verify(&input_types));
+            assert_ne!(request.tsk(), candidate.tsk());
+
+            // Ensure the same inputs with different visibilities and the same seed yield a different nonce.
+            for other_types in [["address.private", "u64.private"], ["address.public", "u64.public"]] {
+                let other_types = other_types.map(|input_type| ValueType::from_str(input_type).unwrap());
+                let candidate = Request::sign_deterministic(
+                    &private_key,
+                    program_id,
+                    function_name,
+                    inputs.iter(),
+                    &other_types,
+                    seed,
+                )
+                .unwrap();
+                assert!(candidate.verify(&other_types));
+                assert_ne!(request.tsk(), candidate.tsk());
+                assert_ne!(request.tvk(), candidate.tvk());
+            }
+
+            // Ensure a constant and a public input, which share an input ID, yield a different nonce.
+            let sign_u64 = |input_type| {
+                let input_types = [ValueType::from_str(input_type).unwrap()];
+                let inputs = [Value::<CurrentNetwork>::from_str("100u64").unwrap()];
+                Request::sign_deterministic(&private_key, program_id, function_name, inputs.iter(), &input_types, seed)
+                    .unwrap()
+            };
+            assert_ne!(sign_u64("u64.constant").tsk(), sign_u64("u64.public").tsk());
+        }
+    }
 }
@@ -31,6 +31,20 @@ impl<E: Environment> ToBytes for Address<E> {
     }
 }
 
+impl<E: Environment> Address<E> {
+    /// Reads in an account address from a buffer, as either the compressed x-coordinate,
+    /// or the uncompressed `(x, y)` affine coordinates.
+    pub fn read_le_with_compression<R: Read>(reader: R, compress: Compress) -> IoResult<Self> {
+        Ok(Address::new(Group::read_le_with_compression(reader, compress)?))
+    }
+
+    /// Writes an account address to a buffer, as either the compressed x-coordinate,
+    /// or the uncompressed `(x, y)` affine coordinates.
+    pub fn write_le_with_compression<W: Write>(&self, writer: W, compress: Compress) -> IoResult<()> {
+        self.address.write_le_with_compression(writer, compress)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -55,4 +69,25 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_with_compression() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new address.
+            let expected = Address::<CurrentEnvironment>::new(Uniform::rand(&mut rng));
+
+            for compress in [Compress::Yes, Compress::No] {
+                // Check the byte representation.
+                let mut expected_bytes = vec![];
+                expected.write_le_with_compression(&mut expected_bytes, compress)?;
+                assert_eq!(expected, Address::read_le_with_compression(&expected_bytes[..], compress)?);
+                assert!(
+                    Address::<CurrentEnvironment>::read_le_with_compression(&expected_bytes[1..], compress).is_err()
+                );
+            }
+        }
+        Ok(())
+    }
 }
@@ -19,6 +19,7 @@
 
 mod bitwise;
 mod bytes;
+mod ct_eq;
 mod from_bits;
 mod from_field;
 mod from_fields;
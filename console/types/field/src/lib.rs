@@ -21,6 +21,7 @@ mod arithmetic;
 mod bitwise;
 mod bytes;
 mod compare;
+mod ct_eq;
 mod from_bits;
 mod one;
 mod parse;
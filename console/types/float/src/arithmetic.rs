@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Neg for F64<E> {
+    type Output = F64<E>;
+
+    /// Returns the `negation` of `self`, by flipping the sign bit.
+    /// If `self` is NaN, returns the canonical quiet NaN.
+    #[inline]
+    fn neg(self) -> Self::Output {
+        F64::canonicalize(-self.float)
+    }
+}
+
+impl<E: Environment> Add<F64<E>> for F64<E> {
+    type Output = F64<E>;
+
+    /// Returns the `sum` of `self` and `other`.
+    #[inline]
+    fn add(self, other: F64<E>) -> Self::Output {
+        F64::canonicalize(self.float + other.float)
+    }
+}
+
+impl<E: Environment> Add<&F64<E>> for F64<E> {
+    type Output = F64<E>;
+
+    /// Returns the `sum` of `self` and `other`.
+    #[inline]
+    fn add(self, other: &F64<E>) -> Self::Output {
+        F64::canonicalize(self.float + other.float)
+    }
+}
+
+impl<E: Environment> Sub<F64<E>> for F64<E> {
+    type Output = F64<E>;
+
+    /// Returns the `difference` of `self` and `other`.
+    #[inline]
+    fn sub(self, other: F64<E>) -> Self::Output {
+        F64::canonicalize(self.float - other.float)
+    }
+}
+
+impl<E: Environment> Sub<&F64<E>> for F64<E> {
+    type Output = F64<E>;
+
+    /// Returns the `difference` of `self` and `other`.
+    #[inline]
+    fn sub(self, other: &F64<E>) -> Self::Output {
+        F64::canonicalize(self.float - other.float)
+    }
+}
+
+impl<E: Environment> Mul<F64<E>> for F64<E> {
+    type Output = F64<E>;
+
+    /// Returns the `product` of `self` and `other`.
+    #[inline]
+    fn mul(self, other: F64<E>) -> Self::Output {
+        F64::canonicalize(self.float * other.float)
+    }
+}
+
+impl<E: Environment> Mul<&F64<E>> for F64<E> {
+    type Output = F64<E>;
+
+    /// Returns the `product` of `self` and `other`.
+    #[inline]
+    fn mul(self, other: &F64<E>) -> Self::Output {
+        F64::canonicalize(self.float * other.float)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    #[test]
+    fn test_canonical_nan() {
+        let infinity = F64::<CurrentEnvironment>::new(f64::INFINITY);
+        let zero = F64::<CurrentEnvironment>::new(0.0);
+        let nan = F64::<CurrentEnvironment>::from_bits(0xFFF0_0000_0000_0001);
+
+        // Ensure every NaN result is the canonical quiet NaN.
+        for candidate in [infinity - infinity, infinity + (-infinity), infinity * zero, nan + zero, nan * nan] {
+            assert_eq!(F64::<CurrentEnvironment>::CANONICAL_NAN, candidate.to_bits());
+        }
+    }
+
+    #[test]
+    fn test_neg_nan() {
+        // Ensure the negation of any NaN is the canonical quiet NaN.
+        for bits in [0x7FF8_0000_0000_0000, 0xFFF8_0000_0000_0000, 0x7FF0_0000_0000_0001, 0xFFF0_0000_0000_0001] {
+            let nan = F64::<CurrentEnvironment>::from_bits(bits);
+            assert_eq!(F64::<CurrentEnvironment>::CANONICAL_NAN, (-nan).to_bits());
+        }
+    }
+
+    #[test]
+    fn test_signed_zero() {
+        let zero = F64::<CurrentEnvironment>::new(0.0);
+        let one = F64::<CurrentEnvironment>::new(1.0);
+
+        assert_eq!(0, (one - one).to_bits());
+        assert_eq!(0x8000_0000_0000_0000, (-zero + -zero).to_bits());
+        assert_eq!(0, (zero + -zero).to_bits());
+        assert_eq!(0x8000_0000_0000_0000, (-one * zero).to_bits());
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Equal for F64<E> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` and `other` are equal, where NaN is not equal to any value, and `-0 == +0`.
+    fn is_equal(&self, other: &Self) -> Self::Output {
+        Boolean::new(self.float == other.float)
+    }
+
+    /// Returns `true` if `self` and `other` are *not* equal, where NaN is not equal to any value, and `-0 == +0`.
+    fn is_not_equal(&self, other: &Self) -> Self::Output {
+        !self.is_equal(other)
+    }
+}
+
+impl<E: Environment> Compare<Self> for F64<E> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` is less than `other`.
+    fn is_less_than(&self, other: &Self) -> Self::Output {
+        Boolean::new(self.float < other.float)
+    }
+
+    /// Returns `true` if `self` is greater than `other`.
+    fn is_greater_than(&self, other: &Self) -> Self::Output {
+        other.is_less_than(self)
+    }
+
+    /// Returns `true` if `self` is less than or equal to `other`.
+    fn is_less_than_or_equal(&self, other: &Self) -> Self::Output {
+        Boolean::new(self.float <= other.float)
+    }
+
+    /// Returns `true` if `self` is greater than or equal to `other`.
+    fn is_greater_than_or_equal(&self, other: &Self) -> Self::Output {
+        other.is_less_than_or_equal(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    #[test]
+    fn test_compare() {
+        let nan = F64::<CurrentEnvironment>::from_bits(F64::<CurrentEnvironment>::CANONICAL_NAN);
+        let zero = F64::<CurrentEnvironment>::new(0.0);
+        let one = F64::<CurrentEnvironment>::new(1.0);
+
+        // Ensure NaN is unordered, and not equal to itself.
+        assert!(!*nan.is_equal(&nan));
+        assert!(*nan.is_not_equal(&nan));
+        assert!(!*nan.is_less_than(&one));
+        assert!(!*nan.is_greater_than_or_equal(&one));
+
+        // Ensure the zeros are equal.
+        assert!(*zero.is_equal(&-zero));
+        assert!(!*zero.is_less_than(&-zero));
+        assert!(*zero.is_less_than_or_equal(&-zero));
+
+        assert!(*zero.is_less_than(&one));
+        assert!(*(-one).is_less_than(&zero));
+        assert!(*one.is_greater_than(&-one));
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> FromBits for F64<E> {
+    /// Initializes a new float from the little-endian bits of its IEEE-754 encoding.
+    fn from_bits_le(bits_le: &[bool]) -> Result<Self> {
+        ensure!(bits_le.len() == 64, "F64::from_bits_le expects a list of 64 booleans, found {}", bits_le.len());
+        Ok(Self::from_bits(bits_le.iter().rev().fold(0u64, |bits, bit| (bits << 1) | (*bit as u64))))
+    }
+
+    /// Initializes a new float from the big-endian bits of its IEEE-754 encoding.
+    fn from_bits_be(bits_be: &[bool]) -> Result<Self> {
+        let mut bits_le = bits_be.to_vec();
+        bits_le.reverse();
+        Self::from_bits_le(&bits_le)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_from_bits() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random element.
+            let expected: F64<CurrentEnvironment> = Uniform::rand(&mut rng);
+
+            let given_bits = expected.to_bits_le();
+            assert_eq!(F64::<CurrentEnvironment>::size_in_bits(), given_bits.len());
+            assert_eq!(expected, F64::<CurrentEnvironment>::from_bits_le(&given_bits)?);
+
+            let given_bits = expected.to_bits_be();
+            assert_eq!(expected, F64::<CurrentEnvironment>::from_bits_be(&given_bits)?);
+
+            // Ensure an incorrect number of bits fails.
+            assert!(F64::<CurrentEnvironment>::from_bits_le(&given_bits[1..]).is_err());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#![forbid(unsafe_code)]
+#![cfg_attr(test, allow(clippy::assertions_on_result_states))]
+#![warn(clippy::cast_possible_truncation)]
+
+mod arithmetic;
+mod bitwise;
+mod from_bits;
+mod parse;
+mod random;
+mod size_in_bits;
+mod to_bits;
+
+pub use snarkvm_console_network_environment::prelude::*;
+pub use snarkvm_console_types_boolean::Boolean;
+pub use snarkvm_console_types_integers::Integer;
+
+use core::marker::PhantomData;
+
+/// An IEEE-754 binary64 floating-point number.
+///
+/// Arithmetic follows IEEE-754 with rounding to nearest, ties to even, except that every NaN
+/// produced by an operation is the canonical quiet NaN, so that results are deterministic.
+/// Note that `PartialEq` compares the encodings, while `Equal` and `Compare` follow IEEE-754.
+#[derive(Copy, Clone)]
+pub struct F64<E: Environment> {
+    /// The underlying float.
+    float: f64,
+    /// PhantomData.
+    _phantom: PhantomData<E>,
+}
+
+impl<E: Environment> F64<E> {
+    /// The encoding of the canonical quiet NaN.
+    pub const CANONICAL_NAN: u64 = 0x7FF8_0000_0000_0000;
+
+    /// Initializes a new float.
+    pub const fn new(float: f64) -> Self {
+        Self { float, _phantom: PhantomData }
+    }
+
+    /// Initializes a new float from its IEEE-754 encoding.
+    pub fn from_bits(bits: u64) -> Self {
+        Self::new(f64::from_bits(bits))
+    }
+
+    /// Returns the IEEE-754 encoding of the float.
+    pub fn to_bits(&self) -> u64 {
+        self.float.to_bits()
+    }
+
+    /// Returns the float, replacing any NaN with the canonical quiet NaN.
+    pub(crate) fn canonicalize(float: f64) -> Self {
+        match float.is_nan() {
+            true => Self::from_bits(Self::CANONICAL_NAN),
+            false => Self::new(float),
+        }
+    }
+}
+
+impl<E: Environment> TypeName for F64<E> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "f64"
+    }
+}
+
+impl<E: Environment> PartialEq for F64<E> {
+    /// Returns `true` if the encodings of `self` and `other` are equal.
+    fn eq(&self, other: &Self) -> bool {
+        self.to_bits() == other.to_bits()
+    }
+}
+
+impl<E: Environment> Eq for F64<E> {}
+
+impl<E: Environment> core::hash::Hash for F64<E> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.to_bits().hash(state)
+    }
+}
+
+impl<E: Environment> Deref for F64<E> {
+    type Target = f64;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.float
+    }
+}
@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Parser for F64<E> {
+    /// Parses a string into a float.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses a non-empty sequence of digits.
+        fn digits(string: &str) -> ParserResult<&str> {
+            recognize(many1(one_of("0123456789")))(string)
+        }
+
+        // Parse the digits, with an optional fractional part and exponent.
+        let number = recognize(pair(
+            pair(digits, opt(pair(char('.'), digits))),
+            opt(pair(pair(one_of("eE"), opt(one_of("+-"))), digits)),
+        ));
+        // Parse the sign, followed by the infinity, NaN, or number.
+        let (string, primitive) = recognize(pair(opt(tag("-")), alt((tag("inf"), tag("NaN"), number))))(string)?;
+        // Parse the value from the string.
+        let (string, value) = map_res(tag(Self::type_name()), |_| primitive.parse::<f64>())(string)?;
+
+        Ok((string, F64::new(value)))
+    }
+}
+
+impl<E: Environment> FromStr for F64<E> {
+    type Err = Error;
+
+    /// Parses a string into a float.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<E: Environment> Debug for F64<E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<E: Environment> Display for F64<E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.float, Self::type_name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 10_000;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        // Ensure empty value fails.
+        assert!(F64::<CurrentEnvironment>::parse(F64::<CurrentEnvironment>::type_name()).is_err());
+        assert!(F64::<CurrentEnvironment>::parse("").is_err());
+        // Ensure a missing type fails.
+        assert!(F64::<CurrentEnvironment>::from_str("1.5").is_err());
+
+        for (string, expected) in [
+            ("1.5f64", 1.5),
+            ("-0f64", -0.0),
+            ("25e-1f64", 2.5),
+            ("1E3f64", 1000.0),
+            ("inff64", f64::INFINITY),
+            ("-inff64", f64::NEG_INFINITY),
+        ] {
+            assert_eq!(F64::<CurrentEnvironment>::new(expected), F64::from_str(string)?);
+        }
+        assert!(F64::<CurrentEnvironment>::from_str("NaNf64")?.is_nan());
+        Ok(())
+    }
+
+    #[test]
+    fn test_display() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random value, that is not a NaN.
+            let expected: F64<CurrentEnvironment> = Uniform::rand(&mut rng);
+            if expected.is_nan() {
+                continue;
+            }
+
+            // Ensure the display round trips.
+            let candidate = format!("{expected}");
+            assert_eq!(expected, F64::from_str(&candidate)?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> ToBits for F64<E> {
+    /// Outputs the little-endian bit representation of the IEEE-754 encoding of `self`.
+    fn to_bits_le(&self) -> Vec<bool> {
+        let bits = self.to_bits();
+        (0..64).map(|i| (bits >> i) & 1 == 1).collect()
+    }
+
+    /// Outputs the big-endian bit representation of the IEEE-754 encoding of `self`.
+    fn to_bits_be(&self) -> Vec<bool> {
+        let mut bits_le = self.to_bits_le();
+        bits_le.reverse();
+        bits_le
+    }
+}
@@ -32,6 +32,40 @@ impl<E: Environment> ToBytes for Group<E> {
     }
 }
 
+impl<E: Environment> Group<E> {
+    /// Reads the group from a buffer, as either the compressed x-coordinate,
+    /// or the uncompressed `(x, y)` affine coordinates.
+    pub fn read_le_with_compression<R: Read>(mut reader: R, compress: Compress) -> IoResult<Self> {
+        match compress {
+            Compress::Yes => Self::read_le(&mut reader),
+            Compress::No => {
+                // Read the affine coordinates.
+                let x: Field<E> = FromBytes::read_le(&mut reader)?;
+                let y: Field<E> = FromBytes::read_le(&mut reader)?;
+                // Ensure the point is on the curve and in the prime-order subgroup.
+                match E::Affine::from_coordinates((*x, *y)) {
+                    Some(point) => Ok(Self { group: point.into() }),
+                    None => Err(error("Failed to read an invalid group element from (x, y) coordinates")),
+                }
+            }
+        }
+    }
+
+    /// Writes the group to a buffer, as either the compressed x-coordinate,
+    /// or the uncompressed `(x, y)` affine coordinates.
+    pub fn write_le_with_compression<W: Write>(&self, mut writer: W, compress: Compress) -> IoResult<()> {
+        match compress {
+            Compress::Yes => self.write_le(&mut writer),
+            Compress::No => {
+                // Write the affine coordinates.
+                let (x, y) = self.to_xy_coordinates();
+                x.write_le(&mut writer)?;
+                y.write_le(&mut writer)
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -56,4 +90,34 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_with_compression() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new group.
+            let expected = Group::<CurrentEnvironment>::new(Uniform::rand(&mut rng));
+
+            // Check the compressed byte representation.
+            let mut compressed = vec![];
+            expected.write_le_with_compression(&mut compressed, Compress::Yes)?;
+            assert_eq!(expected.to_bytes_le()?, compressed);
+            assert_eq!(expected, Group::read_le_with_compression(&compressed[..], Compress::Yes)?);
+
+            // Check the uncompressed byte representation.
+            let mut uncompressed = vec![];
+            expected.write_le_with_compression(&mut uncompressed, Compress::No)?;
+            assert_eq!(uncompressed.len(), 2 * compressed.len());
+            assert_eq!(expected, Group::read_le_with_compression(&uncompressed[..], Compress::No)?);
+            assert!(Group::<CurrentEnvironment>::read_le_with_compression(&uncompressed[1..], Compress::No).is_err());
+
+            // Ensure an invalid point is rejected.
+            let (x, y) = expected.to_xy_coordinates();
+            let mut invalid = x.to_bytes_le()?;
+            invalid.extend((y + Field::one()).to_bytes_le()?);
+            assert!(Group::<CurrentEnvironment>::read_le_with_compression(&invalid[..], Compress::No).is_err());
+        }
+        Ok(())
+    }
 }
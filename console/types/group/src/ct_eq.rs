@@ -0,0 +1,48 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> ConstantTimeEq for Group<E> {
+    /// Returns `1` if `self` and `other` are equal, without branching on their values.
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.to_x_coordinate().ct_eq(&other.to_x_coordinate()) & self.to_y_coordinate().ct_eq(&other.to_y_coordinate())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_ct_eq() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let a = Group::<CurrentEnvironment>::rand(&mut rng);
+            let b = Group::<CurrentEnvironment>::rand(&mut rng);
+            assert!(bool::from(a.ct_eq(&a)));
+            assert_eq!(a == b, bool::from(a.ct_eq(&b)));
+            // Ensure a point and its negation are not equal.
+            assert!(!bool::from(a.ct_eq(&-a)));
+        }
+    }
+}
@@ -20,6 +20,7 @@
 mod arithmetic;
 mod bitwise;
 mod bytes;
+mod ct_eq;
 mod from_bits;
 mod from_field;
 mod from_fields;
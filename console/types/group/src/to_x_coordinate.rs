@@ -22,3 +22,45 @@ impl<E: Environment> Group<E> {
         Field::new(self.group.to_affine().to_x_coordinate())
     }
 }
+
+impl<E: Environment> Group<E> {
+    /// Returns the *x-coordinates* in the affine coordinates of the given groups.
+    /// The conversion to affine coordinates is amortized with a single batch inversion.
+    pub fn to_x_coordinates(groups: &[Self]) -> Vec<Field<E>> {
+        E::Projective::batch_normalization_into_affine(groups.iter().map(|group| group.group).collect())
+            .into_iter()
+            .map(|group| Field::new(group.to_x_coordinate()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_to_x_coordinates() {
+        let mut rng = TestRng::default();
+
+        // Sample random groups, which are not normalized.
+        let groups: Vec<Group<CurrentEnvironment>> = (0..ITERATIONS)
+            .map(|_| Uniform::rand(&mut rng))
+            .map(|group: Group<_>| group + Group::generator())
+            .collect();
+
+        // Ensure the batched x-coordinates match the individual x-coordinates.
+        let candidates = Group::to_x_coordinates(&groups);
+        assert_eq!(groups.len(), candidates.len());
+        for (group, candidate) in groups.iter().zip(candidates) {
+            assert_eq!(group.to_x_coordinate(), candidate);
+        }
+
+        // Ensure the empty case is handled.
+        assert!(Group::<CurrentEnvironment>::to_x_coordinates(&[]).is_empty());
+    }
+}
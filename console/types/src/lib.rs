@@ -35,6 +35,11 @@ pub use snarkvm_console_types_field as field;
 #[cfg(feature = "field")]
 pub use snarkvm_console_types_field::Field;
 
+#[cfg(feature = "float")]
+pub use snarkvm_console_types_float as float;
+#[cfg(feature = "float")]
+pub use snarkvm_console_types_float::F64;
+
 #[cfg(feature = "group")]
 pub use snarkvm_console_types_group as group;
 #[cfg(feature = "group")]
@@ -34,6 +34,9 @@ pub use poseidon_grain_lfsr::*;
 mod poseidon_default;
 pub use poseidon_default::*;
 
+mod poseidon2_default;
+pub use poseidon2_default::*;
+
 mod prime_field;
 pub use prime_field::*;
 
@@ -0,0 +1,284 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{PoseidonGrainLFSR, PrimeField};
+
+use anyhow::{bail, Result};
+
+/// The maximum number of internal diagonals to sample, before failing to find the parameters.
+const MAX_DIAGONAL_ATTEMPTS: usize = 1000;
+
+/// Parameters for the Poseidon2 permutation.
+///
+/// Poseidon2 replaces the dense MDS matrix of Poseidon with an external matrix for the full rounds,
+/// and a matrix `J + diag(d)` for the partial rounds, where `J` is the all-ones matrix.
+/// See the [Poseidon2 paper](https://eprint.iacr.org/2023/323) for more detail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Poseidon2Parameters<F: PrimeField, const RATE: usize, const CAPACITY: usize> {
+    /// number of rounds in a full-round operation
+    pub full_rounds: usize,
+    /// number of rounds in a partial-round operation
+    pub partial_rounds: usize,
+    /// Exponent used in S-boxes
+    pub alpha: u64,
+    /// Additive round keys of the full rounds, indexed by `external_ark[round_num][state_element_index]`.
+    pub external_ark: Vec<Vec<F>>,
+    /// Additive round keys of the partial rounds, which are only added to the first state element.
+    pub internal_ark: Vec<F>,
+    /// Matrix of the linear layer in the full rounds, which is also applied before the first round.
+    pub external_matrix: Vec<Vec<F>>,
+    /// Diagonal `d` of the linear layer in the partial rounds, whose matrix is `J + diag(d)`.
+    pub internal_diagonal: Vec<F>,
+}
+
+impl<F: PrimeField, const RATE: usize> Poseidon2Parameters<F, RATE, 1> {
+    /// Computes the Poseidon2 parameters for the given S-box exponent and number of rounds.
+    ///
+    /// The round constants are sampled from the Poseidon Grain LFSR, as in the reference implementation,
+    /// where the partial rounds only use the first constant of each round. The internal diagonal is then
+    /// sampled from the LFSR until the characteristic polynomials of `M, M^2, ..., M^(2t)` are irreducible,
+    /// for the internal matrix `M` and state width `t`, which rules out invariant subspace trails.
+    pub fn new(alpha: u64, full_rounds: usize, partial_rounds: usize) -> Result<Self> {
+        let width = RATE + 1;
+        let external_matrix = external_matrix::<F>(width)?;
+
+        // Sample the round constants.
+        let mut lfsr = PoseidonGrainLFSR::new(
+            false,
+            F::size_in_bits() as u64,
+            width as u64,
+            full_rounds as u64,
+            partial_rounds as u64,
+        );
+        let partial_round_range = full_rounds / 2..(full_rounds / 2 + partial_rounds);
+        let mut external_ark = Vec::with_capacity(full_rounds);
+        let mut internal_ark = Vec::with_capacity(partial_rounds);
+        for round in 0..(full_rounds + partial_rounds) {
+            let constants = lfsr.get_field_elements_rejection_sampling::<F>(width)?;
+            match partial_round_range.contains(&round) {
+                true => internal_ark.push(constants[0]),
+                false => external_ark.push(constants),
+            }
+        }
+
+        // Sample the internal diagonal.
+        for _ in 0..MAX_DIAGONAL_ATTEMPTS {
+            let internal_diagonal = lfsr.get_field_elements_mod_p::<F>(width)?;
+            if is_internal_diagonal_secure(&internal_diagonal) {
+                return Ok(Self {
+                    full_rounds,
+                    partial_rounds,
+                    alpha,
+                    external_ark,
+                    internal_ark,
+                    external_matrix,
+                    internal_diagonal,
+                });
+            }
+        }
+        bail!("Failed to find a Poseidon2 internal matrix for a state width of {width}")
+    }
+}
+
+/// Returns the external matrix of Poseidon2 for the given state width.
+/// The widths 2 and 3 use `circ(2, 1, ...)`, and multiples of 4 use `circ(2 * M4, M4, ...)`.
+fn external_matrix<F: PrimeField>(width: usize) -> Result<Vec<Vec<F>>> {
+    const M4: [[u64; 4]; 4] = [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]];
+
+    match width {
+        2 | 3 => {
+            Ok((0..width).map(|i| (0..width).map(|j| F::from(if i == j { 2u64 } else { 1u64 })).collect()).collect())
+        }
+        width if width % 4 == 0 => Ok((0..width)
+            .map(|i| {
+                (0..width)
+                    .map(|j| {
+                        let entry = F::from(M4[i % 4][j % 4]);
+                        if i / 4 == j / 4 && width > 4 {
+                            entry.double()
+                        } else {
+                            entry
+                        }
+                    })
+                    .collect()
+            })
+            .collect()),
+        _ => bail!("Poseidon2 does not support a state width of {width}"),
+    }
+}
+
+/// Returns `true` if, for the internal matrix `M = J + diag(d)` of the given diagonal `d` and state width `t`,
+/// the characteristic polynomials of `M, M^2, ..., M^(2t)` are irreducible.
+/// Note: As each polynomial is of degree `t`, it is then also the minimal polynomial of the matrix.
+fn is_internal_diagonal_secure<F: PrimeField>(diagonal: &[F]) -> bool {
+    let width = diagonal.len();
+    let matrix = (0..width)
+        .map(|i| (0..width).map(|j| if i == j { F::one() + diagonal[i] } else { F::one() }).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    let mut power = matrix.clone();
+    for _ in 0..(2 * width) {
+        if !is_irreducible(&characteristic_polynomial(&power)) {
+            return false;
+        }
+        power = matrix_mul(&matrix, &power);
+    }
+    true
+}
+
+/// Returns the product of the given square matrices.
+fn matrix_mul<F: PrimeField>(a: &[Vec<F>], b: &[Vec<F>]) -> Vec<Vec<F>> {
+    (0..a.len()).map(|i| (0..b.len()).map(|j| (0..b.len()).map(|k| a[i][k] * b[k][j]).sum()).collect()).collect()
+}
+
+/// Returns the coefficients of the characteristic polynomial of the given square matrix,
+/// in order of increasing degree, using the Faddeev-LeVerrier algorithm.
+fn characteristic_polynomial<F: PrimeField>(matrix: &[Vec<F>]) -> Vec<F> {
+    let n = matrix.len();
+    let mut coefficients = vec![F::zero(); n + 1];
+    coefficients[n] = F::one();
+
+    // Iteratively compute `M_k := A * M_{k-1} + c_{n-k+1} * I` and `c_{n-k} := -tr(A * M_k) / k`.
+    let mut m = vec![vec![F::zero(); n]; n];
+    for k in 1..=n {
+        m = matrix_mul(matrix, &m);
+        for (i, row) in m.iter_mut().enumerate() {
+            row[i] += coefficients[n - k + 1];
+        }
+        let trace: F = matrix_mul(matrix, &m).iter().enumerate().map(|(i, row)| row[i]).sum();
+        // Note: The inverse exists, as `k` is less than the characteristic of the field.
+        coefficients[n - k] = -trace * F::from(k as u64).inverse().unwrap_or_else(F::zero);
+    }
+    coefficients
+}
+
+/// Returns `true` if the given monic polynomial is irreducible, using Rabin's test.
+///
+/// A monic polynomial `f` of degree `n` is irreducible if and only if `x^(p^n) = x mod f`,
+/// and `gcd(x^(p^(n/q)) - x, f) = 1` for every prime divisor `q` of `n`.
+fn is_irreducible<F: PrimeField>(f: &[F]) -> bool {
+    let n = f.len() - 1;
+    if n <= 1 {
+        return true;
+    }
+
+    // Compute `x^(p^k) mod f` for `k = 1, ..., n`.
+    let x = vec![F::zero(), F::one()];
+    let mut powers = Vec::with_capacity(n);
+    let mut current = x.clone();
+    for _ in 0..n {
+        current = poly_pow_characteristic_mod(&current, f);
+        powers.push(current.clone());
+    }
+
+    // Ensure `x^(p^n) = x mod f`.
+    if !poly_sub(&powers[n - 1], &x).is_empty() {
+        return false;
+    }
+    // Ensure `gcd(x^(p^(n/q)) - x, f) = 1` for every prime divisor `q` of `n`.
+    prime_divisors(n).into_iter().all(|q| poly_gcd(&poly_sub(&powers[n / q - 1], &x), f).len() == 1)
+}
+
+/// Returns the prime divisors of the given integer.
+fn prime_divisors(mut n: usize) -> Vec<usize> {
+    let mut divisors = Vec::new();
+    let mut candidate = 2;
+    while candidate * candidate <= n {
+        if n % candidate == 0 {
+            divisors.push(candidate);
+            while n % candidate == 0 {
+                n /= candidate;
+            }
+        }
+        candidate += 1;
+    }
+    if n > 1 {
+        divisors.push(n);
+    }
+    divisors
+}
+
+/// Returns `a^p mod f`, for the characteristic `p` of the field and the monic polynomial `f`.
+fn poly_pow_characteristic_mod<F: PrimeField>(a: &[F], f: &[F]) -> Vec<F> {
+    let mut result = vec![F::one()];
+    for limb in F::characteristic().iter().rev() {
+        for i in (0..64).rev() {
+            result = poly_mul_mod(&result, &result, f);
+            if (limb >> i) & 1 == 1 {
+                result = poly_mul_mod(&result, a, f);
+            }
+        }
+    }
+    result
+}
+
+/// Returns `a * b mod f`, for the monic polynomial `f`.
+fn poly_mul_mod<F: PrimeField>(a: &[F], b: &[F], f: &[F]) -> Vec<F> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let mut product = vec![F::zero(); a.len() + b.len() - 1];
+    for (i, a_i) in a.iter().enumerate() {
+        for (j, b_j) in b.iter().enumerate() {
+            product[i + j] += *a_i * b_j;
+        }
+    }
+    poly_rem(product, f)
+}
+
+/// Returns `a - b`.
+fn poly_sub<F: PrimeField>(a: &[F], b: &[F]) -> Vec<F> {
+    let mut difference = vec![F::zero(); a.len().max(b.len())];
+    a.iter().enumerate().for_each(|(i, a_i)| difference[i] += a_i);
+    b.iter().enumerate().for_each(|(i, b_i)| difference[i] -= b_i);
+    poly_trim(difference)
+}
+
+/// Returns `a mod b`, for a nonzero polynomial `b` with no leading zeros.
+fn poly_rem<F: PrimeField>(a: Vec<F>, b: &[F]) -> Vec<F> {
+    let mut remainder = poly_trim(a);
+    let leading_inverse = b[b.len() - 1].inverse().unwrap_or_else(F::zero);
+    while remainder.len() >= b.len() {
+        let shift = remainder.len() - b.len();
+        let factor = remainder[remainder.len() - 1] * leading_inverse;
+        for (i, b_i) in b.iter().enumerate() {
+            remainder[shift + i] -= factor * b_i;
+        }
+        // Note: The leading coefficient is now zero, so it is removed.
+        remainder.pop();
+        remainder = poly_trim(remainder);
+    }
+    remainder
+}
+
+/// Returns the greatest common divisor of `a` and `b`, up to a constant factor.
+fn poly_gcd<F: PrimeField>(a: &[F], b: &[F]) -> Vec<F> {
+    let (mut a, mut b) = (poly_trim(a.to_vec()), poly_trim(b.to_vec()));
+    while !b.is_empty() {
+        let remainder = poly_rem(a, &b);
+        a = b;
+        b = remainder;
+    }
+    a
+}
+
+/// Returns the given polynomial without its leading zero coefficients.
+fn poly_trim<F: PrimeField>(mut a: Vec<F>) -> Vec<F> {
+    while a.last().map_or(false, |coefficient| coefficient.is_zero()) {
+        a.pop();
+    }
+    a
+}
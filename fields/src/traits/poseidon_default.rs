@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{serial_batch_inversion_and_mul, PoseidonGrainLFSR, PrimeField};
+use crate::{serial_batch_inversion_and_mul, Poseidon2Parameters, PoseidonGrainLFSR, PrimeField};
 use aleo_std::{end_timer, start_timer};
 use itertools::Itertools;
 
@@ -109,6 +109,18 @@ pub trait PoseidonDefaultField {
             None => bail!("No Poseidon parameters were found for this rate"),
         }
     }
+
+    /// Obtain the default Poseidon2 parameters for this rate and for this prime field.
+    /// Note: Poseidon2 uses the same S-box and number of rounds as the default Poseidon parameters.
+    fn default_poseidon2_parameters<const RATE: usize>() -> Result<Poseidon2Parameters<Self, RATE, 1>>
+    where
+        Self: PrimeField,
+    {
+        match Self::Parameters::PARAMS_OPT_FOR_CONSTRAINTS.iter().find(|entry| entry.rate == RATE) {
+            Some(entry) => Poseidon2Parameters::new(entry.alpha as u64, entry.full_rounds, entry.partial_rounds),
+            None => bail!("No Poseidon2 parameters were found for this rate"),
+        }
+    }
 }
 
 /// A trait for default Poseidon parameters associated with a prime field
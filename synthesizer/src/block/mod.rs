@@ -17,6 +17,9 @@
 mod header;
 pub use header::*;
 
+mod prover_job;
+pub use prover_job::*;
+
 mod transaction;
 pub use transaction::*;
 
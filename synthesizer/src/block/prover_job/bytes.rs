@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> FromBytes for ProverJob<N> {
+    /// Reads the prover job from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u16::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 0 {
+            return Err(error("Invalid prover job version"));
+        }
+        // Read the authorization.
+        let authorization = Authorization::read_le(&mut reader)?;
+        // Read the fee authorization variant.
+        let fee_variant = u8::read_le(&mut reader)?;
+        // Read the fee authorization.
+        let fee_authorization = match fee_variant {
+            0 => None,
+            1 => Some(Authorization::read_le(&mut reader)?),
+            _ => return Err(error(format!("Invalid fee authorization variant '{fee_variant}'"))),
+        };
+        // Return the new `ProverJob` instance.
+        Self::new(authorization, fee_authorization).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for ProverJob<N> {
+    /// Writes the prover job to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        0u16.write_le(&mut writer)?;
+        // Write the authorization.
+        self.authorization.write_le(&mut writer)?;
+        // Write the fee authorization.
+        match &self.fee_authorization {
+            None => 0u8.write_le(&mut writer),
+            Some(fee_authorization) => {
+                1u8.write_le(&mut writer)?;
+                fee_authorization.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for expected in test_helpers::sample_prover_jobs(rng) {
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le()?;
+            let candidate = ProverJob::read_le(&expected_bytes[..])?;
+            assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+            assert!(ProverJob::<console::network::Testnet3>::read_le(&expected_bytes[1..]).is_err());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,236 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use crate::{
+    block::Transition,
+    process::{Authorization, Execution, Fee},
+};
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID},
+};
+
+/// A delegated proving job, holding the authorizations for a remote prover to execute,
+/// without access to the private key of the caller.
+#[derive(Clone)]
+pub struct ProverJob<N: Network> {
+    /// The authorization of the execution.
+    authorization: Authorization<N>,
+    /// The authorization of the fee, if one is present.
+    fee_authorization: Option<Authorization<N>>,
+}
+
+impl<N: Network> ProverJob<N> {
+    /// Initializes a new prover job, with the given authorization and optional fee authorization.
+    pub fn new(authorization: Authorization<N>, fee_authorization: Option<Authorization<N>>) -> Result<Self> {
+        // Ensure the authorization is not empty.
+        ensure!(!authorization.is_empty(), "Prover job requires a non-empty authorization");
+        // Ensure the fee authorization, if present, is a single call to the fee function.
+        if let Some(fee_authorization) = &fee_authorization {
+            ensure!(fee_authorization.len() == 1, "Prover job fee authorization must contain 1 request");
+            let request = fee_authorization.peek_next()?;
+            let (program_id, function_name) = (ProgramID::from_str("credits.aleo")?, Identifier::from_str("fee")?);
+            ensure!(
+                request.program_id() == &program_id && request.function_name() == &function_name,
+                "Prover job fee authorization must be a call to 'credits.aleo/fee'"
+            );
+        }
+        Ok(Self { authorization, fee_authorization })
+    }
+
+    /// Returns the authorization of the execution.
+    pub const fn authorization(&self) -> &Authorization<N> {
+        &self.authorization
+    }
+
+    /// Returns the authorization of the fee, if one is present.
+    pub const fn fee_authorization(&self) -> Option<&Authorization<N>> {
+        self.fee_authorization.as_ref()
+    }
+
+    /// Returns the program ID of the main function.
+    pub fn program_id(&self) -> Result<ProgramID<N>> {
+        Ok(*self.authorization.peek_next()?.program_id())
+    }
+
+    /// Returns the name of the main function.
+    pub fn function_name(&self) -> Result<Identifier<N>> {
+        Ok(*self.authorization.peek_next()?.function_name())
+    }
+
+    /// Ensures the given execution contains exactly the authorized transitions.
+    pub fn verify_execution(&self, execution: &Execution<N>) -> Result<()> {
+        verify_transitions(&self.authorization, execution.transitions())
+    }
+
+    /// Ensures the given fee is the authorized fee transition.
+    pub fn verify_fee(&self, fee: &Fee<N>) -> Result<()> {
+        match &self.fee_authorization {
+            Some(fee_authorization) => verify_transitions(fee_authorization, std::iter::once(fee.transition())),
+            None => bail!("Prover job does not contain a fee authorization"),
+        }
+    }
+}
+
+/// Ensures each of the given transitions corresponds to exactly one authorized request,
+/// by matching the transition commitment, program ID, and function name.
+fn verify_transitions<'a, N: Network>(
+    authorization: &Authorization<N>,
+    transitions: impl ExactSizeIterator<Item = &'a Transition<N>>,
+) -> Result<()> {
+    let mut requests = authorization.to_vec_deque();
+    // Ensure the number of transitions matches the number of requests.
+    ensure!(
+        transitions.len() == requests.len(),
+        "Expected {} transitions, found {}",
+        requests.len(),
+        transitions.len()
+    );
+    for transition in transitions {
+        // Find the request with the same transition commitment.
+        let index = requests
+            .iter()
+            .position(|request| request.tcm() == transition.tcm())
+            .ok_or_else(|| anyhow!("Transition '{}' is not authorized", transition.id()))?;
+        // Remove the request, so it is matched at most once.
+        let request = requests.remove(index).ok_or_else(|| anyhow!("Missing request at index {index}"))?;
+        // Ensure the transition calls the authorized function.
+        ensure!(
+            request.program_id() == transition.program_id() && request.function_name() == transition.function_name(),
+            "Transition '{}' calls '{}/{}', but '{}/{}' was authorized",
+            transition.id(),
+            transition.program_id(),
+            transition.function_name(),
+            request.program_id(),
+            request.function_name()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use crate::process::test_helpers::{sample_fee_request, sample_mint_request};
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples prover jobs, with and without a fee authorization.
+    pub(crate) fn sample_prover_jobs(rng: &mut TestRng) -> Vec<ProverJob<CurrentNetwork>> {
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        vec![
+            ProverJob::new(Authorization::new(&[sample_mint_request(&private_key, 1, rng)]), None).unwrap(),
+            ProverJob::new(
+                Authorization::new(&[sample_mint_request(&private_key, 5, rng)]),
+                Some(Authorization::new(&[sample_fee_request(&private_key, rng)])),
+            )
+            .unwrap(),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{process::test_helpers::sample_mint_request, Transaction};
+    use console::{
+        account::{Address, PrivateKey, ViewKey},
+        network::Testnet3,
+        program::Value,
+    };
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_new() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Ensure the accessors return the main function.
+        for job in test_helpers::sample_prover_jobs(rng) {
+            let request = job.authorization().peek_next()?;
+            assert_eq!(*request.program_id(), job.program_id()?);
+            assert_eq!(*request.function_name(), job.function_name()?);
+        }
+
+        // Ensure an empty authorization is rejected.
+        assert!(ProverJob::<CurrentNetwork>::new(Authorization::new(&[]), None).is_err());
+        // Ensure a fee authorization that is not a call to the fee function is rejected.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let authorization = Authorization::new(&[sample_mint_request(&private_key, 1, rng)]);
+        assert!(ProverJob::new(authorization.replicate(), Some(authorization)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_execution_rejects_mismatch() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for job in test_helpers::sample_prover_jobs(rng) {
+            // Ensure an empty execution does not match the authorized transitions.
+            assert!(job.verify_execution(&Execution::new()).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_execute_prover_job() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Initialize the caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key)?;
+        let caller_address = Address::try_from(&caller_private_key)?;
+
+        // Initialize the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+
+        // Fetch the unspent records.
+        let records = genesis.records().collect::<indexmap::IndexMap<_, _>>();
+        let credits = records.values().next().unwrap().decrypt(&caller_view_key)?;
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Update the VM.
+        vm.add_next_block(&genesis)?;
+
+        // Authorize the execution and the fee, as the caller.
+        let authorization = vm.authorize(
+            &caller_private_key,
+            "credits.aleo",
+            "mint",
+            [Value::<CurrentNetwork>::from_str(&caller_address.to_string())?, Value::from_str("1u64")?].into_iter(),
+            rng,
+        )?;
+        let fee_authorization = vm.authorize_fee(&caller_private_key, credits, 1, rng)?;
+
+        // Construct the prover job, and relay it to the prover.
+        let job = ProverJob::new(authorization, Some(fee_authorization))?;
+        let job = ProverJob::<CurrentNetwork>::from_bytes_le(&job.to_bytes_le()?)?;
+
+        // Prove the job, without the caller's private key.
+        let transaction = Transaction::execute_prover_job(&vm, &job, None, rng)?;
+        assert!(matches!(&transaction, Transaction::Execute(_, execution, Some(_)) if execution.len() == 1));
+
+        // Ensure the resulting transaction is valid.
+        assert!(vm.verify_transaction(&transaction));
+        Ok(())
+    }
+}
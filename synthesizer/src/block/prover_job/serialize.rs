@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use snarkvm_utilities::DeserializeExt;
+
+impl<N: Network> Serialize for ProverJob<N> {
+    /// Serializes the prover job into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut job =
+                    serializer.serialize_struct("ProverJob", 1 + self.fee_authorization.is_some() as usize)?;
+                job.serialize_field("authorization", &self.authorization)?;
+                if let Some(fee_authorization) = &self.fee_authorization {
+                    job.serialize_field("fee_authorization", fee_authorization)?;
+                }
+                job.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for ProverJob<N> {
+    /// Deserializes the prover job from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the prover job from a string into a value.
+                let mut job = serde_json::Value::deserialize(deserializer)?;
+                // Recover the prover job.
+                Self::new(
+                    // Retrieve the authorization.
+                    DeserializeExt::take_from_value::<D>(&mut job, "authorization")?,
+                    // Retrieve the fee authorization, if it is present.
+                    match job.get("fee_authorization") {
+                        Some(fee_authorization) => {
+                            Some(serde_json::from_value(fee_authorization.clone()).map_err(de::Error::custom)?)
+                        }
+                        None => None,
+                    },
+                )
+                .map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "prover job"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for expected in test_helpers::sample_prover_jobs(rng) {
+            // Serialize
+            let expected_string = &expected.to_string();
+            let candidate_string = serde_json::to_string(&expected)?;
+
+            // Deserialize
+            assert_eq!(expected.to_bytes_le()?, ProverJob::from_str(expected_string)?.to_bytes_le()?);
+            assert_eq!(
+                expected.to_bytes_le()?,
+                serde_json::from_str::<ProverJob<_>>(&candidate_string)?.to_bytes_le()?
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for expected in test_helpers::sample_prover_jobs(rng) {
+            // Serialize
+            let expected_bytes = expected.to_bytes_le()?;
+            let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+            assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+            // Deserialize
+            assert_eq!(expected_bytes, ProverJob::read_le(&expected_bytes[..])?.to_bytes_le()?);
+            assert_eq!(
+                expected_bytes,
+                bincode::deserialize::<ProverJob<_>>(&expected_bytes_with_size_encoding[..])?.to_bytes_le()?
+            );
+        }
+        Ok(())
+    }
+}
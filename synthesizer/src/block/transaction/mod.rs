@@ -20,7 +20,7 @@ mod serialize;
 mod string;
 
 use crate::{
-    block::Transition,
+    block::{ProverJob, Transition},
     process::{Authorization, Deployment, Execution, Fee},
     program::Program,
     vm::VM,
@@ -131,6 +131,51 @@ impl<N: Network> Transaction<N> {
         Self::from_execution(execution, additional_fee)
     }
 
+    /// Initializes a new execution transaction from an authorization, and a fee authorization.
+    /// The fee authorization may be signed by a sponsor, who pays the fee on behalf of the caller.
+    /// Note: The fee is not bound to the execution, so the sponsor must trust the party relaying the fee authorization.
+    pub fn execute_authorization_with_sponsored_fee<C: ConsensusStorage<N>, R: Rng + CryptoRng>(
+        vm: &VM<N, C>,
+        authorization: Authorization<N>,
+        fee_authorization: Authorization<N>,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Compute the execution.
+        let (_response, execution, _metrics) = vm.execute(authorization, query.clone(), rng)?;
+        // Compute the sponsored fee.
+        let (_response, fee, _metrics) = vm.execute_fee_authorization(fee_authorization, query, rng)?;
+        // Initialize the transaction.
+        Self::from_execution(execution, Some(fee))
+    }
+
+    /// Initializes a new execution transaction from the given prover job.
+    /// This ensures the resulting execution and fee match the authorized transitions.
+    pub fn execute_prover_job<C: ConsensusStorage<N>, R: Rng + CryptoRng>(
+        vm: &VM<N, C>,
+        job: &ProverJob<N>,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Compute the execution.
+        let (_response, execution, _metrics) = vm.execute(job.authorization().replicate(), query.clone(), rng)?;
+        // Ensure the execution matches the authorized transitions.
+        job.verify_execution(&execution)?;
+        // Compute the fee, if it is present.
+        let fee = match job.fee_authorization() {
+            Some(fee_authorization) => {
+                let (_response, fee, _metrics) =
+                    vm.execute_fee_authorization(fee_authorization.replicate(), query, rng)?;
+                // Ensure the fee matches the authorized transition.
+                job.verify_fee(&fee)?;
+                Some(fee)
+            }
+            None => None,
+        };
+        // Initialize the transaction.
+        Self::from_execution(execution, fee)
+    }
+
     /// Initializes a new execution transaction.
     #[allow(clippy::too_many_arguments)]
     pub fn execute<C: ConsensusStorage<N>, R: Rng + CryptoRng>(
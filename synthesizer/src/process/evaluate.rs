@@ -18,6 +18,7 @@ use super::*;
 
 impl<N: Network> Process<N> {
     /// Evaluates a program function on the given request.
+    /// Note: Evaluation runs on console values, and does not synthesize a circuit.
     #[inline]
     pub fn evaluate<A: circuit::Aleo<Network = N>>(&self, authorization: Authorization<N>) -> Result<Response<N>> {
         let timer = timer!("Process::evaluate");
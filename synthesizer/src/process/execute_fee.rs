@@ -26,7 +26,29 @@ impl<N: Network> Process<N> {
         fee_in_gates: u64,
         rng: &mut R,
     ) -> Result<(Response<N>, Transition<N>, Inclusion<N>, Vec<CallMetrics<N>>)> {
-        let timer = timer!("Process::execute_fee");
+        // Authorize the fee.
+        let authorization = self.authorize_fee::<A, R>(private_key, credits, fee_in_gates, rng)?;
+        // Execute the fee.
+        self.execute_fee_authorization::<A, R>(authorization, rng)
+    }
+
+    /// Authorizes the fee given the credits record and the fee amount (in gates).
+    ///
+    /// The fee authorization may be signed by a party other than the caller of the execution
+    /// (e.g. a sponsor paying fees on behalf of a user), as it is executed independently.
+    ///
+    /// Note: The fee is not bound to the deployment or execution it pays for, so a fee authorization
+    /// may be attached to any transaction. Binding it requires an additional input on `credits.aleo/fee`,
+    /// and with it, new fee proving and verifying keys.
+    #[inline]
+    pub fn authorize_fee<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        credits: Record<N, Plaintext<N>>,
+        fee_in_gates: u64,
+        rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        let timer = timer!("Process::authorize_fee");
 
         // Ensure the fee has the correct program ID.
         let program_id = ProgramID::from_str("credits.aleo")?;
@@ -46,12 +68,37 @@ impl<N: Network> Process<N> {
         lap!(timer, "Initialize the authorization");
         // Construct the call stack.
         let call_stack = CallStack::Authorize(vec![request], *private_key, authorization.clone());
-        // Construct the authorization from the function.
-        let _response = self.get_stack(program_id)?.execute_function::<A, R>(call_stack, rng)?;
+        // Construct the authorization from the function, computing only the witnesses of the circuit.
+        let stack = self.get_stack(program_id)?;
+        let _response = A::witness_only(|| stack.execute_function::<A, R>(call_stack, rng))?;
         lap!(timer, "Construct the authorization from the function");
 
+        finish!(timer);
+
+        Ok(authorization)
+    }
+
+    /// Executes the fee given the fee authorization.
+    #[inline]
+    pub fn execute_fee_authorization<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        authorization: Authorization<N>,
+        rng: &mut R,
+    ) -> Result<(Response<N>, Transition<N>, Inclusion<N>, Vec<CallMetrics<N>>)> {
+        let timer = timer!("Process::execute_fee_authorization");
+
+        // Ensure the fee has the correct program ID.
+        let program_id = ProgramID::from_str("credits.aleo")?;
+        // Ensure the fee has the correct function.
+        let function_name = Identifier::from_str("fee")?;
+
+        // Ensure the authorization contains 1 request.
+        ensure!(authorization.len() == 1, "The fee authorization must contain 1 request");
         // Retrieve the main request (without popping it).
         let request = authorization.peek_next()?;
+        // Ensure the request is a call to the fee function.
+        ensure!(*request.program_id() == program_id, "Incorrect program ID for the fee authorization");
+        ensure!(*request.function_name() == function_name, "Incorrect function name for the fee authorization");
         // Prepare the stack.
         let stack = self.get_stack(request.program_id())?;
 
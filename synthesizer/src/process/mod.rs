@@ -274,13 +274,29 @@ impl<N: Network> Process<N> {
         // Synthesize the proving and verifying key.
         self.get_stack(program_id)?.synthesize_key::<A, R>(function_name, rng)
     }
+
+    /// Returns the circuit digest for the given program ID and function name.
+    #[inline]
+    pub fn circuit_digest<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<[u8; 32]> {
+        // Compute the circuit digest.
+        self.get_stack(program_id)?.circuit_digest::<A, R>(function_name, rng)
+    }
 }
 
 #[cfg(test)]
 pub(crate) mod test_helpers {
     use super::*;
     use crate::{Process, Program, Transition};
-    use console::{account::PrivateKey, network::Testnet3, program::Identifier};
+    use console::{
+        account::{Address, PrivateKey},
+        network::Testnet3,
+        program::{Identifier, ProgramID, Request, Value, ValueType},
+    };
 
     use once_cell::sync::OnceCell;
 
@@ -398,6 +414,46 @@ function compute:
         // Return the process.
         process
     }
+
+    /// Samples a signed request to `credits.aleo/mint`, minting the given amount to the caller.
+    pub(crate) fn sample_mint_request(
+        private_key: &PrivateKey<CurrentNetwork>,
+        amount: u64,
+        rng: &mut TestRng,
+    ) -> Request<CurrentNetwork> {
+        // Construct the inputs and input types.
+        let address = Address::try_from(private_key).unwrap();
+        let inputs =
+            [Value::from_str(&address.to_string()).unwrap(), Value::from_str(&format!("{amount}u64")).unwrap()];
+        let input_types =
+            [ValueType::from_str("address.private").unwrap(), ValueType::from_str("u64.private").unwrap()];
+
+        // Compute the signed request.
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let function_name = Identifier::from_str("mint").unwrap();
+        Request::sign(private_key, program_id, function_name, inputs.into_iter(), &input_types, rng).unwrap()
+    }
+
+    /// Samples a signed request to `credits.aleo/fee`.
+    pub(crate) fn sample_fee_request(
+        private_key: &PrivateKey<CurrentNetwork>,
+        rng: &mut TestRng,
+    ) -> Request<CurrentNetwork> {
+        // Prepare a record belonging to the caller.
+        let address = Address::try_from(private_key).unwrap();
+        let record_string = format!(
+            "{{ owner: {address}.private, gates: 3u64.private, _nonce: 2293253577170800572742339369209137467208538700597121244293392265726446806023group.public }}"
+        );
+
+        // Construct the inputs and input types.
+        let inputs = [Value::from_str(&record_string).unwrap(), Value::from_str("1u64").unwrap()];
+        let input_types = [ValueType::from_str("credits.record").unwrap(), ValueType::from_str("u64.private").unwrap()];
+
+        // Compute the signed request.
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let function_name = Identifier::from_str("fee").unwrap();
+        Request::sign(private_key, program_id, function_name, inputs.into_iter(), &input_types, rng).unwrap()
+    }
 }
 
 #[cfg(test)]
@@ -516,10 +572,11 @@ mod tests {
             .unwrap();
         let result = process.execute::<CurrentAleo, _>(authorization, rng);
         assert!(result.is_err());
-        assert_eq!(
-            result.err().unwrap().to_string(),
-            format!("'token.aleo/mint' is not satisfied on the given inputs (26610 constraints).")
-        );
+        // Ensure the error surfaces the first unsatisfied constraint.
+        let error = result.err().unwrap().to_string();
+        assert!(error
+            .starts_with("'token.aleo/mint' is not satisfied on the given inputs (26610 constraints).\nConstraint "));
+        assert!(error.contains("is not satisfied: ("));
     }
 
     #[test]
@@ -546,6 +603,48 @@ function hello_world:
         process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, &mut TestRng::default()).unwrap();
     }
 
+    #[test]
+    fn test_process_circuit_digest() {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r#"program testing.aleo;
+
+function hello_world:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    output r2 as u32.private;
+
+function goodbye_world:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    mul r0 r1 into r2;
+    output r2 as u32.private;
+"#,
+        )
+        .unwrap();
+
+        // Declare the function names.
+        let hello_world = Identifier::from_str("hello_world").unwrap();
+        let goodbye_world = Identifier::from_str("goodbye_world").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program);
+
+        // Compute the circuit digests, and ensure they are deterministic and distinct.
+        let digest = process.circuit_digest::<CurrentAleo, _>(program.id(), &hello_world, rng).unwrap();
+        assert_eq!(digest, process.circuit_digest::<CurrentAleo, _>(program.id(), &hello_world, rng).unwrap());
+        assert_ne!(digest, process.circuit_digest::<CurrentAleo, _>(program.id(), &goodbye_world, rng).unwrap());
+
+        // Ensure the digest matches the synthesized proving key.
+        process.synthesize_key::<CurrentAleo, _>(program.id(), &hello_world, rng).unwrap();
+        let proving_key = process.get_proving_key(program.id(), hello_world).unwrap();
+        assert_eq!(digest, proving_key.circuit_digest().unwrap());
+    }
+
     #[test]
     fn test_process_multirecords() {
         // Initialize a new program.
@@ -729,6 +828,104 @@ function hello_world:
         process.verify_execution::<false>(&execution).unwrap();
     }
 
+    #[test]
+    fn test_process_execute_profile() {
+        use circuit::Environment;
+
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"program profile.aleo;
+
+  function compute:
+    input r0 as field.private;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    hash.psd2 r2 into r3;
+    output r3 as field.private;",
+        )
+        .unwrap();
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program);
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Declare the input values.
+        let inputs = [Value::<CurrentNetwork>::from_str("1field").unwrap(), Value::from_str("2field").unwrap()];
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+            .unwrap();
+
+        // Execute the request, with the profiler enabled.
+        CurrentAleo::enable_profiler();
+        process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        let profile = CurrentAleo::disable_profiler();
+
+        // Ensure the constraints of the hash are attributed to its instruction.
+        let hash = profile.get("profile.aleo/compute[1] hash.psd2 r2 into r3;").unwrap();
+        assert!(hash.num_constraints > 0);
+        assert!(hash.num_private > 0);
+        // Ensure the addition of two field elements is free.
+        assert!(profile.get("profile.aleo/compute[0] add r0 r1 into r2;").is_none());
+        // Ensure the remaining constraints are attributed to the transition itself.
+        assert!(profile.get("").unwrap().num_constraints > 0);
+    }
+
+    #[test]
+    fn test_process_authorize_is_witness_only() {
+        use circuit::Environment;
+
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"program witness.aleo;
+
+  function compute:
+    input r0 as field.private;
+    input r1 as field.private;
+    mul r0 r1 into r2;
+    hash.psd2 r2 into r3;
+    output r3 as field.private;",
+        )
+        .unwrap();
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program);
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Declare the input values.
+        let inputs = [Value::<CurrentNetwork>::from_str("1field").unwrap(), Value::from_str("2field").unwrap()];
+
+        // Authorize the function call, with the profiler enabled.
+        CurrentAleo::enable_profiler();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+            .unwrap();
+        let profile = CurrentAleo::disable_profiler();
+        assert_eq!(1, authorization.len());
+
+        // Ensure the witnesses were computed, without allocating any constraints.
+        assert!(profile.total("").num_private > 0);
+        assert_eq!(0, profile.total("").num_constraints);
+        assert!(!CurrentAleo::is_witness_only());
+    }
+
     #[test]
     fn test_process_program_id() {
         // Initialize a new program.
@@ -0,0 +1,145 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Authorization<N> {
+    /// Returns the serial numbers of the input records in the authorization.
+    pub fn serial_numbers(&self) -> Vec<Field<N>> {
+        self.requests
+            .read()
+            .iter()
+            .flat_map(|request| request.input_ids().iter())
+            .filter_map(|input_id| match input_id {
+                InputID::Record(_, _, serial_number, _) => Some(*serial_number),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns a Bloom filter of `num_bits` bits over the serial numbers of the input records,
+    /// using `num_hashes` hash functions. This allows a light client to quickly test whether
+    /// a candidate serial number *may* be spent by this authorization.
+    pub fn serial_number_bloom(&self, num_bits: usize, num_hashes: usize) -> Result<Vec<u8>> {
+        // Ensure the Bloom filter parameters are valid.
+        ensure!(num_bits > 0, "The Bloom filter must contain at least one bit");
+        ensure!(num_hashes > 0, "The Bloom filter must use at least one hash function");
+
+        // Insert each serial number into the Bloom filter.
+        let mut bloom = vec![0u8; (num_bits + 7) / 8];
+        for serial_number in self.serial_numbers() {
+            for index in bloom_indices(&serial_number, num_bits, num_hashes)? {
+                bloom[index / 8] |= 1 << (index % 8);
+            }
+        }
+        Ok(bloom)
+    }
+
+    /// Returns `true` if the given serial number *may* be in the Bloom filter,
+    /// and `false` if it is definitely not.
+    pub fn serial_number_bloom_contains(
+        bloom: &[u8],
+        num_bits: usize,
+        num_hashes: usize,
+        serial_number: &Field<N>,
+    ) -> Result<bool> {
+        // Ensure the Bloom filter parameters are valid.
+        ensure!(num_bits > 0, "The Bloom filter must contain at least one bit");
+        ensure!(num_hashes > 0, "The Bloom filter must use at least one hash function");
+        ensure!(bloom.len() == (num_bits + 7) / 8, "The Bloom filter does not contain {num_bits} bits");
+
+        // Check the serial number against the Bloom filter.
+        Ok(bloom_indices(serial_number, num_bits, num_hashes)?.all(|index| bloom[index / 8] & (1 << (index % 8)) != 0))
+    }
+}
+
+/// Returns the Bloom filter indices of the given serial number.
+///
+/// As the serial number is itself a hash output, the indices are derived by double hashing,
+/// where `index_i := (h_1 + i * h_2) mod num_bits` for the first two 64-bit words of the serial number.
+fn bloom_indices<N: Network>(
+    serial_number: &Field<N>,
+    num_bits: usize,
+    num_hashes: usize,
+) -> Result<impl Iterator<Item = usize>> {
+    // Retrieve the first two 64-bit words of the serial number.
+    let bytes = serial_number.to_bytes_le()?;
+    let h_1 = u64::from_le_bytes(bytes[0..8].try_into()?);
+    let h_2 = u64::from_le_bytes(bytes[8..16].try_into()?);
+    // Compute the indices.
+    let num_bits = num_bits as u64;
+    Ok((0..num_hashes as u64).map(move |i| (h_1.wrapping_add(i.wrapping_mul(h_2)) % num_bits) as usize))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::stack::authorization::test_helpers::{sample_record_request, sample_request};
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_serial_number_bloom() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct an authorization with several record inputs.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let authorization = Authorization::new(&[
+            sample_record_request(&private_key, 1, rng),
+            sample_request(&private_key, 2, rng),
+            sample_record_request(&private_key, 3, rng),
+            sample_record_request(&private_key, 4, rng),
+        ]);
+        let serial_numbers = authorization.serial_numbers();
+        assert_eq!(3, serial_numbers.len());
+
+        for (num_bits, num_hashes) in [(1, 1), (13, 2), (256, 3), (1024, 7)] {
+            let bloom = authorization.serial_number_bloom(num_bits, num_hashes)?;
+            assert_eq!((num_bits + 7) / 8, bloom.len());
+
+            // Ensure all actual serial numbers test positive.
+            for serial_number in &serial_numbers {
+                assert!(Authorization::serial_number_bloom_contains(&bloom, num_bits, num_hashes, serial_number)?);
+            }
+        }
+
+        // Ensure an empty Bloom filter contains no serial numbers.
+        let bloom = Authorization::<CurrentNetwork>::new(&[]).serial_number_bloom(1024, 7)?;
+        assert!(bloom.iter().all(|byte| *byte == 0));
+        for serial_number in &serial_numbers {
+            assert!(!Authorization::serial_number_bloom_contains(&bloom, 1024, 7, serial_number)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_serial_number_bloom_fails() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let authorization = Authorization::new(&[sample_record_request(&private_key, 1, rng)]);
+        let serial_number = authorization.serial_numbers()[0];
+
+        // Ensure invalid parameters are rejected.
+        assert!(authorization.serial_number_bloom(0, 1).is_err());
+        assert!(authorization.serial_number_bloom(8, 0).is_err());
+        let bloom = authorization.serial_number_bloom(16, 2)?;
+        assert!(Authorization::serial_number_bloom_contains(&bloom, 24, 2, &serial_number).is_err());
+        assert!(Authorization::serial_number_bloom_contains(&bloom, 16, 0, &serial_number).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use flate2::{read::DeflateDecoder, write::DeflateEncoder, Compression};
+
+/// The magic bytes prefixing a compressed authorization.
+const COMPRESSED_MAGIC: [u8; 4] = *b"auth";
+/// The version of the compressed authorization format.
+const COMPRESSED_VERSION: u8 = 0;
+
+impl<N: Network> Authorization<N> {
+    /// Returns the authorization as deflate-compressed bytes, prefixed with a magic and version header.
+    pub fn to_bytes_compressed(&self) -> Result<Vec<u8>> {
+        // Write the header.
+        let mut bytes = COMPRESSED_MAGIC.to_vec();
+        bytes.push(COMPRESSED_VERSION);
+        // Write the compressed authorization.
+        let mut encoder = DeflateEncoder::new(bytes, Compression::default());
+        self.write_le(&mut encoder)?;
+        Ok(encoder.finish()?)
+    }
+
+    /// Initializes a new authorization from bytes produced by `to_bytes_compressed`.
+    pub fn from_bytes_compressed(bytes: &[u8]) -> Result<Self> {
+        // Ensure the header is valid.
+        let header_size = COMPRESSED_MAGIC.len() + 1;
+        ensure!(bytes.len() >= header_size, "Compressed authorization is missing its header");
+        ensure!(bytes[..COMPRESSED_MAGIC.len()] == COMPRESSED_MAGIC, "Invalid compressed authorization magic");
+        ensure!(bytes[COMPRESSED_MAGIC.len()] == COMPRESSED_VERSION, "Invalid compressed authorization version");
+
+        // Read the decompressed authorization.
+        let mut decoder = DeflateDecoder::new(&bytes[header_size..]);
+        let authorization = Self::read_le(&mut decoder)?;
+        // Ensure there are no trailing bytes.
+        ensure!(decoder.bytes().next().is_none(), "Compressed authorization contains trailing bytes");
+        Ok(authorization)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::stack::authorization::test_helpers::{sample_authorization, sample_request};
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes_compressed() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new authorization.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let expected = sample_authorization(&private_key, rng);
+
+        // Check the compressed byte representation.
+        let compressed = expected.to_bytes_compressed()?;
+        let candidate = Authorization::<CurrentNetwork>::from_bytes_compressed(&compressed)?;
+        assert_eq!(expected.to_vec_deque(), candidate.to_vec_deque());
+
+        // Ensure an invalid header is rejected.
+        assert!(Authorization::<CurrentNetwork>::from_bytes_compressed(&compressed[1..]).is_err());
+        assert!(Authorization::<CurrentNetwork>::from_bytes_compressed(&expected.to_bytes_le()?).is_err());
+        // Ensure a truncated body is rejected.
+        assert!(Authorization::<CurrentNetwork>::from_bytes_compressed(&compressed[..compressed.len() - 8]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_compressed_is_smaller() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a batch of similar requests.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let requests = (0..16).map(|i| sample_request(&private_key, i, rng)).collect::<Vec<_>>();
+        let authorization = Authorization::new(&requests);
+
+        // Ensure the compressed form is smaller.
+        let compressed = authorization.to_bytes_compressed()?;
+        assert!(compressed.len() < authorization.to_bytes_le()?.len());
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::io::ErrorKind;
+
+impl<N: Network> Authorization<N> {
+    /// Writes the authorization to a buffer, prefixed with its length in bytes as a `u32`.
+    pub fn write_delimited<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Encode the authorization.
+        let bytes = self.to_bytes_le().map_err(|e| error(e.to_string()))?;
+        // Write the length prefix.
+        u32::try_from(bytes.len())
+            .map_err(|_| error("Authorization exceeds the maximum frame size"))?
+            .write_le(&mut writer)?;
+        // Write the authorization.
+        writer.write_all(&bytes)
+    }
+
+    /// Returns an iterator over the length-delimited authorizations in the given reader,
+    /// as written by `write_delimited`. The iterator stops cleanly at the end of the stream,
+    /// and stops after yielding an error for a malformed or truncated frame.
+    pub fn read_delimited<R: Read>(mut reader: R) -> impl Iterator<Item = IoResult<Self>> {
+        let mut is_done = false;
+        std::iter::from_fn(move || {
+            // Stop if the stream has ended, or an error has been yielded.
+            if is_done {
+                return None;
+            }
+            let result = Self::read_frame(&mut reader);
+            is_done = !matches!(result, Some(Ok(_)));
+            result
+        })
+    }
+
+    /// Reads the next length-delimited authorization, returning `None` at the end of the stream.
+    fn read_frame<R: Read>(mut reader: R) -> Option<IoResult<Self>> {
+        // Read the length prefix, stopping cleanly if the stream ends before the frame begins.
+        let mut length = [0u8; 4];
+        let mut num_read = 0;
+        while num_read < length.len() {
+            match reader.read(&mut length[num_read..]) {
+                Ok(0) if num_read == 0 => return None,
+                Ok(0) => return Some(Err(error("Truncated authorization frame length"))),
+                Ok(n) => num_read += n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+
+        // Read the frame.
+        // Note: The frame is read incrementally, so a large length prefix does not allocate the full frame upfront.
+        let length = u32::from_le_bytes(length) as usize;
+        let mut bytes = Vec::new();
+        if let Err(e) = reader.take(length as u64).read_to_end(&mut bytes) {
+            return Some(Err(e));
+        }
+        if bytes.len() != length {
+            return Some(Err(error("Truncated authorization frame")));
+        }
+        // Decode the authorization, ensuring the frame is fully consumed.
+        let mut frame = &bytes[..];
+        Some(Self::read_le(&mut frame).and_then(|authorization| match frame.is_empty() {
+            true => Ok(authorization),
+            false => Err(error("Authorization frame contains trailing bytes")),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::stack::authorization::test_helpers::{sample_authorization, sample_request};
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_delimited() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct several authorizations.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let expected = vec![
+            sample_authorization(&private_key, rng),
+            Authorization::new(&[]),
+            Authorization::new(&[sample_request(&private_key, 3, rng)]),
+        ];
+
+        // Write the authorizations.
+        let mut buffer = Vec::new();
+        for authorization in &expected {
+            authorization.write_delimited(&mut buffer)?;
+        }
+
+        // Read the authorizations.
+        let candidates = Authorization::<CurrentNetwork>::read_delimited(&buffer[..]).collect::<IoResult<Vec<_>>>()?;
+        assert_eq!(expected.len(), candidates.len());
+        for (expected, candidate) in expected.iter().zip_eq(&candidates) {
+            assert_eq!(expected.to_vec_deque(), candidate.to_vec_deque());
+        }
+
+        // Ensure an empty stream yields no authorizations.
+        assert_eq!(0, Authorization::<CurrentNetwork>::read_delimited(&[][..]).count());
+        Ok(())
+    }
+
+    #[test]
+    fn test_delimited_truncated() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Write two authorizations.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let mut buffer = Vec::new();
+        sample_authorization(&private_key, rng).write_delimited(&mut buffer)?;
+        sample_authorization(&private_key, rng).write_delimited(&mut buffer)?;
+
+        // Ensure a truncated final frame yields an error, after the first authorization.
+        let results = Authorization::<CurrentNetwork>::read_delimited(&buffer[..buffer.len() - 1]).collect::<Vec<_>>();
+        assert_eq!(2, results.len());
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+
+        // Ensure a truncated length prefix yields an error.
+        let first_frame_len = 4 + u32::from_le_bytes(buffer[..4].try_into()?) as usize;
+        let results =
+            Authorization::<CurrentNetwork>::read_delimited(&buffer[..first_frame_len + 2]).collect::<Vec<_>>();
+        assert_eq!(2, results.len());
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_delimited_oversized_length() {
+        // Construct a frame with the maximum length prefix, followed by a few bytes.
+        let mut buffer = u32::MAX.to_le_bytes().to_vec();
+        buffer.extend([0u8; 8]);
+
+        // Ensure the oversized frame yields an error, without allocating the full frame.
+        let results = Authorization::<CurrentNetwork>::read_delimited(&buffer[..]).collect::<Vec<_>>();
+        assert_eq!(1, results.len());
+        assert!(results[0].is_err());
+    }
+}
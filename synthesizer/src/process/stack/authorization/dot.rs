@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Authorization<N> {
+    /// Returns a Graphviz DOT representation of the authorization, where each program and each input record
+    /// is a node, and each edge indicates which program (through the requested function) consumes which record.
+    pub fn to_dot(&self) -> Result<String> {
+        let mut dot = String::from("digraph authorization {\n");
+
+        // Add a node for each program.
+        let requests_by_program = self.requests_by_program();
+        for program_id in requests_by_program.keys() {
+            dot.push_str(&format!("    \"{program_id}\" [shape=box];\n"));
+        }
+
+        // Add a node for each input record, with an edge to the program that consumes it.
+        for (index, request) in self.requests.read().iter().enumerate() {
+            for input_id in request.input_ids() {
+                if let InputID::Record(commitment, ..) = input_id {
+                    dot.push_str(&format!("    \"{commitment}\" [shape=ellipse];\n"));
+                    dot.push_str(&format!(
+                        "    \"{commitment}\" -> \"{}\" [label=\"{index}: {}\"];\n",
+                        request.program_id(),
+                        request.function_name()
+                    ));
+                }
+            }
+        }
+
+        dot.push('}');
+        Ok(dot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::stack::authorization::test_helpers::{sample_record_request, sample_request};
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_dot() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct an authorization with record inputs.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let authorization = Authorization::new(&[
+            sample_record_request(&private_key, 1, rng),
+            sample_request(&private_key, 2, rng),
+            sample_record_request(&private_key, 3, rng),
+        ]);
+        let dot = authorization.to_dot()?;
+
+        // Ensure the output is a well-formed DOT graph.
+        assert!(dot.starts_with("digraph authorization {\n"));
+        assert!(dot.ends_with('}'));
+        assert_eq!(1, dot.matches('{').count());
+        assert_eq!(1, dot.matches('}').count());
+        assert_eq!(0, dot.matches('"').count() % 2);
+        assert!(dot.lines().skip(1).take_while(|line| *line != "}").all(|line| line.ends_with(';')));
+
+        // Ensure there is one node per program, and one node and edge per record.
+        assert_eq!(2, dot.matches("[shape=box]").count());
+        assert_eq!(2, dot.matches("[shape=ellipse]").count());
+        assert_eq!(2, dot.matches("->").count());
+        assert!(dot.contains("\"credits.aleo\" [shape=box];"));
+        assert!(dot.contains("\"token.aleo\" [shape=box];"));
+        assert!(dot.contains("[label=\"0: transfer\"]"));
+        assert!(dot.contains("[label=\"2: transfer\"]"));
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Authorization<N> {
+    /// Returns `true` if both authorizations contain the same requests, ignoring the signatures.
+    ///
+    /// Note: As each signature commits to a fresh nonce, the transition keys (`tvk`, `tsk`, `tcm`)
+    /// and input IDs of a re-signed request also differ, and are likewise ignored.
+    pub fn eq_ignoring_signatures(&self, other: &Self) -> bool {
+        let (requests, other_requests) = (self.to_vec_deque(), other.to_vec_deque());
+        // Ensure the number of requests matches.
+        requests.len() == other_requests.len()
+            // Ensure each request has the same caller, program, function, and inputs.
+            && requests.iter().zip_eq(other_requests.iter()).all(|(request, other)| {
+                request.caller() == other.caller()
+                    && request.network_id() == other.network_id()
+                    && request.program_id() == other.program_id()
+                    && request.function_name() == other.function_name()
+                    && request.inputs() == other.inputs()
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::stack::authorization::test_helpers::{sample_authorization, sample_request};
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_eq_ignoring_signatures() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Sign the same requests twice.
+        let authorization = sample_authorization(&private_key, rng);
+        let resigned = sample_authorization(&private_key, rng);
+
+        // Ensure the signatures differ.
+        assert_ne!(authorization.get(0).unwrap().signature(), resigned.get(0).unwrap().signature());
+        assert_ne!(authorization.get(1).unwrap().signature(), resigned.get(1).unwrap().signature());
+
+        // Ensure the authorizations are equal, ignoring signatures.
+        assert!(authorization.eq_ignoring_signatures(&resigned));
+        assert!(resigned.eq_ignoring_signatures(&authorization));
+        assert!(authorization.eq_ignoring_signatures(&authorization));
+    }
+
+    #[test]
+    fn test_eq_ignoring_signatures_fails() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let authorization = sample_authorization(&private_key, rng);
+
+        // Ensure a different input is detected.
+        let other = Authorization::new(&[sample_request(&private_key, 1, rng), sample_request(&private_key, 3, rng)]);
+        assert!(!authorization.eq_ignoring_signatures(&other));
+
+        // Ensure a different number of requests is detected.
+        let other = Authorization::new(&[sample_request(&private_key, 1, rng)]);
+        assert!(!authorization.eq_ignoring_signatures(&other));
+
+        // Ensure a different caller is detected.
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let other = sample_authorization(&other_private_key, rng);
+        assert!(!authorization.eq_ignoring_signatures(&other));
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use console::{
+    collections::merkle_tree::{LeafHash, MerklePath},
+    network::BHPMerkleTree,
+};
+
+impl<N: Network> Authorization<N> {
+    /// Returns the commitments of the input records in the authorization.
+    pub fn input_commitments(&self) -> Vec<Field<N>> {
+        self.requests
+            .read()
+            .iter()
+            .flat_map(|request| request.input_ids().iter())
+            .filter_map(|input_id| match input_id {
+                InputID::Record(commitment, ..) => Some(*commitment),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the Merkle path of each input record commitment in the given record commitment tree,
+    /// in the order of `input_commitments`. This errors if any input record is not in the tree.
+    pub fn input_inclusion_paths<const DEPTH: u8>(
+        &self,
+        tree: &BHPMerkleTree<N, DEPTH>,
+    ) -> Result<Vec<MerklePath<N, DEPTH>>> {
+        // Retrieve the leaf hashes of the tree.
+        let leaf_hashes = tree.leaf_hashes()?;
+
+        self.input_commitments()
+            .iter()
+            .map(|commitment| {
+                // Compute the leaf hash of the commitment, using the leaf hasher of the tree.
+                let leaf = commitment.to_bits_le();
+                let leaf_hash = tree.leaf_hasher().hash_leaf(&leaf)?;
+                // Find the leaf index of the commitment.
+                let leaf_index = leaf_hashes
+                    .iter()
+                    .position(|hash| *hash == leaf_hash)
+                    .ok_or_else(|| anyhow!("Input record commitment '{commitment}' is not in the given tree"))?;
+                // Compute the Merkle path.
+                tree.prove(leaf_index, &leaf)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::stack::authorization::test_helpers::{sample_record_request, sample_request};
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    const DEPTH: u8 = 8;
+
+    #[test]
+    fn test_input_inclusion_paths() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct an authorization with two input records, and a request without input records.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let authorization = Authorization::new(&[
+            sample_record_request(&private_key, 1, rng),
+            sample_request(&private_key, 3, rng),
+            sample_record_request(&private_key, 2, rng),
+        ]);
+        let commitments = authorization.input_commitments();
+        assert_eq!(2, commitments.len());
+
+        // Construct a tree, with the input commitments among other leaves.
+        let mut leaves = (0..5).map(|_| Field::<CurrentNetwork>::rand(rng).to_bits_le()).collect::<Vec<_>>();
+        leaves.insert(1, commitments[1].to_bits_le());
+        leaves.insert(4, commitments[0].to_bits_le());
+        let tree = CurrentNetwork::merkle_tree_bhp::<DEPTH>(&leaves)?;
+
+        // Ensure each path verifies for its commitment.
+        let paths = authorization.input_inclusion_paths(&tree)?;
+        assert_eq!(commitments.len(), paths.len());
+        for (commitment, path) in commitments.iter().zip_eq(&paths) {
+            assert!(tree.verify(path, tree.root(), &commitment.to_bits_le()));
+        }
+
+        // Ensure a tree without the input commitments fails.
+        let tree = CurrentNetwork::merkle_tree_bhp::<DEPTH>(&leaves[5..])?;
+        assert!(authorization.input_inclusion_paths(&tree).is_err());
+        Ok(())
+    }
+}
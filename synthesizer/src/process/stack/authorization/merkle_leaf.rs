@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The domain tag prepended to the authorization bytes when hashing into a Merkle leaf,
+/// to prevent collisions with leaves of other types.
+pub const AUTHORIZATION_LEAF_DOMAIN: u8 = 0x61; // Note: This is the ASCII character 'a'.
+
+impl<N: Network> Authorization<N> {
+    /// Returns the Merkle leaf hash of the authorization, as `Hash(AUTHORIZATION_LEAF_DOMAIN || bytes)`.
+    pub fn to_merkle_leaf(&self) -> Result<Field<N>> {
+        // Construct the domain-separated preimage.
+        let mut preimage = vec![AUTHORIZATION_LEAF_DOMAIN];
+        self.write_le(&mut preimage)?;
+        // Hash the preimage.
+        N::hash_bhp1024(&preimage.to_bits_le())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::stack::authorization::test_helpers::sample_authorization;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_merkle_leaf() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new authorization.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let authorization = sample_authorization(&private_key, rng);
+
+        // Ensure the leaf is deterministic.
+        let leaf = authorization.to_merkle_leaf()?;
+        assert_eq!(leaf, authorization.to_merkle_leaf()?);
+
+        // Ensure the domain separation is applied.
+        let bytes = authorization.to_bytes_le()?;
+        assert_ne!(leaf, CurrentNetwork::hash_bhp1024(&bytes.to_bits_le())?);
+        let mut preimage = vec![AUTHORIZATION_LEAF_DOMAIN];
+        preimage.extend(bytes);
+        assert_eq!(leaf, CurrentNetwork::hash_bhp1024(&preimage.to_bits_le())?);
+
+        // Ensure a different authorization has a different leaf.
+        assert_ne!(leaf, sample_authorization(&private_key, rng).to_merkle_leaf()?);
+        Ok(())
+    }
+}
@@ -14,10 +14,51 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use console::{network::prelude::*, program::Request};
+mod bloom;
+mod bytes;
+mod delimited;
+mod dot;
+mod equal;
+mod inclusion;
+mod programs;
+mod reparameterize;
+mod serialize;
+mod signatures_commitment;
+mod signing_order;
+mod size;
+mod string;
 
-use parking_lot::RwLock;
-use std::{collections::VecDeque, sync::Arc};
+mod view;
+pub use view::{AuthorizationReadGuard, AuthorizationRef};
+
+mod merkle_leaf;
+pub use merkle_leaf::AUTHORIZATION_LEAF_DOMAIN;
+
+mod summary;
+pub use summary::{AuthorizationSummary, RequestSummary};
+
+mod tagged;
+pub use tagged::TaggedAuthorization;
+
+#[cfg(feature = "compression")]
+mod compression;
+
+#[cfg(feature = "prost")]
+mod proto;
+#[cfg(feature = "prost")]
+pub use proto::AuthorizationProto;
+
+use console::{
+    network::prelude::*,
+    program::{InputID, ProgramID, Request},
+    types::{Field, Scalar},
+};
+
+use parking_lot::{RwLock, RwLockReadGuard};
+use std::{
+    collections::{BTreeMap, VecDeque},
+    sync::Arc,
+};
 
 #[derive(Clone)]
 pub struct Authorization<N: Network> {
@@ -71,3 +112,93 @@ impl<N: Network> Authorization<N> {
         self.requests.read().clone()
     }
 }
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use console::{
+        account::{Address, PrivateKey},
+        network::Testnet3,
+        program::{Identifier, ProgramID, Value, ValueType},
+    };
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a signed request to `token.aleo/transfer`, sending the given amount to the caller.
+    pub(crate) fn sample_request(
+        private_key: &PrivateKey<CurrentNetwork>,
+        amount: u64,
+        rng: &mut TestRng,
+    ) -> Request<CurrentNetwork> {
+        sample_request_for_program(private_key, "token.aleo", amount, rng)
+    }
+
+    /// Samples a signed request to `{program_id}/transfer`, sending the given amount to the caller.
+    pub(crate) fn sample_request_for_program(
+        private_key: &PrivateKey<CurrentNetwork>,
+        program_id: &str,
+        amount: u64,
+        rng: &mut TestRng,
+    ) -> Request<CurrentNetwork> {
+        // Construct a program ID and function name.
+        let program_id = ProgramID::from_str(program_id).unwrap();
+        let function_name = Identifier::from_str("transfer").unwrap();
+
+        // Construct the inputs.
+        let address = Address::try_from(private_key).unwrap();
+        let inputs =
+            [Value::from_str(&address.to_string()).unwrap(), Value::from_str(&format!("{amount}u64")).unwrap()];
+        // Construct the input types.
+        let input_types = [ValueType::from_str("address.private").unwrap(), ValueType::from_str("u64.public").unwrap()];
+
+        // Compute the signed request.
+        let request =
+            Request::sign(private_key, program_id, function_name, inputs.into_iter(), &input_types, rng).unwrap();
+        assert!(request.verify(&input_types));
+        request
+    }
+
+    /// Samples a signed request to `credits.aleo/transfer`, spending a record with the given gates.
+    pub(crate) fn sample_record_request(
+        private_key: &PrivateKey<CurrentNetwork>,
+        gates: u64,
+        rng: &mut TestRng,
+    ) -> Request<CurrentNetwork> {
+        // Construct a program ID and function name.
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let function_name = Identifier::from_str("transfer").unwrap();
+
+        // Prepare a record belonging to the caller.
+        let address = Address::try_from(private_key).unwrap();
+        let record_string = format!(
+            "{{ owner: {address}.private, gates: {gates}u64.private, _nonce: 2293253577170800572742339369209137467208538700597121244293392265726446806023group.public }}"
+        );
+
+        // Construct the inputs.
+        let inputs = [
+            Value::from_str(&record_string).unwrap(),
+            Value::from_str(&address.to_string()).unwrap(),
+            Value::from_str(&format!("{gates}u64")).unwrap(),
+        ];
+        // Construct the input types.
+        let input_types = [
+            ValueType::from_str("credits.record").unwrap(),
+            ValueType::from_str("address.private").unwrap(),
+            ValueType::from_str("u64.private").unwrap(),
+        ];
+
+        // Compute the signed request.
+        let request =
+            Request::sign(private_key, program_id, function_name, inputs.into_iter(), &input_types, rng).unwrap();
+        assert!(request.verify(&input_types));
+        request
+    }
+
+    /// Samples an authorization with two signed requests from the given private key.
+    pub(crate) fn sample_authorization(
+        private_key: &PrivateKey<CurrentNetwork>,
+        rng: &mut TestRng,
+    ) -> Authorization<CurrentNetwork> {
+        Authorization::new(&[sample_request(private_key, 1, rng), sample_request(private_key, 2, rng)])
+    }
+}
@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Authorization<N> {
+    /// Returns the indices of the requests in the authorization, grouped by program ID.
+    pub fn requests_by_program(&self) -> BTreeMap<ProgramID<N>, Vec<usize>> {
+        let mut requests_by_program = BTreeMap::<_, Vec<_>>::new();
+        for (index, request) in self.requests.read().iter().enumerate() {
+            requests_by_program.entry(*request.program_id()).or_default().push(index);
+        }
+        requests_by_program
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::stack::authorization::test_helpers::sample_request_for_program;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_requests_by_program() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct an authorization with requests split across two programs.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let authorization = Authorization::new(&[
+            sample_request_for_program(&private_key, "token.aleo", 1, rng),
+            sample_request_for_program(&private_key, "swap.aleo", 2, rng),
+            sample_request_for_program(&private_key, "token.aleo", 3, rng),
+            sample_request_for_program(&private_key, "swap.aleo", 4, rng),
+            sample_request_for_program(&private_key, "token.aleo", 5, rng),
+        ]);
+
+        // Check the grouping.
+        let requests_by_program = authorization.requests_by_program();
+        assert_eq!(2, requests_by_program.len());
+        assert_eq!(vec![0, 2, 4], requests_by_program[&ProgramID::from_str("token.aleo")?]);
+        assert_eq!(vec![1, 3], requests_by_program[&ProgramID::from_str("swap.aleo")?]);
+
+        // Check an empty authorization.
+        assert!(Authorization::<CurrentNetwork>::new(&[]).requests_by_program().is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The protobuf message for an authorization, for transport over gRPC.
+/// Each request is carried as a `bytes` field containing its `ToBytes` encoding.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct AuthorizationProto {
+    /// The encoded requests, in order.
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub requests: Vec<Vec<u8>>,
+}
+
+impl<N: Network> Authorization<N> {
+    /// Returns the authorization as a protobuf message.
+    pub fn to_proto(&self) -> Result<AuthorizationProto> {
+        Ok(AuthorizationProto {
+            requests: self.requests.read().iter().map(|request| request.to_bytes_le()).collect::<Result<_>>()?,
+        })
+    }
+
+    /// Initializes a new authorization from a protobuf message.
+    pub fn from_proto(proto: &AuthorizationProto) -> Result<Self> {
+        let requests = proto.requests.iter().map(|bytes| Request::from_bytes_le(bytes)).collect::<Result<Vec<_>>>()?;
+        Ok(Self::new(&requests))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::stack::authorization::test_helpers::sample_authorization;
+    use console::{account::PrivateKey, network::Testnet3};
+    use prost::Message;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_proto() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the authorization.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let expected = sample_authorization(&private_key, rng);
+
+        // Check the protobuf round trip.
+        let proto = expected.to_proto()?;
+        assert_eq!(expected.len(), proto.requests.len());
+        let candidate = Authorization::<CurrentNetwork>::from_proto(&proto)?;
+        assert_eq!(expected.to_vec_deque(), candidate.to_vec_deque());
+
+        // Check the wire encoding round trip.
+        let decoded = AuthorizationProto::decode(proto.encode_to_vec().as_slice())?;
+        assert_eq!(proto, decoded);
+        let candidate = Authorization::<CurrentNetwork>::from_proto(&decoded)?;
+        assert_eq!(expected.to_vec_deque(), candidate.to_vec_deque());
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Authorization<N> {
+    /// Returns a new authorization, with the requests re-encoded under the network `D`.
+    /// This is primarily a testing and migration aid for networks that share a wire format.
+    ///
+    /// Note: As each request signs over its network ID, the networks must share the same ID.
+    pub fn try_reparameterize<D: Network>(&self) -> Result<Authorization<D>> {
+        // Ensure the network IDs match, as `Request` halts on a mismatched network ID.
+        ensure_compatible_networks(N::ID, D::ID)?;
+        // Re-encode the authorization under the new network.
+        Authorization::<D>::from_bytes_le(&self.to_bytes_le()?)
+    }
+}
+
+/// Ensures an authorization on the network `source_id` can be reparameterized to the network `target_id`.
+fn ensure_compatible_networks(source_id: u16, target_id: u16) -> Result<()> {
+    ensure!(
+        source_id == target_id,
+        "Cannot reparameterize an authorization from network {source_id} to network {target_id}"
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::stack::authorization::test_helpers::sample_authorization;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_try_reparameterize() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new authorization.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let expected = sample_authorization(&private_key, rng);
+
+        // Reparameterize the authorization.
+        let candidate = expected.try_reparameterize::<CurrentNetwork>()?;
+        assert_eq!(expected.to_vec_deque(), candidate.to_vec_deque());
+        assert_eq!(expected.to_bytes_le()?, candidate.to_bytes_le()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_ensure_compatible_networks() {
+        assert!(ensure_compatible_networks(CurrentNetwork::ID, CurrentNetwork::ID).is_ok());
+
+        // Ensure a different network ID fails.
+        let error = ensure_compatible_networks(CurrentNetwork::ID, CurrentNetwork::ID + 1).unwrap_err();
+        assert_eq!("Cannot reparameterize an authorization from network 3 to network 4", error.to_string());
+    }
+}
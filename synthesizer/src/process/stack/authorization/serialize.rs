@@ -0,0 +1,118 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use snarkvm_utilities::DeserializeExt;
+
+impl<N: Network> Serialize for Authorization<N> {
+    /// Serializes the authorization into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut authorization = serializer.serialize_struct("Authorization", 1)?;
+                authorization.serialize_field("requests", &*self.requests.read())?;
+                authorization.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for Authorization<N> {
+    /// Deserializes the authorization from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the authorization from a string into a value.
+                let mut authorization = serde_json::Value::deserialize(deserializer)?;
+                // Retrieve the requests.
+                let requests: Vec<Request<N>> = DeserializeExt::take_from_value::<D>(&mut authorization, "requests")?;
+                // Recover the authorization.
+                Ok(Self::new(&requests))
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "authorization"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::stack::authorization::test_helpers::{sample_authorization, sample_record_request};
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    fn sample_authorizations(rng: &mut TestRng) -> Result<Vec<Authorization<CurrentNetwork>>> {
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        Ok(vec![
+            Authorization::new(&[]),
+            sample_authorization(&private_key, rng),
+            Authorization::new(&[sample_record_request(&private_key, 5, rng)]),
+        ])
+    }
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for expected in sample_authorizations(rng)? {
+            // Serialize
+            let expected_string = &expected.to_string();
+            let candidate_string = serde_json::to_string(&expected)?;
+
+            // Ensure the requests are named members.
+            let candidate_value = serde_json::from_str::<serde_json::Value>(&candidate_string)?;
+            assert_eq!(expected.len(), candidate_value["requests"].as_array().map_or(0, |requests| requests.len()));
+
+            // Deserialize
+            assert_eq!(
+                expected.to_vec_deque(),
+                Authorization::<CurrentNetwork>::from_str(expected_string)?.to_vec_deque()
+            );
+            assert_eq!(
+                expected.to_vec_deque(),
+                serde_json::from_str::<Authorization<CurrentNetwork>>(&candidate_string)?.to_vec_deque()
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for expected in sample_authorizations(rng)? {
+            // Serialize
+            let expected_bytes = expected.to_bytes_le()?;
+            let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+            assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+            // Deserialize
+            assert_eq!(
+                expected.to_vec_deque(),
+                Authorization::<CurrentNetwork>::read_le(&expected_bytes[..])?.to_vec_deque()
+            );
+            assert_eq!(
+                expected.to_vec_deque(),
+                bincode::deserialize::<Authorization<CurrentNetwork>>(&expected_bytes_with_size_encoding[..])?
+                    .to_vec_deque()
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Authorization<N> {
+    /// Returns a BHP commitment to the concatenated signature bytes of the requests, in order.
+    pub fn signatures_commitment(&self, randomizer: &Scalar<N>) -> Result<Field<N>> {
+        // Concatenate the signature bytes.
+        let mut preimage = Vec::new();
+        for request in self.requests.read().iter() {
+            request.signature().write_le(&mut preimage)?;
+        }
+        // Commit to the signature bits.
+        N::commit_bhp1024(&preimage.to_bits_le(), randomizer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::stack::authorization::test_helpers::{sample_authorization, sample_request};
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_signatures_commitment() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new authorization.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let authorization = sample_authorization(&private_key, rng);
+        let randomizer = Uniform::rand(rng);
+
+        // Ensure the commitment is deterministic.
+        let expected = authorization.signatures_commitment(&randomizer)?;
+        assert_eq!(expected, authorization.signatures_commitment(&randomizer)?);
+        assert_eq!(expected, authorization.replicate().signatures_commitment(&randomizer)?);
+
+        // Ensure changing any signature changes the commitment.
+        let requests = authorization.to_vec_deque();
+        for index in 0..requests.len() {
+            let mut resigned = requests.clone();
+            resigned[index] = sample_request(&private_key, index as u64 + 1, rng);
+            assert_ne!(resigned[index].signature(), requests[index].signature());
+
+            let candidate = Authorization::new(resigned.make_contiguous()).signatures_commitment(&randomizer)?;
+            assert_ne!(expected, candidate);
+        }
+        Ok(())
+    }
+}
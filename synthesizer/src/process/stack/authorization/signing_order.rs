@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Authorization<N> {
+    /// Returns the request indices sorted by the caller address bytes (ties are kept in request order),
+    /// giving a stable order in which to collect signatures across parties.
+    ///
+    /// Note: This order is purely for coordination, and does not affect the order of the requests.
+    pub fn signing_order(&self) -> Result<Vec<usize>> {
+        // Retrieve the caller bytes for each request.
+        let callers =
+            self.requests.read().iter().map(|request| request.caller().to_bytes_le()).collect::<Result<Vec<_>>>()?;
+        // Sort the request indices by the caller bytes.
+        let mut order = (0..callers.len()).collect::<Vec<_>>();
+        order.sort_by(|a, b| callers[*a].cmp(&callers[*b]));
+        Ok(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::stack::authorization::test_helpers::sample_request;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_signing_order() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct an authorization with requests from several callers.
+        let private_keys = (0..4).map(|_| PrivateKey::<CurrentNetwork>::new(rng)).collect::<Result<Vec<_>>>()?;
+        let requests = (0..8).map(|i| sample_request(&private_keys[i % 4], i as u64, rng)).collect::<Vec<_>>();
+        let authorization = Authorization::new(&requests);
+
+        // Ensure the order covers all request indices.
+        let order = authorization.signing_order()?;
+        assert_eq!((0..requests.len()).collect::<Vec<_>>(), order.iter().copied().sorted().collect::<Vec<_>>());
+
+        // Ensure the order is sorted by the caller bytes, and stable for the same caller.
+        for (a, b) in order.iter().tuple_windows() {
+            let (caller_a, caller_b) = (requests[*a].caller().to_bytes_le()?, requests[*b].caller().to_bytes_le()?);
+            assert!(caller_a < caller_b || (caller_a == caller_b && a < b));
+        }
+
+        // Ensure the order is deterministic, and independent of the signatures.
+        assert_eq!(order, authorization.signing_order()?);
+        let resigned = (0..8).map(|i| sample_request(&private_keys[i % 4], i as u64, rng)).collect::<Vec<_>>();
+        assert_eq!(order, Authorization::new(&resigned).signing_order()?);
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Authorization<N> {
+    /// Returns the size of the authorization in bytes, when serialized.
+    pub fn size_in_bytes(&self) -> Result<usize> {
+        // Count the serialized bytes, without allocating the encoding.
+        let mut counter = ByteCounter(0);
+        self.write_le(&mut counter)?;
+        Ok(counter.0)
+    }
+
+    /// Returns `true` if the serialized authorization fits within the given number of bytes.
+    ///
+    /// Note: The authorization only contains the signed requests. The encrypted output records
+    /// are produced during execution, and are accounted for in the size of the resulting transaction.
+    pub fn fits_in_budget(&self, max_bytes: usize) -> Result<bool> {
+        Ok(self.size_in_bytes()? <= max_bytes)
+    }
+}
+
+/// A writer that counts the number of bytes written to it.
+struct ByteCounter(usize);
+
+impl Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::stack::authorization::test_helpers::sample_authorization;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_fits_in_budget() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new authorization.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let authorization = sample_authorization(&private_key, rng);
+
+        // Ensure the size matches the serialized length.
+        let size = authorization.size_in_bytes()?;
+        assert_eq!(authorization.to_bytes_le()?.len(), size);
+
+        // Ensure a tight budget rejects, and a loose budget accepts.
+        assert!(!authorization.fits_in_budget(size - 1)?);
+        assert!(!authorization.fits_in_budget(0)?);
+        assert!(authorization.fits_in_budget(size)?);
+        assert!(authorization.fits_in_budget(usize::MAX)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use console::{
+    account::Address,
+    program::{Identifier, Literal, Plaintext, Value},
+};
+
+/// A human-readable summary of an authorized request.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RequestSummary<N: Network> {
+    /// The caller of the request.
+    pub caller: Address<N>,
+    /// The program ID of the called function.
+    pub program_id: ProgramID<N>,
+    /// The name of the called function.
+    pub function_name: Identifier<N>,
+    /// The input values.
+    pub inputs: Vec<Value<N>>,
+    /// The total gates in the input records.
+    pub input_gates: u64,
+}
+
+/// A human-readable summary of an authorization, for display before signing or proving.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuthorizationSummary<N: Network> {
+    /// The summaries of the requests, in order.
+    pub requests: Vec<RequestSummary<N>>,
+    /// The fee (in gates), if the authorization includes a call to `credits.aleo/fee`.
+    pub fee_in_gates: Option<u64>,
+}
+
+impl<N: Network> Authorization<N> {
+    /// Returns a human-readable summary of the authorization, with the called functions,
+    /// the input values and record gates, and the fee (if present).
+    /// Note: The outputs are not known until the authorization is executed, and are not summarized.
+    pub fn summarize(&self) -> Result<AuthorizationSummary<N>> {
+        let fee_program_id = ProgramID::<N>::from_str("credits.aleo")?;
+        let fee_function_name = Identifier::<N>::from_str("fee")?;
+
+        let mut fee_in_gates = None;
+        let requests = self
+            .to_vec_deque()
+            .into_iter()
+            .map(|request| {
+                // Sum the gates in the input records.
+                let input_gates = request.inputs().iter().try_fold(0u64, |total, input| match input {
+                    Value::Record(record) => total
+                        .checked_add(***record.gates())
+                        .ok_or_else(|| anyhow!("Overflow summing the input record gates")),
+                    Value::Plaintext(..) => Ok(total),
+                })?;
+
+                // Retrieve the fee amount, if this is a call to the fee function.
+                if *request.program_id() == fee_program_id && *request.function_name() == fee_function_name {
+                    match request.inputs().get(1) {
+                        Some(Value::Plaintext(Plaintext::Literal(Literal::U64(fee), ..))) => {
+                            fee_in_gates = Some(fee_in_gates.unwrap_or(0u64).saturating_add(**fee))
+                        }
+                        _ => bail!("Malformed fee request in the authorization"),
+                    }
+                }
+
+                Ok(RequestSummary {
+                    caller: *request.caller(),
+                    program_id: *request.program_id(),
+                    function_name: *request.function_name(),
+                    inputs: request.inputs().to_vec(),
+                    input_gates,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(AuthorizationSummary { requests, fee_in_gates })
+    }
+}
+
+impl<N: Network> Display for AuthorizationSummary<N> {
+    /// Prints the summary as one line per request, followed by the fee.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for (index, request) in self.requests.iter().enumerate() {
+            let inputs = request.inputs.iter().map(|input| input.to_string()).join(", ");
+            writeln!(
+                f,
+                "{index}: {} calls {}/{}({inputs}) spending {} gates",
+                request.caller, request.program_id, request.function_name, request.input_gates
+            )?;
+        }
+        match self.fee_in_gates {
+            Some(fee) => write!(f, "fee: {fee} gates"),
+            None => write!(f, "fee: none"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::{
+        stack::authorization::test_helpers::{sample_record_request, sample_request},
+        test_helpers::sample_fee_request,
+    };
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_summarize() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let address = Address::try_from(&private_key)?;
+
+        // Ensure an authorization without a fee is summarized.
+        let authorization =
+            Authorization::new(&[sample_request(&private_key, 7, rng), sample_record_request(&private_key, 5, rng)]);
+        let summary = authorization.summarize()?;
+        assert_eq!(2, summary.requests.len());
+        assert_eq!(None, summary.fee_in_gates);
+        assert_eq!(address, summary.requests[0].caller);
+        assert_eq!("token.aleo", summary.requests[0].program_id.to_string());
+        assert_eq!("transfer", summary.requests[0].function_name.to_string());
+        assert_eq!(Value::from_str("7u64")?, summary.requests[0].inputs[1]);
+        assert_eq!(0, summary.requests[0].input_gates);
+        assert_eq!("credits.aleo", summary.requests[1].program_id.to_string());
+        assert_eq!(5, summary.requests[1].input_gates);
+
+        // Ensure the fee is summarized.
+        let authorization = Authorization::new(&[sample_fee_request(&private_key, rng)]);
+        let summary = authorization.summarize()?;
+        assert_eq!(Some(1), summary.fee_in_gates);
+        assert_eq!(3, summary.requests[0].input_gates);
+        assert!(summary.to_string().ends_with("fee: 1 gates"));
+
+        // Ensure an empty authorization is summarized.
+        let summary = Authorization::<CurrentNetwork>::new(&[]).summarize()?;
+        assert!(summary.requests.is_empty());
+        assert_eq!("fee: none", summary.to_string());
+        Ok(())
+    }
+}
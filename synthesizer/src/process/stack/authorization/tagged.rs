@@ -0,0 +1,176 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// An authorization, tagged with local metadata (e.g. labels, timestamps) for bookkeeping.
+/// The metadata does not affect the encoding of the inner authorization.
+#[derive(Clone)]
+pub struct TaggedAuthorization<N: Network> {
+    /// The authorization.
+    authorization: Authorization<N>,
+    /// The local metadata.
+    metadata: BTreeMap<String, String>,
+}
+
+impl<N: Network> TaggedAuthorization<N> {
+    /// Initializes a new tagged authorization, with the given metadata.
+    pub const fn new(authorization: Authorization<N>, metadata: BTreeMap<String, String>) -> Self {
+        Self { authorization, metadata }
+    }
+
+    /// Returns the authorization.
+    pub const fn authorization(&self) -> &Authorization<N> {
+        &self.authorization
+    }
+
+    /// Returns the metadata.
+    pub const fn metadata(&self) -> &BTreeMap<String, String> {
+        &self.metadata
+    }
+
+    /// Returns the metadata value for the given key, if it exists.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.metadata.get(key).map(String::as_str)
+    }
+
+    /// Inserts the given metadata entry, returning the previous value, if it exists.
+    pub fn insert(&mut self, key: String, value: String) -> Option<String> {
+        self.metadata.insert(key, value)
+    }
+
+    /// Returns the bare authorization, discarding the metadata.
+    pub fn strip(self) -> Authorization<N> {
+        self.authorization
+    }
+}
+
+impl<N: Network> From<Authorization<N>> for TaggedAuthorization<N> {
+    /// Initializes a new tagged authorization, with no metadata.
+    fn from(authorization: Authorization<N>) -> Self {
+        Self::new(authorization, Default::default())
+    }
+}
+
+#[cfg(feature = "metadata")]
+impl<N: Network> FromBytes for TaggedAuthorization<N> {
+    /// Reads the tagged authorization from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the authorization.
+        let authorization = Authorization::read_le(&mut reader)?;
+        // Read the number of metadata entries.
+        let num_entries = u16::read_le(&mut reader)?;
+        // Read the metadata entries.
+        let mut metadata = BTreeMap::new();
+        for _ in 0..num_entries {
+            let key = read_string_le(&mut reader)?;
+            let value = read_string_le(&mut reader)?;
+            if metadata.insert(key, value).is_some() {
+                return Err(error("Duplicate key in the authorization metadata"));
+            }
+        }
+        Ok(Self::new(authorization, metadata))
+    }
+}
+
+#[cfg(feature = "metadata")]
+impl<N: Network> ToBytes for TaggedAuthorization<N> {
+    /// Writes the tagged authorization to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of metadata entries is within bounds.
+        let num_entries =
+            u16::try_from(self.metadata.len()).map_err(|_| error("Too many entries in the authorization metadata"))?;
+
+        // Write the authorization.
+        self.authorization.write_le(&mut writer)?;
+        // Write the number of metadata entries.
+        num_entries.write_le(&mut writer)?;
+        // Write the metadata entries.
+        for (key, value) in &self.metadata {
+            write_string_le(key, &mut writer)?;
+            write_string_le(value, &mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads a length-prefixed UTF-8 string from a buffer.
+#[cfg(feature = "metadata")]
+fn read_string_le<R: Read>(mut reader: R) -> IoResult<String> {
+    let num_bytes = u16::read_le(&mut reader)?;
+    let mut bytes = vec![0u8; num_bytes as usize];
+    reader.read_exact(&mut bytes)?;
+    String::from_utf8(bytes).map_err(|_| error("Invalid UTF-8 in the authorization metadata"))
+}
+
+/// Writes a length-prefixed UTF-8 string to a buffer.
+#[cfg(feature = "metadata")]
+fn write_string_le<W: Write>(string: &str, mut writer: W) -> IoResult<()> {
+    let num_bytes = u16::try_from(string.len()).map_err(|_| error("Authorization metadata entry is too large"))?;
+    num_bytes.write_le(&mut writer)?;
+    writer.write_all(string.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::stack::authorization::test_helpers::sample_authorization;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    fn sample_tagged_authorization(rng: &mut TestRng) -> Result<TaggedAuthorization<CurrentNetwork>> {
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let mut tagged = TaggedAuthorization::from(sample_authorization(&private_key, rng));
+        assert!(tagged.insert("label".to_string(), "rent".to_string()).is_none());
+        assert!(tagged.insert("timestamp".to_string(), "1700000000".to_string()).is_none());
+        Ok(tagged)
+    }
+
+    #[test]
+    fn test_strip() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let tagged = sample_tagged_authorization(rng)?;
+        assert_eq!(Some("rent"), tagged.get("label"));
+        assert_eq!(2, tagged.metadata().len());
+
+        // Ensure the bare encoding is unchanged by the metadata.
+        let expected = tagged.authorization().to_bytes_le()?;
+        assert_eq!(expected, tagged.strip().to_bytes_le()?);
+        Ok(())
+    }
+
+    #[cfg(feature = "metadata")]
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let expected = sample_tagged_authorization(rng)?;
+        let expected_bytes = expected.to_bytes_le()?;
+
+        // Ensure the metadata is written after the authorization.
+        let authorization_bytes = expected.authorization().to_bytes_le()?;
+        assert_eq!(authorization_bytes[..], expected_bytes[..authorization_bytes.len()]);
+
+        // Ensure the tagged authorization round-trips.
+        let candidate = TaggedAuthorization::<CurrentNetwork>::read_le(&expected_bytes[..])?;
+        assert_eq!(expected.metadata(), candidate.metadata());
+        assert_eq!(expected.authorization().to_vec_deque(), candidate.authorization().to_vec_deque());
+        assert!(TaggedAuthorization::<CurrentNetwork>::read_le(&expected_bytes[..expected_bytes.len() - 1]).is_err());
+        Ok(())
+    }
+}
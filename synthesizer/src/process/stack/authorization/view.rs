@@ -0,0 +1,145 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A read-only view of an authorization, over borrowed requests.
+///
+/// The requests may be split across two slices, as in a `VecDeque`.
+#[derive(Copy, Clone)]
+pub struct AuthorizationRef<'a, N: Network> {
+    /// The authorized requests, in order.
+    requests: (&'a [Request<N>], &'a [Request<N>]),
+}
+
+impl<'a, N: Network> AuthorizationRef<'a, N> {
+    /// Initialize a new `AuthorizationRef` instance, over the given requests.
+    pub const fn new(requests: &'a [Request<N>]) -> Self {
+        Self { requests: (requests, &[]) }
+    }
+
+    /// Initialize a new `AuthorizationRef` instance, over the requests in `front` followed by `back`.
+    pub const fn from_slices(front: &'a [Request<N>], back: &'a [Request<N>]) -> Self {
+        Self { requests: (front, back) }
+    }
+
+    /// Returns the `Request` at the given index.
+    pub fn get(&self, index: usize) -> Result<&'a Request<N>> {
+        let (front, back) = self.requests;
+        let request = match index < front.len() {
+            true => front.get(index),
+            false => back.get(index - front.len()),
+        };
+        request.ok_or_else(|| anyhow!("Attempted to get missing request {index}."))
+    }
+
+    /// Returns the number of `Request`s in the authorization.
+    pub const fn len(&self) -> usize {
+        self.requests.0.len() + self.requests.1.len()
+    }
+
+    /// Return `true` if the authorization is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the requests in the authorization, in order.
+    pub fn requests(&self) -> impl 'a + Iterator<Item = &'a Request<N>> {
+        self.requests.0.iter().chain(self.requests.1.iter())
+    }
+
+    /// Returns the program IDs of the requests in the authorization, in order.
+    pub fn program_ids(&self) -> impl 'a + Iterator<Item = &'a ProgramID<N>> {
+        self.requests().map(|request| request.program_id())
+    }
+
+    /// Returns an owned `Authorization`, cloning the requests.
+    pub fn to_authorization(&self) -> Authorization<N> {
+        Authorization { requests: Arc::new(RwLock::new(self.requests().cloned().collect())) }
+    }
+}
+
+/// A read lock on the requests of an authorization, from which read-only views are borrowed.
+pub struct AuthorizationReadGuard<'a, N: Network> {
+    /// The read guard over the authorized requests.
+    requests: RwLockReadGuard<'a, VecDeque<Request<N>>>,
+}
+
+impl<N: Network> AuthorizationReadGuard<'_, N> {
+    /// Returns a read-only view of the authorization, without cloning the requests.
+    pub fn view(&self) -> AuthorizationRef<'_, N> {
+        let (front, back) = self.requests.as_slices();
+        AuthorizationRef::from_slices(front, back)
+    }
+}
+
+impl<N: Network> Authorization<N> {
+    /// Returns a read lock on the requests, from which a read-only view of the authorization is borrowed.
+    /// The authorization can not be modified until the guard is dropped.
+    pub fn as_ref_view(&self) -> AuthorizationReadGuard<'_, N> {
+        AuthorizationReadGuard { requests: self.requests.read() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::stack::authorization::test_helpers::{sample_authorization, sample_request};
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_ref_view() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new authorization.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let authorization = sample_authorization(&private_key, rng);
+        // Wrap the deque around, so its requests are not contiguous.
+        authorization.next()?;
+        authorization.push(sample_request(&private_key, 3, rng));
+        let expected = authorization.to_vec_deque();
+        let expected_bytes = authorization.to_bytes_le()?;
+
+        {
+            let guard = authorization.as_ref_view();
+            let view = guard.view();
+            // Check the accessors.
+            assert_eq!(expected.len(), view.len());
+            assert!(!view.is_empty());
+            assert!(expected.iter().eq(view.requests()));
+            for (index, request) in expected.iter().enumerate() {
+                assert_eq!(request, view.get(index)?);
+                assert_eq!(request.program_id(), view.program_ids().nth(index).unwrap());
+            }
+            assert!(view.get(expected.len()).is_err());
+
+            // Check the view serializes identically to the owned authorization.
+            assert_eq!(expected_bytes, view.to_bytes_le()?);
+            assert_eq!(expected, view.to_authorization().to_vec_deque());
+
+            // Check the authorization remains readable while the view is held.
+            assert_eq!(expected.len(), authorization.len());
+        }
+
+        // Check a view constructed directly over borrowed requests.
+        let requests = expected.iter().cloned().collect::<Vec<_>>();
+        let view = AuthorizationRef::new(&requests);
+        assert_eq!(expected_bytes, view.to_bytes_le()?);
+        Ok(())
+    }
+}
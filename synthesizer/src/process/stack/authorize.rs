@@ -55,8 +55,8 @@ impl<N: Network> Stack<N> {
         let authorization = Authorization::new(&[request.clone()]);
         // Construct the call stack.
         let call_stack = CallStack::Authorize(vec![request], *private_key, authorization.clone());
-        // Construct the authorization from the function.
-        let _response = self.execute_function::<A, R>(call_stack, rng)?;
+        // Construct the authorization from the function, computing only the witnesses of the circuit.
+        let _response = A::witness_only(|| self.execute_function::<A, R>(call_stack, rng))?;
         lap!(timer, "Construct the authorization from the function");
 
         finish!(timer);
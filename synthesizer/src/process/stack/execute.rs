@@ -66,7 +66,7 @@ impl<N: Network> Stack<N> {
         lap!(timer, "Store the inputs");
 
         // Execute the instructions.
-        for instruction in closure.instructions() {
+        for (index, instruction) in closure.instructions().iter().enumerate() {
             // If the circuit is in execute mode, then evaluate the instructions.
             if let CallStack::Execute(..) = registers.call_stack() {
                 // If the evaluation fails, bail and return the error.
@@ -74,8 +74,9 @@ impl<N: Network> Stack<N> {
                     bail!("Failed to evaluate instruction ({instruction}): {error}");
                 }
             }
-            // Execute the instruction.
-            instruction.execute(self, &mut registers)?;
+            // Execute the instruction, labeled for the circuit profiler and the constraint diagnostics.
+            let label = InstructionLabel { program_id: self.program_id(), name: closure.name(), index, instruction };
+            A::profile(&label, || A::label(&label, || instruction.execute(self, &mut registers)))?;
         }
         lap!(timer, "Execute the instructions");
 
@@ -220,7 +221,7 @@ impl<N: Network> Stack<N> {
         let mut contains_function_call = false;
 
         // Execute the instructions.
-        for instruction in function.instructions() {
+        for (index, instruction) in function.instructions().iter().enumerate() {
             // If the circuit is in execute mode, then evaluate the instructions.
             if let CallStack::Execute(..) = registers.call_stack() {
                 // If the evaluation fails, bail and return the error.
@@ -229,8 +230,9 @@ impl<N: Network> Stack<N> {
                 }
             }
 
-            // Execute the instruction.
-            instruction.execute(self, &mut registers)?;
+            // Execute the instruction, labeled for the circuit profiler and the constraint diagnostics.
+            let label = InstructionLabel { program_id: self.program_id(), name: function.name(), index, instruction };
+            A::profile(&label, || A::label(&label, || instruction.execute(self, &mut registers)))?;
 
             // If the instruction was a function call, then set the tracker to `true`.
             if let Instruction::Call(call) = instruction {
@@ -348,6 +350,13 @@ impl<N: Network> Stack<N> {
                                 function.name()
                             );
                         }
+                        circuit::Value::Plaintext(circuit::Plaintext::Array(..)) => {
+                            bail!(
+                                "'{}/{}' attempts to pass an 'array' into 'finalize'",
+                                self.program_id(),
+                                function.name()
+                            );
+                        }
                         circuit::Value::Record(..) => {
                             bail!(
                                 "'{}/{}' attempts to pass a 'record' into 'finalize'",
@@ -467,14 +476,23 @@ impl<N: Network> Stack<N> {
 
         // If the circuit is in `Execute` mode, then ensure the circuit is satisfied.
         if let CallStack::Execute(..) = registers.call_stack() {
-            // If the circuit is empty or not satisfied, then throw an error.
+            // If the circuit is empty, then throw an error.
             ensure!(
-                A::num_constraints() > 0 && A::is_satisfied(),
+                A::num_constraints() > 0,
                 "'{}/{}' is not satisfied on the given inputs ({} constraints).",
                 self.program.id(),
                 function.name(),
                 A::num_constraints()
             );
+            // If the circuit is not satisfied, then throw an error with the first unsatisfied constraint.
+            if let Some(constraint) = A::first_unsatisfied_constraint() {
+                bail!(
+                    "'{}/{}' is not satisfied on the given inputs ({} constraints).\n{constraint}",
+                    self.program.id(),
+                    function.name(),
+                    A::num_constraints()
+                )
+            }
         }
 
         // Eject the circuit assignment and reset the circuit.
@@ -555,3 +573,23 @@ impl<N: Network> Stack<N> {
         );
     }
 }
+
+/// The label of an instruction in a closure or function, for the circuit profiler and the constraint diagnostics.
+/// Note: The label is only formatted if it is used, as the instructions are labeled on every execution.
+struct InstructionLabel<'a, N: Network> {
+    /// The program ID.
+    program_id: &'a ProgramID<N>,
+    /// The name of the closure or function.
+    name: &'a Identifier<N>,
+    /// The index of the instruction.
+    index: usize,
+    /// The instruction.
+    instruction: &'a Instruction<N>,
+}
+
+impl<N: Network> Display for InstructionLabel<'_, N> {
+    /// Prints the label as `program_id/name[index] instruction`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}/{}[{}] {}", self.program_id, self.name, self.index, self.instruction)
+    }
+}
@@ -109,6 +109,14 @@ impl<N: Network> FinalizeTypes<N> {
                     bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
                 }
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the element struct, if any, is defined in the program.
+                if let PlaintextType::Struct(struct_name) = array_type.element_type() {
+                    if !stack.program().contains_struct(&struct_name) {
+                        bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
+                    }
+                }
+            }
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
                 if !stack.program().contains_record(identifier) {
@@ -158,6 +166,14 @@ impl<N: Network> FinalizeTypes<N> {
                     bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
                 }
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the element struct, if any, is defined in the program.
+                if let PlaintextType::Struct(struct_name) = array_type.element_type() {
+                    if !stack.program().contains_struct(&struct_name) {
+                        bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
+                    }
+                }
+            }
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
                 if !stack.program().contains_record(identifier) {
@@ -241,6 +257,9 @@ impl<N: Network> FinalizeTypes<N> {
             RegisterType::Plaintext(PlaintextType::Struct(..)) => {
                 bail!("Decrement cannot decrement by an 'struct' (found at '{decrement}')")
             }
+            RegisterType::Plaintext(PlaintextType::Array(..)) => {
+                bail!("Decrement cannot decrement by an 'array' (found at '{decrement}')")
+            }
             RegisterType::Record(..) => bail!("Decrement cannot decrement by a 'record' (found at '{decrement}')"),
             RegisterType::ExternalRecord(..) => {
                 bail!("Decrement cannot decrement by an 'external record' (found at '{decrement}')")
@@ -297,6 +316,9 @@ impl<N: Network> FinalizeTypes<N> {
             RegisterType::Plaintext(PlaintextType::Struct(..)) => {
                 bail!("Increment cannot increment by an 'struct' (found at '{increment}')")
             }
+            RegisterType::Plaintext(PlaintextType::Array(..)) => {
+                bail!("Increment cannot increment by an 'array' (found at '{increment}')")
+            }
             RegisterType::Record(..) => bail!("Increment cannot increment by a 'record' (found at '{increment}')"),
             RegisterType::ExternalRecord(..) => {
                 bail!("Increment cannot increment by an 'external record' (found at '{increment}')")
@@ -410,6 +432,16 @@ impl<N: Network> FinalizeTypes<N> {
                         // Ensure the operand types match the struct.
                         self.matches_struct(stack, instruction.operands(), &struct_)?;
                     }
+                    RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                        // Ensure the element struct name, if any, exists in the program.
+                        if let PlaintextType::Struct(struct_name) = array_type.element_type() {
+                            if !stack.program().contains_struct(&struct_name) {
+                                bail!("Struct '{struct_name}' is not defined.")
+                            }
+                        }
+                        // Ensure the operand types match the array.
+                        self.matches_array(stack, instruction.operands(), array_type)?;
+                    }
                     RegisterType::Record(..) => {
                         bail!("Unsupported operation: Cannot cast to a record (yet).")
                         // // Ensure the record type is defined in the program.
@@ -487,6 +519,8 @@ impl<N: Network> FinalizeTypes<N> {
                     "hash.psd2",
                     "hash.psd4",
                     "hash.psd8",
+                    "hash.psd2v2",
+                    "hash.blake3",
                 ]
                 .contains(&opcode)
                 {
@@ -530,6 +564,14 @@ impl<N: Network> FinalizeTypes<N> {
                         matches!(instruction, Instruction::HashPSD8(..)),
                         "Instruction '{instruction}' is not for opcode '{opcode}'."
                     ),
+                    "hash.psd2v2" => ensure!(
+                        matches!(instruction, Instruction::HashPSD2V2(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "hash.blake3" => ensure!(
+                        matches!(instruction, Instruction::HashBLAKE3(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
@@ -89,6 +89,30 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
+    /// Checks that the given operands matches the layout of the array. The ordering of the operands matters.
+    pub fn matches_array(&self, stack: &Stack<N>, operands: &[Operand<N>], array_type: &ArrayType<N>) -> Result<()> {
+        // Ensure the number of operands matches the array length.
+        let num_elements = operands.len();
+        let expected_num_elements = array_type.length() as usize;
+        if expected_num_elements != num_elements {
+            bail!("'{array_type}' expected {expected_num_elements} elements, found {num_elements} elements")
+        }
+
+        // Retrieve the element type.
+        let element_type = RegisterType::Plaintext(array_type.element_type());
+        // Ensure the operand types match the element type.
+        for operand in operands {
+            // Retrieve the operand type.
+            let operand_type = self.get_type_from_operand(stack, operand)?;
+            // Ensure the operand type matches the element type.
+            ensure!(
+                operand_type == element_type,
+                "Array '{array_type}' expects {element_type}, but found '{operand_type}' in the operand '{operand}'.",
+            )
+        }
+        Ok(())
+    }
+
     /// Checks that the given record matches the layout of the record type.
     /// Note: Ordering for `owner` and `gates` **does** matter, however ordering
     /// for record data does **not** matter, as long as all defined members are present.
@@ -27,7 +27,17 @@ use crate::{
 };
 use console::{
     network::prelude::*,
-    program::{EntryType, Identifier, LiteralType, PlaintextType, RecordType, Register, RegisterType, Struct},
+    program::{
+        ArrayType,
+        EntryType,
+        Identifier,
+        LiteralType,
+        PlaintextType,
+        RecordType,
+        Register,
+        RegisterType,
+        Struct,
+    },
 };
 
 use indexmap::IndexMap;
@@ -105,6 +115,8 @@ impl<N: Network> FinalizeTypes<N> {
             register_type = match &register_type {
                 // Ensure the plaintext type is not a literal, as the register references a member.
                 RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("'{register}' references a literal."),
+                // Ensure the plaintext type is not an array, as the register references a member.
+                RegisterType::Plaintext(PlaintextType::Array(..)) => bail!("'{register}' references an array."),
                 // Traverse the member path to output the register type.
                 RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                     // Retrieve the member type from the struct.
@@ -69,6 +69,62 @@ impl<N: Network> Stack<N> {
         Ok(())
     }
 
+    /// Returns the circuit digest for the given function name, without storing its keys.
+    /// This digest matches the `circuit_digest` of the proving key for the function.
+    #[inline]
+    pub fn circuit_digest<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<[u8; 32]> {
+        // Retrieve the program ID.
+        let program_id = self.program_id();
+        // Retrieve the function input types.
+        let input_types = self.get_function(function_name)?.input_types();
+
+        // Initialize a burner private key.
+        let burner_private_key = PrivateKey::new(rng)?;
+        // Compute the burner address.
+        let burner_address = Address::try_from(&burner_private_key)?;
+        // Sample the inputs.
+        let inputs = input_types
+            .iter()
+            .map(|input_type| match input_type {
+                ValueType::ExternalRecord(locator) => {
+                    // Retrieve the external stack.
+                    let stack = self.get_external_stack(locator.program_id())?;
+                    // Sample the input.
+                    stack.sample_value(&burner_address, &ValueType::Record(*locator.resource()), rng)
+                }
+                _ => self.sample_value(&burner_address, input_type, rng),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Compute the request, with a burner private key.
+        let request =
+            Request::sign(&burner_private_key, *program_id, *function_name, inputs.into_iter(), &input_types, rng)?;
+        // Initialize the assignments.
+        let assignments = Assignments::<N>::default();
+        // Initialize the call stack.
+        let call_stack = CallStack::CheckDeployment(vec![request], burner_private_key, assignments.clone());
+        // Synthesize the circuit.
+        let _response = self.execute_function::<A, R>(call_stack, rng)?;
+
+        // Retrieve the assignment.
+        let assignments_guard = assignments.read();
+        let assignment = match assignments_guard.last() {
+            Some(assignment) => assignment,
+            None => bail!("The assignment for function '{function_name}' is missing in '{program_id}'"),
+        };
+        // Synthesize the proving key, without storing it.
+        let (proving_key, _) = self.universal_srs.to_circuit_key(function_name, assignment)?;
+        // Release the assignments.
+        drop(assignments_guard);
+
+        // Compute the circuit digest.
+        proving_key.circuit_digest()
+    }
+
     /// Synthesizes and stores the `(proving_key, verifying_key)` for the given function name and assignment.
     #[inline]
     pub fn synthesize_from_assignment(
@@ -86,6 +86,7 @@ impl<N: Network> Decrement<N> {
         let start = match store.get_value(stack.program_id(), &self.mapping, &key)? {
             Some(Value::Plaintext(Plaintext::Literal(literal, _))) => literal,
             Some(Value::Plaintext(Plaintext::Struct(..))) => bail!("Cannot 'decrement' by an 'struct'"),
+            Some(Value::Plaintext(Plaintext::Array(..))) => bail!("Cannot 'decrement' by an 'array'"),
             Some(Value::Record(..)) => bail!("Cannot 'decrement' by a 'record'"),
             // If the key does not exist, set the starting value to 0.
             // Infer the starting type from the decrement type.
@@ -95,4 +95,26 @@ mod tests {
         assert_eq!(expected_bytes, candidate.to_bytes_le()?);
         Ok(())
     }
+
+    #[test]
+    fn test_opcode_indices_are_stable() -> Result<()> {
+        // Note: New instructions must be appended to the end of the opcode list,
+        // as the opcode index is part of the serialized instruction.
+        let expected = [
+            ("abs r0 into r1;", 0u16),
+            ("add r0 r1 into r2;", 2),
+            ("hash.psd8 r0 into r1;", 28),
+            ("inv r0 into r1;", 29),
+            ("is.eq r0 r1 into r2;", 30),
+            ("ternary r0 r1 r2 into r3;", 54),
+            ("xor r0 r1 into r2;", 55),
+            ("hash.psd2v2 r0 into r1;", 56),
+            ("hash.blake3 r0 into r1;", 57),
+        ];
+        for (instruction, index) in expected {
+            let bytes = Instruction::<CurrentNetwork>::from_str(instruction)?.to_bytes_le()?;
+            assert_eq!(index.to_le_bytes(), bytes[..2], "Opcode index changed for '{instruction}'");
+        }
+        Ok(())
+    }
 }
@@ -171,6 +171,10 @@ pub enum Instruction<N: Network> {
     Ternary(Ternary<N>),
     /// Performs a bitwise `xor` on `first` and `second`, storing the outcome in `destination`.
     Xor(Xor<N>),
+    /// Performs a Poseidon2 hash with an input rate of 2.
+    HashPSD2V2(HashPSD2V2<N>),
+    /// Performs a BLAKE3 hash on a byte string.
+    HashBLAKE3(HashBLAKE3<N>),
 }
 
 /// Creates a match statement that applies the given operation for each instruction.
@@ -258,6 +262,8 @@ macro_rules! instruction {
             SubWrapped,
             Ternary,
             Xor,
+            HashPSD2V2,
+            HashBLAKE3,
         }}
     };
     // A variant **without** curly braces:
@@ -404,7 +410,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            56,
+            58,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
@@ -14,7 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use console::network::prelude::*;
+use crate::Instruction;
+use console::{network::prelude::*, program::Identifier};
 
 /// The `Opcode` enum stores the mnemonic for the instruction.
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
@@ -39,6 +40,15 @@ pub enum Opcode {
     Literal(&'static str),
 }
 
+impl Opcode {
+    /// Returns the instruction opcode with the given name, or `None` if the identifier is not an opcode.
+    /// Note: Opcodes containing a `.` (e.g. `hash.psd2`) are never valid identifiers, and are not matched.
+    pub fn from_identifier<N: Network>(identifier: &Identifier<N>) -> Option<Self> {
+        let name = identifier.to_string();
+        Instruction::<N>::OPCODES.iter().copied().find(|opcode| *opcode.deref() == name)
+    }
+}
+
 impl Deref for Opcode {
     type Target = &'static str;
 
@@ -82,3 +92,41 @@ impl Display for Opcode {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_from_identifier() -> Result<()> {
+        // Check known opcodes.
+        for (name, expected) in [
+            ("add", Opcode::Literal("add")),
+            ("sub", Opcode::Literal("sub")),
+            ("mul", Opcode::Literal("mul")),
+            ("ternary", Opcode::Literal("ternary")),
+            ("call", Opcode::Call),
+            ("cast", Opcode::Cast),
+        ] {
+            let identifier = Identifier::<CurrentNetwork>::from_str(name)?;
+            assert_eq!(Some(expected), Opcode::from_identifier(&identifier));
+        }
+
+        // Check every opcode that is a valid identifier.
+        for opcode in Instruction::<CurrentNetwork>::OPCODES {
+            if let Ok(identifier) = Identifier::<CurrentNetwork>::from_str(**opcode) {
+                assert_eq!(Some(*opcode), Opcode::from_identifier(&identifier));
+            }
+        }
+
+        // Check unknown names.
+        for name in ["foo", "hash", "commit", "is", "assert", "Add", "add_"] {
+            let identifier = Identifier::<CurrentNetwork>::from_str(name)?;
+            assert_eq!(None, Opcode::from_identifier(&identifier));
+        }
+        Ok(())
+    }
+}
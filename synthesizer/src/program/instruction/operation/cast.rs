@@ -119,6 +119,41 @@ impl<N: Network> Cast<N> {
                 // Store the struct.
                 registers.store(stack, &self.destination, Value::Plaintext(struct_))
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the number of operands matches the array length.
+                ensure!(
+                    inputs.len() == array_type.length() as usize,
+                    "Casting to '{array_type}' requires {} operands, found {}",
+                    array_type.length(),
+                    inputs.len()
+                );
+
+                // Compute the register type of each element.
+                let register_type = RegisterType::Plaintext(array_type.element_type());
+
+                // Initialize the array elements.
+                let mut elements = Vec::with_capacity(inputs.len());
+                for element in inputs.iter() {
+                    // Retrieve the plaintext value from the element.
+                    let plaintext = match element {
+                        Value::Plaintext(plaintext) => {
+                            // Ensure the element matches the register type.
+                            stack.matches_register_type(&Value::Plaintext(plaintext.clone()), &register_type)?;
+                            // Output the plaintext.
+                            plaintext.clone()
+                        }
+                        // Ensure the array element is not a record.
+                        Value::Record(..) => bail!("Casting a record into an array element is illegal"),
+                    };
+                    // Append the element to the array elements.
+                    elements.push(plaintext);
+                }
+
+                // Construct the array.
+                let array = Plaintext::Array(elements, Default::default());
+                // Store the array.
+                registers.store(stack, &self.destination, Value::Plaintext(array))
+            }
             RegisterType::Record(record_name) => {
                 // Ensure the operands length is at least 2.
                 ensure!(inputs.len() >= 2, "Casting to a record requires at least two operands");
@@ -250,6 +285,46 @@ impl<N: Network> Cast<N> {
                 // Store the struct.
                 registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(struct_))
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the number of operands matches the array length.
+                ensure!(
+                    inputs.len() == array_type.length() as usize,
+                    "Casting to '{array_type}' requires {} operands, found {}",
+                    array_type.length(),
+                    inputs.len()
+                );
+
+                // Compute the register type of each element.
+                let register_type = RegisterType::Plaintext(array_type.element_type());
+
+                // Initialize the array elements.
+                let mut elements = Vec::with_capacity(inputs.len());
+                for element in inputs.iter() {
+                    // Retrieve the plaintext value from the element.
+                    let plaintext = match element {
+                        circuit::Value::Plaintext(plaintext) => {
+                            // Ensure the element matches the register type.
+                            stack.matches_register_type(
+                                &circuit::Value::Plaintext(plaintext.clone()).eject_value(),
+                                &register_type,
+                            )?;
+                            // Output the plaintext.
+                            plaintext.clone()
+                        }
+                        // Ensure the array element is not a record.
+                        circuit::Value::Record(..) => {
+                            bail!("Casting a record into an array element is illegal")
+                        }
+                    };
+                    // Append the element to the array elements.
+                    elements.push(plaintext);
+                }
+
+                // Construct the array.
+                let array = circuit::Plaintext::Array(elements, Default::default());
+                // Store the array.
+                registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(array))
+            }
             RegisterType::Record(record_name) => {
                 // Ensure the operands length is at least 2.
                 ensure!(inputs.len() >= 2, "Casting to a record requires at least two operands");
@@ -380,6 +455,37 @@ impl<N: Network> Cast<N> {
                     }
                 }
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the number of input types matches the array length.
+                ensure!(
+                    input_types.len() == array_type.length() as usize,
+                    "Casting to '{array_type}' requires {} operands, found {}",
+                    array_type.length(),
+                    input_types.len()
+                );
+                // Retrieve the element type.
+                let element_type = array_type.element_type();
+                // Ensure the input types match the element type.
+                for input_type in input_types {
+                    match input_type {
+                        // Ensure the plaintext type matches the element type.
+                        RegisterType::Plaintext(plaintext_type) => {
+                            ensure!(
+                                element_type == *plaintext_type,
+                                "Array '{array_type}' element type mismatch: expected '{element_type}', found '{plaintext_type}'"
+                            )
+                        }
+                        // Ensure the input type cannot be a record (this is unsupported behavior).
+                        RegisterType::Record(record_name) => bail!(
+                            "Array '{array_type}' element type mismatch: expected '{element_type}', found record '{record_name}'"
+                        ),
+                        // Ensure the input type cannot be an external record (this is unsupported behavior).
+                        RegisterType::ExternalRecord(locator) => bail!(
+                            "Array '{array_type}' element type mismatch: expected '{element_type}', found external record '{locator}'"
+                        ),
+                    }
+                }
+            }
             RegisterType::Record(record_name) => {
                 // Retrieve the record type and ensure is defined in the program.
                 let record = stack.program().get_record(&record_name)?;
@@ -592,7 +698,10 @@ impl<N: Network> ToBytes for Cast<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use console::{network::Testnet3, program::Identifier};
+    use console::{
+        network::Testnet3,
+        program::{ArrayType, Identifier},
+    };
 
     type CurrentNetwork = Testnet3;
 
@@ -625,6 +734,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_cast_into_array() {
+        let (string, cast) = Cast::<CurrentNetwork>::parse("cast r0 r1 r2 into r3 as [u64; 3]").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(cast.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(cast.destination, Register::Locator(3), "The destination register is incorrect");
+        assert_eq!(
+            cast.register_type,
+            RegisterType::Plaintext(PlaintextType::Array(ArrayType::from_str("[u64; 3]").unwrap())),
+            "The value type is incorrect"
+        );
+        assert_eq!(cast.to_string(), "cast r0 r1 r2 into r3 as [u64; 3]");
+    }
+
     #[test]
     fn test_parse_cast_into_plaintext_max_operands() {
         let mut string = "cast ".to_string();
@@ -41,6 +41,13 @@ pub type HashPSD4<N> = HashInstruction<N, { Hasher::PSD4 as u8 }>;
 /// Poseidon8 is a cryptographic hash function that processes inputs in 8-field chunks.
 pub type HashPSD8<N> = HashInstruction<N, { Hasher::PSD8 as u8 }>;
 
+/// Poseidon2V2 is the Poseidon2 hash function that processes inputs in 2-field chunks.
+pub type HashPSD2V2<N> = HashInstruction<N, { Hasher::PSD2V2 as u8 }>;
+
+/// BLAKE3 is a cryptographic hash function that processes byte strings, for compatibility with off-chain tooling.
+/// The plaintext input is hashed as a byte string, and the digest is truncated to the data bits of a field element.
+pub type HashBLAKE3<N> = HashInstruction<N, { Hasher::BLAKE3 as u8 }>;
+
 enum Hasher {
     BHP256,
     BHP512,
@@ -51,6 +58,8 @@ enum Hasher {
     PSD2,
     PSD4,
     PSD8,
+    PSD2V2,
+    BLAKE3,
 }
 
 /// Hashes the operand into the declared type.
@@ -76,6 +85,8 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             6 => Opcode::Hash("hash.psd2"),
             7 => Opcode::Hash("hash.psd4"),
             8 => Opcode::Hash("hash.psd8"),
+            9 => Opcode::Hash("hash.psd2v2"),
+            10 => Opcode::Hash("hash.blake3"),
             _ => panic!("Invalid 'hash' instruction opcode"),
         }
     }
@@ -121,6 +132,11 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             6 => N::hash_psd2(&input.to_fields()?)?,
             7 => N::hash_psd4(&input.to_fields()?)?,
             8 => N::hash_psd8(&input.to_fields()?)?,
+            9 => N::hash_psd2v2(&input.to_fields()?)?,
+            10 => match &input {
+                Value::Plaintext(plaintext) => N::hash_blake3(&to_byte_string_le(plaintext))?,
+                Value::Record(..) => bail!("Instruction '{}' expects a plaintext input", Self::opcode()),
+            },
             _ => bail!("Invalid 'hash' variant: {VARIANT}"),
         };
         // Store the output.
@@ -153,6 +169,11 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             6 => A::hash_psd2(&input.to_fields()),
             7 => A::hash_psd4(&input.to_fields()),
             8 => A::hash_psd8(&input.to_fields()),
+            9 => A::hash_psd2v2(&input.to_fields()),
+            10 => match &input {
+                circuit::Value::Plaintext(plaintext) => A::hash_blake3(&to_byte_string_le_circuit(plaintext)),
+                circuit::Value::Record(..) => bail!("Instruction '{}' expects a plaintext input", Self::opcode()),
+            },
             _ => bail!("Invalid 'hash' variant: {VARIANT}"),
         };
         // Convert the output to a stack value.
@@ -176,8 +197,13 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
 
         // TODO (howardwu): If the operation is Pedersen, check that it is within the number of bits.
 
+        // Ensure the BLAKE3 input is a plaintext.
+        if VARIANT == Hasher::BLAKE3 as u8 && !matches!(input_types[0], RegisterType::Plaintext(..)) {
+            bail!("Instruction '{}' expects a plaintext input, found '{}'", Self::opcode(), input_types[0])
+        }
+
         match VARIANT {
-            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 => {
+            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 => {
                 Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field))])
             }
             _ => bail!("Invalid 'hash' variant: {VARIANT}"),
@@ -185,6 +211,36 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
     }
 }
 
+/// Returns the given plaintext as a byte string of **little-endian** bits.
+/// Each literal is encoded as its little-endian bits, zero-padded to a whole number of bytes,
+/// and structs and arrays are encoded as the concatenation of their members or elements.
+fn to_byte_string_le<N: Network>(plaintext: &Plaintext<N>) -> Vec<bool> {
+    match plaintext {
+        Plaintext::Literal(literal, ..) => {
+            let mut bits_le = literal.to_bits_le();
+            bits_le.resize((bits_le.len() + 7) / 8 * 8, false);
+            bits_le
+        }
+        Plaintext::Struct(members, ..) => members.values().flat_map(to_byte_string_le).collect(),
+        Plaintext::Array(elements, ..) => elements.iter().flat_map(to_byte_string_le).collect(),
+    }
+}
+
+/// Returns the given circuit plaintext as a byte string of **little-endian** bits, as in `to_byte_string_le`.
+fn to_byte_string_le_circuit<A: circuit::Aleo>(plaintext: &circuit::Plaintext<A>) -> Vec<circuit::Boolean<A>> {
+    use circuit::{Inject, ToBits};
+
+    match plaintext {
+        circuit::Plaintext::Literal(literal, ..) => {
+            let mut bits_le = literal.to_bits_le();
+            bits_le.resize((bits_le.len() + 7) / 8 * 8, circuit::Boolean::constant(false));
+            bits_le
+        }
+        circuit::Plaintext::Struct(members, ..) => members.values().flat_map(to_byte_string_le_circuit).collect(),
+        circuit::Plaintext::Array(elements, ..) => elements.iter().flat_map(to_byte_string_le_circuit).collect(),
+    }
+}
+
 impl<N: Network, const VARIANT: u8> Parser for HashInstruction<N, VARIANT> {
     /// Parses a string into an operation.
     #[inline]
@@ -277,6 +333,7 @@ impl<N: Network, const VARIANT: u8> ToBytes for HashInstruction<N, VARIANT> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Instruction;
     use console::network::Testnet3;
 
     type CurrentNetwork = Testnet3;
@@ -289,4 +346,44 @@ mod tests {
         assert_eq!(hash.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
         assert_eq!(hash.destination, Register::Locator(1), "The destination register is incorrect");
     }
+
+    #[test]
+    fn test_parse_psd2v2() {
+        let (string, hash) = HashPSD2V2::<CurrentNetwork>::parse("hash.psd2v2 r0 into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(hash.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(hash.destination, Register::Locator(1), "The destination register is incorrect");
+
+        // Ensure the Poseidon opcode does not accept the Poseidon2 opcode.
+        assert!(HashPSD2::<CurrentNetwork>::parse("hash.psd2v2 r0 into r1").is_err());
+        // Ensure the instruction parser selects the Poseidon2 opcode.
+        let instruction = Instruction::<CurrentNetwork>::from_str("hash.psd2v2 r0 into r1;").unwrap();
+        assert!(matches!(instruction, Instruction::HashPSD2V2(..)));
+    }
+
+    #[test]
+    fn test_parse_blake3() {
+        let (string, hash) = HashBLAKE3::<CurrentNetwork>::parse("hash.blake3 r0 into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(hash.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(hash.destination, Register::Locator(1), "The destination register is incorrect");
+
+        // Ensure the instruction parser selects the BLAKE3 opcode.
+        let instruction = Instruction::<CurrentNetwork>::from_str("hash.blake3 r0 into r1;").unwrap();
+        assert!(matches!(instruction, Instruction::HashBLAKE3(..)));
+    }
+
+    #[test]
+    fn test_blake3_byte_string() {
+        // Ensure a byte array is hashed as its raw bytes.
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("[ 97u8, 98u8, 99u8 ]").unwrap();
+        let expected = b"abc".iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)).collect::<Vec<_>>();
+        assert_eq!(to_byte_string_le(&plaintext), expected);
+
+        // Ensure literals are padded to a whole number of bytes.
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("true").unwrap();
+        assert_eq!(to_byte_string_le(&plaintext), [true, false, false, false, false, false, false, false]);
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("1field").unwrap();
+        assert_eq!(to_byte_string_le(&plaintext).len(), 256);
+    }
 }
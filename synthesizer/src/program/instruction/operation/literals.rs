@@ -152,7 +152,7 @@ impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const N
             .copied()
             .map(|input_type| match input_type {
                 RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => Ok(literal_type),
-                RegisterType::Plaintext(PlaintextType::Struct(..)) => {
+                RegisterType::Plaintext(PlaintextType::Struct(..) | PlaintextType::Array(..)) => {
                     bail!("Expected literal type, found '{input_type}'")
                 }
                 RegisterType::Record(..) => bail!("Expected literal type, found '{input_type}'"),
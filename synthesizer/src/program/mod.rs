@@ -367,6 +367,14 @@ impl<N: Network> Program<N> {
                         bail!("'{member_identifier}' in struct '{}' is not defined.", struct_name)
                     }
                 }
+                PlaintextType::Array(array_type) => {
+                    // Ensure the element struct name, if any, exists in the program.
+                    if let PlaintextType::Struct(element_identifier) = array_type.element_type() {
+                        if !self.structs.contains_key(&element_identifier) {
+                            bail!("'{element_identifier}' in struct '{}' is not defined.", struct_name)
+                        }
+                    }
+                }
             }
         }
 
@@ -420,6 +428,13 @@ impl<N: Network> Program<N> {
                             bail!("Struct '{identifier}' in record '{record_name}' is not defined.")
                         }
                     }
+                    PlaintextType::Array(array_type) => {
+                        if let PlaintextType::Struct(identifier) = array_type.element_type() {
+                            if !self.structs.contains_key(&identifier) {
+                                bail!("Struct '{identifier}' in record '{record_name}' is not defined.")
+                            }
+                        }
+                    }
                 },
             }
         }
@@ -528,75 +543,6 @@ impl<N: Network> Program<N> {
 }
 
 impl<N: Network> Program<N> {
-    #[rustfmt::skip]
-    const KEYWORDS: &'static [&'static str] = &[
-        // Mode
-        "const",
-        "constant",
-        "public",
-        "private",
-        // Literals
-        "address",
-        "boolean",
-        "field",
-        "group",
-        "i8",
-        "i16",
-        "i32",
-        "i64",
-        "i128",
-        "u8",
-        "u16",
-        "u32",
-        "u64",
-        "u128",
-        "scalar",
-        "string",
-        // Boolean
-        "true",
-        "false",
-        // Statements
-        "input",
-        "output",
-        "as",
-        "into",
-        // Record
-        "record",
-        "gates",
-        // Program
-        "function",
-        "struct",
-        "closure",
-        "program",
-        "aleo",
-        "self",
-        "storage",
-        "mapping",
-        "key",
-        "value",
-        // Reserved (catch all)
-        "global",
-        "return",
-        "break",
-        "assert",
-        "continue",
-        "let",
-        "if",
-        "else",
-        "while",
-        "for",
-        "switch",
-        "case",
-        "default",
-        "match",
-        "enum",
-        "struct",
-        "union",
-        "trait",
-        "impl",
-        "type",
-    ];
-
     /// Returns `true` if the given name does not already exist in the program.
     fn is_unique_name(&self, name: &Identifier<N>) -> bool {
         !self.identifiers.contains_key(name)
@@ -610,10 +556,7 @@ impl<N: Network> Program<N> {
 
     /// Returns `true` if the given name uses a reserved keyword.
     pub fn is_reserved_keyword(name: &Identifier<N>) -> bool {
-        // Convert the given name to a string.
-        let name = name.to_string();
-        // Check if the name is a keyword.
-        Self::KEYWORDS.iter().any(|keyword| *keyword == name)
+        name.is_reserved()
     }
 
     /// Returns `true` if the given program ID and function name corresponds to a coinbase function.
@@ -935,6 +878,83 @@ function compute:
         assert_eq!(expected, candidate[0]);
     }
 
+    #[test]
+    fn test_program_evaluate_array_and_function() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program example.aleo;
+
+struct series:
+    label as field;
+    values as [u64; 3];
+
+function compute:
+    input r0 as [u64; 3].private;
+    input r1 as field.private;
+    cast r1 r0 into r2 as series;
+    cast 4u64 5u64 6u64 into r3 as [u64; 3];
+    output r2 as series.private;
+    output r3 as [u64; 3].private;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+        // Declare the input values.
+        let inputs = [
+            Value::<CurrentNetwork>::Plaintext(Plaintext::from_str("[1u64, 2u64, 3u64]").unwrap()),
+            Value::<CurrentNetwork>::Plaintext(Plaintext::from_str("7field").unwrap()),
+        ];
+        // Declare the expected output values.
+        let expected = [
+            Value::Plaintext(Plaintext::from_str("{ label: 7field, values: [1u64, 2u64, 3u64] }").unwrap()),
+            Value::Plaintext(Plaintext::from_str("[4u64, 5u64, 6u64]").unwrap()),
+        ];
+
+        // Construct the process.
+        let process = crate::process::test_helpers::sample_process(&program);
+
+        // Compute the authorization.
+        let authorization = {
+            // Initialize an RNG.
+            let rng = &mut TestRng::default();
+
+            // Initialize caller private key.
+            let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+            // Authorize the function call.
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+                .unwrap();
+            assert_eq!(authorization.len(), 1);
+            authorization
+        };
+
+        // Retrieve the stack.
+        let stack = process.get_stack(program.id()).unwrap();
+
+        // Compute the output values.
+        let response = stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization).unwrap()).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(2, candidate.len());
+        assert_eq!(expected[0], candidate[0]);
+        assert_eq!(expected[1], candidate[1]);
+
+        // Ensure an array of the wrong length is rejected.
+        let rng = &mut TestRng::default();
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let inputs = [
+            Value::<CurrentNetwork>::Plaintext(Plaintext::from_str("[1u64, 2u64]").unwrap()),
+            Value::<CurrentNetwork>::Plaintext(Plaintext::from_str("7field").unwrap()),
+        ];
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+            .unwrap();
+        assert!(stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization).unwrap()).is_err());
+    }
+
     #[test]
     fn test_program_evaluate_record_and_function() {
         // Initialize a new program.
@@ -34,6 +34,16 @@ impl<N: Network> ProvingKey<N> {
         Self { proving_key }
     }
 
+    /// Returns the digest of the circuit that this proving key was synthesized from,
+    /// over the constraint system and the circuit commitments in the verifying key.
+    pub fn circuit_digest(&self) -> Result<[u8; 32]> {
+        // Construct the input as `(constraint_system_digest || circuit_verifying_key)`.
+        let mut input = self.proving_key.circuit.digest().to_vec();
+        input.extend(self.proving_key.circuit_verifying_key.to_bytes_le()?);
+        // Compute the SHA-256 digest of the input.
+        Ok(snarkvm_algorithms::crypto_hash::sha256(&input))
+    }
+
     /// Returns a proof for the given assignment on the circuit.
     pub fn prove<R: Rng + CryptoRng>(
         &self,
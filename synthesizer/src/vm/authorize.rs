@@ -70,4 +70,127 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Process the logic.
         process!(self, logic)
     }
+
+    /// Authorizes a batch of calls to program functions, for the given private key.
+    ///
+    /// This returns one authorization per call, in order, so that a multi-step workflow may be signed
+    /// at once, and executed later (e.g. by a prover) without requesting further signatures.
+    /// Note: Each call must be authorized with known inputs, so a call may not consume the outputs of an
+    /// earlier call in the same batch.
+    ///
+    /// Note: The batch is **not** atomic. The authorizations are independent, and each one may be executed,
+    /// dropped, or reordered on its own. Binding the calls together (e.g. with a shared batch ID in each request)
+    /// requires the binding to be verified in the function circuits, which changes the request format.
+    #[inline]
+    pub fn authorize_batch<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        calls: impl IntoIterator<Item = (ProgramID<N>, Identifier<N>, Vec<Value<N>>)>,
+        rng: &mut R,
+    ) -> Result<Vec<Authorization<N>>> {
+        calls
+            .into_iter()
+            .enumerate()
+            .map(|(index, (program_id, function_name, inputs))| {
+                self.authorize(private_key, program_id, function_name, inputs, rng).map_err(|error| {
+                    anyhow!("Failed to authorize call #{index} ('{program_id}/{function_name}'): {error}")
+                })
+            })
+            .collect()
+    }
+
+    /// Authorizes a fee for the given private key, credits record, and fee amount (in gates).
+    /// The resulting fee authorization may be signed by a sponsor, who pays the fee on behalf of the caller.
+    #[inline]
+    pub fn authorize_fee<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        credits: Record<N, Plaintext<N>>,
+        fee_in_gates: u64,
+        rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        let timer = timer!("VM::authorize_fee");
+
+        // Compute the core logic.
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                type RecordPlaintext<NetworkMacro> = Record<NetworkMacro, Plaintext<NetworkMacro>>;
+
+                // Prepare the private key and credits record.
+                let private_key = cast_ref!(&private_key as PrivateKey<$network>);
+                let credits = cast_ref!(credits as RecordPlaintext<$network>);
+                lap!(timer, "Prepare the private key and credits record");
+
+                // Compute the fee authorization.
+                let authorization =
+                    $process.authorize_fee::<$aleo, _>(private_key, credits.clone(), fee_in_gates, rng)?;
+                lap!(timer, "Compute the fee authorization");
+
+                finish!(timer);
+
+                // Return the fee authorization.
+                Ok(cast_ref!(authorization as Authorization<N>).clone())
+            }};
+        }
+        // Process the logic.
+        process!(self, logic)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use console::account::Address;
+
+    type CurrentNetwork = test_helpers::CurrentNetwork;
+
+    #[test]
+    fn test_authorize_batch() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = test_helpers::sample_vm();
+
+        // Initialize the caller.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        let record = Value::<CurrentNetwork>::from_str(&format!(
+            "{{ owner: {address}.private, gates: 5u64.private, _nonce: 2293253577170800572742339369209137467208538700597121244293392265726446806023group.public }}"
+        ))
+        .unwrap();
+
+        // Prepare the calls.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("credits.aleo").unwrap();
+        let mint = Identifier::from_str("mint").unwrap();
+        let transfer = Identifier::from_str("transfer").unwrap();
+        let calls = vec![
+            (program_id, mint, vec![Value::from_str(&address.to_string()).unwrap(), Value::from_str("1u64").unwrap()]),
+            (program_id, transfer, vec![
+                record,
+                Value::from_str(&address.to_string()).unwrap(),
+                Value::from_str("2u64").unwrap(),
+            ]),
+            (program_id, mint, vec![Value::from_str(&address.to_string()).unwrap(), Value::from_str("3u64").unwrap()]),
+        ];
+
+        // Authorize the batch.
+        let authorizations = vm.authorize_batch(&private_key, calls.clone(), rng).unwrap();
+        assert_eq!(calls.len(), authorizations.len());
+
+        // Ensure each authorization contains a single request for its call, in order.
+        for ((program_id, function_name, inputs), authorization) in calls.iter().zip_eq(&authorizations) {
+            assert_eq!(1, authorization.len());
+            let request = authorization.peek_next().unwrap();
+            assert_eq!(program_id, request.program_id());
+            assert_eq!(function_name, request.function_name());
+            assert_eq!(inputs.len(), request.inputs().len());
+        }
+
+        // Ensure an unknown function fails the whole batch.
+        let mut calls = calls;
+        calls.insert(1, (program_id, Identifier::from_str("unknown").unwrap(), vec![]));
+        let error = vm.authorize_batch(&private_key, calls, rng).unwrap_err();
+        assert!(error.to_string().contains("Failed to authorize call #1 ('credits.aleo/unknown')"));
+    }
 }
@@ -88,7 +88,22 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         query: Option<Query<N, C::BlockStorage>>,
         rng: &mut R,
     ) -> Result<(Response<N>, Fee<N>, Vec<CallMetrics<N>>)> {
-        let timer = timer!("VM::execute_fee");
+        // Authorize the fee.
+        let authorization = self.authorize_fee(private_key, credits, fee_in_gates, rng)?;
+        // Execute the fee.
+        self.execute_fee_authorization(authorization, query, rng)
+    }
+
+    /// Executes a fee for the given fee authorization.
+    /// The fee authorization may be signed by a sponsor, who pays the fee on behalf of the caller.
+    #[inline]
+    pub fn execute_fee_authorization<R: Rng + CryptoRng>(
+        &self,
+        authorization: Authorization<N>,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<(Response<N>, Fee<N>, Vec<CallMetrics<N>>)> {
+        let timer = timer!("VM::execute_fee_authorization");
 
         // Prepare the query.
         let query = match query {
@@ -100,16 +115,13 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Compute the core logic.
         macro_rules! logic {
             ($process:expr, $network:path, $aleo:path) => {{
-                type RecordPlaintext<NetworkMacro> = Record<NetworkMacro, Plaintext<NetworkMacro>>;
-
-                // Prepare the private key and credits record.
-                let private_key = cast_ref!(&private_key as PrivateKey<$network>);
-                let credits = cast_ref!(credits as RecordPlaintext<$network>);
-                lap!(timer, "Prepare the private key and credits record");
+                // Prepare the authorization.
+                let authorization = cast_ref!(authorization as Authorization<$network>);
+                lap!(timer, "Prepare the authorization");
 
                 // Execute the call to fee.
                 let (response, fee_transition, inclusion, metrics) =
-                    $process.execute_fee::<$aleo, _>(private_key, credits.clone(), fee_in_gates, rng)?;
+                    $process.execute_fee_authorization::<$aleo, _>(authorization.clone(), rng)?;
                 lap!(timer, "Execute the call to fee");
 
                 // Prepare the assignments.
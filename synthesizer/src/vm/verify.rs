@@ -394,4 +394,39 @@ mod tests {
         assert!(vm.check_transaction(&transaction).is_ok());
         assert!(vm.verify_transaction(&transaction));
     }
+
+    #[test]
+    fn test_verify_sponsored_fee() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the sponsor.
+        let sponsor_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let sponsor_view_key = ViewKey::try_from(&sponsor_private_key).unwrap();
+
+        // Initialize the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+
+        // Fetch the unspent records.
+        let records = genesis.records().collect::<indexmap::IndexMap<_, _>>();
+        let credits = records.values().next().unwrap().decrypt(&sponsor_view_key).unwrap();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+
+        // Authorize the fee as the sponsor.
+        let fee_authorization = vm.authorize_fee(&sponsor_private_key, credits, 1, rng).unwrap();
+        assert_eq!(fee_authorization.len(), 1);
+
+        // Ensure the fee authorization may be relayed to the prover.
+        let fee_authorization = Authorization::<CurrentNetwork>::from_str(&fee_authorization.to_string()).unwrap();
+
+        // Execute the fee, without the sponsor's private key.
+        let (_response, fee, _metrics) = vm.execute_fee_authorization(fee_authorization, None, rng).unwrap();
+
+        // Verify.
+        assert!(vm.verify_fee(&fee));
+        assert!(Inclusion::verify_fee(&fee).is_ok());
+    }
 }